@@ -12,15 +12,7 @@ impl<const T: usize> ops::Not for BitBoard<T> {
         }
 
         BitBoard::<T> {
-            bits: self
-                .bits
-                .iter()
-                .map(|el| !el)
-                .collect::<Vec<_>>()
-                .try_into()
-                .expect(&format!(
-                    "Could not convert BitBoard data vector into an array during unary operation."
-                )),
+            bits: std::array::from_fn(|i| !self.bits[i]),
         }
     }
 }
@@ -85,26 +77,26 @@ impl<const T: usize> ops::Shl<u16> for BitBoard<T> {
 }
 
 impl<const T: usize> ops::ShlAssign<u16> for BitBoard<T> {
-    fn shl_assign(&mut self, mut rhs: u16) {
+    fn shl_assign(&mut self, rhs: u16) {
         if T == 1 {
             self.bits = [self.bits[0] << rhs; T];
             return;
         }
 
-        while rhs > 128 {
-            *self <<= 128;
-            rhs -= 128;
+        // Shift whole words first, then shift the remaining bits, carrying them over from the next (less significant) word.
+        let words = (rhs / 128) as usize;
+        let rhs = rhs % 128;
+        for i in 0..T {
+            self.bits[i] = if i + words < T { self.bits[i + words] } else { 0 };
         }
 
-        let mask: u128 = u128::MAX - ((1 << (128 - rhs)) - 1); // Mask to get last `rhs` bits of integer (starting from LSB)
-        for i in 0..T {
-            let bits = (self.bits[i] & mask) >> (128 - rhs); // Apply mask and shift the bits over to be the first bits of the integer
-            self.bits[i] = self.bits[i] << rhs;
-            if i == 0 {
-                continue;
-            }
+        if rhs == 0 {
+            return;
+        }
 
-            self.bits[i - 1] |= bits;
+        for i in 0..T {
+            let carry = if i + 1 < T { self.bits[i + 1] >> (128 - rhs) } else { 0 };
+            self.bits[i] = (self.bits[i] << rhs) | carry;
         }
     }
 }
@@ -127,26 +119,26 @@ impl<const T: usize> ops::Shr<u16> for BitBoard<T> {
 }
 
 impl<const T: usize> ops::ShrAssign<u16> for BitBoard<T> {
-    fn shr_assign(&mut self, mut rhs: u16) {
+    fn shr_assign(&mut self, rhs: u16) {
         if T == 1 {
             self.bits = [self.bits[0] >> rhs; T];
             return;
         }
 
-        while rhs > 128 {
-            *self >>= 128;
-            rhs -= 128;
+        // Shift whole words first, then shift the remaining bits, carrying them over from the previous (more significant) word.
+        let words = (rhs / 128) as usize;
+        let rhs = rhs % 128;
+        for i in (0..T).rev() {
+            self.bits[i] = if i >= words { self.bits[i - words] } else { 0 };
         }
 
-        let mask: u128 = (1 << rhs) - 1; // Mask to get first `rhs` bits of integer (starting from LSB)
-        for i in (0..T).rev() {
-            let bits = (self.bits[i] & mask) << (128 - rhs); // Apply mask and shift the bits over to be the last bits of the integer
-            self.bits[i] = self.bits[i] >> rhs;
-            if i == T - 1 {
-                continue;
-            }
+        if rhs == 0 {
+            return;
+        }
 
-            self.bits[i + 1] |= bits;
+        for i in (0..T).rev() {
+            let carry = if i > 0 { self.bits[i - 1] << (128 - rhs) } else { 0 };
+            self.bits[i] = (self.bits[i] >> rhs) | carry;
         }
     }
 }
@@ -213,6 +205,22 @@ mod tests {
             BitBoard::from_data([0, (u128::MAX >> 1) + 1]) << 1,
             BitBoard::from_data([1, 0])
         );
+        assert_eq!(
+            BitBoard::from_data([0, 5]) << 0,
+            BitBoard::from_data([0, 5])
+        );
+        assert_eq!(
+            BitBoard::from_data([0, 5]) << 128,
+            BitBoard::from_data([5, 0])
+        );
+        assert_eq!(
+            BitBoard::from_data([0, 0, 3]) << 129,
+            BitBoard::from_data([0, 6, 0])
+        );
+        assert_eq!(
+            BitBoard::from_data([0, 1]) << 256,
+            BitBoard::from_data([0, 0])
+        );
     }
 
     #[test]
@@ -221,5 +229,21 @@ mod tests {
             BitBoard::from_data([1, 0]) >> 1,
             BitBoard::from_data([0, (u128::MAX >> 1) + 1])
         );
+        assert_eq!(
+            BitBoard::from_data([5, 0]) >> 0,
+            BitBoard::from_data([5, 0])
+        );
+        assert_eq!(
+            BitBoard::from_data([5, 0]) >> 128,
+            BitBoard::from_data([0, 5])
+        );
+        assert_eq!(
+            BitBoard::from_data([6, 0, 0]) >> 129,
+            BitBoard::from_data([0, 3, 0])
+        );
+        assert_eq!(
+            BitBoard::from_data([1, 0]) >> 256,
+            BitBoard::from_data([0, 0])
+        );
     }
 }
@@ -16,14 +16,14 @@ pub fn generate_ranks<const T: usize>(cols: Cols, rows: Rows) -> Vec<BitBoard<T>
 }
 
 pub fn generate_files<const T: usize>(cols: Cols, rows: Rows) -> Vec<BitBoard<T>> {
-    let mut files: Vec<BitBoard<T>> = Vec::with_capacity(rows as usize);
+    let mut files: Vec<BitBoard<T>> = Vec::with_capacity(cols as usize);
     let mut file = BitBoard::<T>::from_lsb(0);
     for _ in 1..rows {
         file |= file.down(1, cols);
     }
 
     files.push(file);
-    for _ in 1..rows {
+    for _ in 1..cols {
         file = file.right(1);
         files.push(file);
     }
@@ -66,14 +66,7 @@ impl<const T: usize> BitBoard<T> {
         }
 
         BitBoard {
-            bits: self
-                .bits
-                .iter()
-                .zip(rhs.bits)
-                .map(apply)
-                .collect::<Vec<_>>()
-                .try_into()
-                .expect(&format!("Could not convert BitBoard data vector into an array when applying operation with `apply`."))
+            bits: std::array::from_fn(|i| apply((&self.bits[i], rhs.bits[i]))),
         }
     }
 
@@ -84,14 +77,7 @@ impl<const T: usize> BitBoard<T> {
             return;
         }
 
-        self.bits = self
-            .bits
-            .iter()
-            .zip(rhs.bits)
-            .map(apply)
-            .collect::<Vec<_>>()
-            .try_into()
-            .expect(&format!("Could not convert BitBoard data vector into an array when applying operation with `effect`."));
+        self.bits = std::array::from_fn(|i| apply((&self.bits[i], rhs.bits[i])));
     }
 
     pub fn count_zeros(&self) -> u32 {
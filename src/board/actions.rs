@@ -40,10 +40,23 @@ pub struct PreviousBoard<const T: usize>(pub BitBoard<T>);
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub struct IndexedPreviousBoard<const T: usize>(pub usize, pub BitBoard<T>);
 
+/// The amount of a piece type a team had in reserve, stored as `(team, piece_type, count)`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct PreviousReserve(pub usize, pub usize, pub u16);
+
+/// The value a game's counter had, stored as `(counter, value)`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct PreviousCounter(pub usize, pub u16);
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum HistoryUpdate<const T: usize> {
     Team(IndexedPreviousBoard<T>),
     Piece(IndexedPreviousBoard<T>),
+    Reserve(PreviousReserve),
+    Promoted(PreviousBoard<T>),
+    Counter(PreviousCounter),
+    Gaps(PreviousBoard<T>),
+    Position,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -94,6 +107,31 @@ pub struct HistoryMove<const T: usize> {
     pub state: HistoryState<T>
 }
 
+impl<const T: usize> HistoryMove<T> {
+    /// Records extra changes that were made on top of this move, so they're also reverted when it's undone.
+    /// This is for games that build on the moves of existing pieces (eg. Crazyhouse adding captured pieces to a reserve.)
+    pub fn add_updates(&mut self, new_updates: Vec<HistoryUpdate<T>>) {
+        match &mut self.state {
+            HistoryState::Any { updates, .. } => {
+                updates.extend(new_updates);
+            }
+            HistoryState::Single { all_pieces, first_move, team, piece } => {
+                let mut updates = vec![HistoryUpdate::Team(*team), HistoryUpdate::Piece(*piece)];
+                updates.extend(new_updates);
+
+                self.state = HistoryState::Any {
+                    all_pieces: *all_pieces,
+                    first_move: *first_move,
+                    updates,
+                };
+            }
+            HistoryState::None => {
+                panic!("Updates can't be added to a move that didn't change the board.");
+            }
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum SimpleMove<T> {
     Pass,
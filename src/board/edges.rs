@@ -19,15 +19,26 @@ pub struct Edges<const T: usize> {
 }
 
 pub fn generate_edges<const T: usize>(buffer: EdgeBuffer, rows: Rows, cols: Cols) -> Edges<T> {
-    let top = !(BitBoard::max() << (buffer * cols));
-    let bottom = BitBoard::max() << ((rows - buffer) * cols);
+    // A buffer at least as deep as the board itself covers every square.
+    let (top, bottom) = if buffer >= rows {
+        (BitBoard::max(), BitBoard::max())
+    } else {
+        (
+            !(BitBoard::max() << (buffer * cols)),
+            BitBoard::max() << ((rows - buffer) * cols),
+        )
+    };
 
-    let mut left = BitBoard::max() & (!(BitBoard::max() << (buffer)));
-    for _ in 1..rows {
-        left |= (left << (cols));
-    }
+    let (left, right) = if buffer >= cols {
+        (BitBoard::max(), BitBoard::max())
+    } else {
+        let mut left = BitBoard::max() & (!(BitBoard::max() << (buffer)));
+        for _ in 1..rows {
+            left |= (left << (cols));
+        }
 
-    let right = left << (cols - buffer);
+        (left, left << (cols - buffer))
+    };
 
     let edges = top | bottom | left | right;
 
@@ -40,14 +51,13 @@ pub fn generate_edges<const T: usize>(buffer: EdgeBuffer, rows: Rows, cols: Cols
     }
 }
 
+/// Generates the edges for every buffer from `1` up to the largest dimension of the board, so that any leap can be checked against the edges it would cross.
 pub fn generate_edge_list<const T: usize>(rows: Rows, cols: Cols) -> Vec<Edges<T>> {
     let mut max_edge = rows;
-    if cols < max_edge {
+    if cols > max_edge {
         max_edge = cols;
     }
 
-    max_edge /= 2;
-
     let mut edges = Vec::with_capacity(max_edge as usize);
 
     for buffer in 1..(max_edge + 1) {
@@ -6,6 +6,7 @@ use super::super::{
     pieces::Piece,
     Board, Cols, Rows,
 };
+use crate::board::pieces::PieceSymbol;
 
 #[derive(Clone, Debug)]
 pub enum FenTeamArgument {
@@ -69,6 +70,56 @@ impl<const T: usize> FenArgument<T> for FenTurns {
     }
 }
 
+/// The turn the moving team is on, for games where teams make several turns per sub-move (see `Game::turns`.)
+#[derive(Debug)]
+pub struct FenCurrentTurn;
+
+impl<const T: usize> FenArgument<T> for FenCurrentTurn {
+    fn encode(&self, board: &Board<T>) -> String {
+        board.state.current_turn.to_string()
+    }
+
+    fn decode(&self, board: &mut Board<T>, arg: &str) -> Result<(), FenDecodeError> {
+        let current_turn = arg.parse::<u16>().map_err(|_| {
+            FenDecodeError::InvalidArgument(format!(
+                "'{arg}' is not a valid turn, as it isn't a positive integer."
+            ))
+        })?;
+
+        let turns = board.game.turns[board.state.moving_team as usize];
+        if current_turn >= turns {
+            return Err(FenDecodeError::InvalidArgument(format!(
+                "'{arg}' is not a valid turn, as the moving team only makes {turns} turns."
+            )));
+        }
+
+        board.state.current_turn = current_turn;
+        Ok(())
+    }
+}
+
+/// A field that's always written as `-`, for fields a game's FENs keep but don't use (eg. castling rights in Xiangqi.)
+#[derive(Debug)]
+pub struct FenUnused;
+
+impl<const T: usize> FenArgument<T> for FenUnused {
+    #[allow(unused_variables)]
+    fn encode(&self, board: &Board<T>) -> String {
+        "-".to_string()
+    }
+
+    #[allow(unused_variables)]
+    fn decode(&self, board: &mut Board<T>, arg: &str) -> Result<(), FenDecodeError> {
+        if arg != "-" {
+            return Err(FenDecodeError::InvalidArgument(format!(
+                "'{arg}' should be '-', as this field isn't used."
+            )));
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug)]
 pub struct FenSubMoves;
 
@@ -105,6 +156,142 @@ impl<const T: usize> FenArgument<T> for FenFullMoves {
     }
 }
 
+/// The pieces each team holds in reserve, such as `[QRp]`.
+/// Each piece is written the same way it would be on the board (so uppercase for the first team, and lowercase for the second), and may be prefixed by a count (eg. `[2Pn]`.)
+/// An empty reserve is written as `[]`, although `-` is also accepted when decoding.
+#[derive(Debug)]
+pub struct FenReserves;
+
+impl FenReserves {
+    pub fn encode_reserves<const T: usize>(board: &Board<T>) -> String {
+        let mut reserves = String::new();
+        for team in 0..board.state.reserves.len() {
+            for (piece_type, count) in board.state.reserves[team].iter().enumerate() {
+                let piece_str = match board.game.pieces[piece_type].get_piece_symbol() {
+                    PieceSymbol::Char(char) => {
+                        if board.state.teams.len() > 2 {
+                            format!("{}{{{}}}", char.to_ascii_lowercase(), team + 1)
+                        } else if team == 0 {
+                            char.to_ascii_uppercase().to_string()
+                        } else {
+                            char.to_ascii_lowercase().to_string()
+                        }
+                    }
+                    PieceSymbol::TeamSymbol(teams) => teams[team].to_string(),
+                    PieceSymbol::Prefixed(prefix, char) => {
+                        if team == 0 {
+                            format!("{prefix}{}", char.to_ascii_uppercase())
+                        } else {
+                            format!("{prefix}{}", char.to_ascii_lowercase())
+                        }
+                    }
+                };
+
+                for _ in 0..*count {
+                    reserves.push_str(&piece_str);
+                }
+            }
+        }
+
+        format!("[{reserves}]")
+    }
+
+    pub fn decode_reserves<const T: usize>(board: &mut Board<T>, arg: &str) -> Result<(), FenDecodeError> {
+        for team in board.state.reserves.iter_mut() {
+            team.iter_mut().for_each(|count| *count = 0);
+        }
+
+        if arg == "-" {
+            return Ok(());
+        }
+
+        let reserves = arg
+            .strip_prefix('[')
+            .and_then(|arg| arg.strip_suffix(']'))
+            .ok_or_else(|| {
+                FenDecodeError::InvalidArgument(format!(
+                    "'{arg}' is not a valid reserve, as it isn't surrounded by brackets."
+                ))
+            })?;
+
+        let chars = reserves.chars().collect::<Vec<_>>();
+        let mut i = 0;
+        while i < chars.len() {
+            let mut count = 0;
+            let count_start = i;
+            while i < chars.len() && chars[i].is_ascii_digit() {
+                count = (count * 10) + chars[i].to_digit(10).unwrap() as u16;
+                i += 1;
+            }
+            if i == count_start {
+                count = 1;
+            }
+
+            let char = *chars.get(i).ok_or_else(|| {
+                FenDecodeError::InvalidArgument(format!(
+                    "The reserve '{arg}' ends with a count, but no piece."
+                ))
+            })?;
+            i += 1;
+
+            let lower_char = char.to_ascii_lowercase();
+            let piece_type = board
+                .game
+                .pieces
+                .iter()
+                .position(|piece| match piece.get_piece_symbol() {
+                    PieceSymbol::Char(piece_char) => piece_char == lower_char,
+                    PieceSymbol::TeamSymbol(teams) => teams.contains(&char),
+                    PieceSymbol::Prefixed(_, _) => false,
+                })
+                .ok_or_else(|| {
+                    FenDecodeError::InvalidArgument(format!(
+                        "'{char}' in the reserve '{arg}' is not a valid piece."
+                    ))
+                })?;
+
+            let mut team = match board.game.pieces[piece_type].get_piece_symbol() {
+                PieceSymbol::TeamSymbol(teams) => teams.iter().position(|el| el == &char).unwrap_or(0),
+                _ => if char.is_ascii_uppercase() { 0 } else { 1 },
+            };
+
+            if chars.get(i) == Some(&'{') {
+                team = chars
+                    .get(i + 1)
+                    .and_then(|el| el.to_digit(10))
+                    .filter(|el| *el > 0 && chars.get(i + 2) == Some(&'}'))
+                    .ok_or_else(|| {
+                        FenDecodeError::InvalidArgument(format!(
+                            "The team of '{char}' in the reserve '{arg}' is invalid."
+                        ))
+                    })? as usize
+                    - 1;
+                i += 3;
+            }
+
+            if team >= board.state.reserves.len() {
+                return Err(FenDecodeError::InvalidArgument(format!(
+                    "'{char}' in the reserve '{arg}' belongs to a team that doesn't exist."
+                )));
+            }
+
+            board.state.reserves[team][piece_type] += count;
+        }
+
+        Ok(())
+    }
+}
+
+impl<const T: usize> FenArgument<T> for FenReserves {
+    fn encode(&self, board: &Board<T>) -> String {
+        FenReserves::encode_reserves(board)
+    }
+
+    fn decode(&self, board: &mut Board<T>, arg: &str) -> Result<(), FenDecodeError> {
+        FenReserves::decode_reserves(board, arg)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum FenDecodeError {
     InvalidArgument(String),
@@ -117,3 +304,64 @@ pub trait FenArgument<const T: usize> : Debug + Send + Sync {
     /// `decode` takes in a board and an existing argument, and will modify the board to meet the argument (eg. changing the team to reflect the given arg team of `w`)
     fn decode(&self, board: &mut Board<T>, arg: &str) -> Result<(), FenDecodeError>;
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::{actions::Move, game::{Game, NORMAL_MODE}},
+        games::chess::Chess,
+    };
+
+    use super::FenReserves;
+
+    fn create_reserve_chess() -> Game<1> {
+        let mut chess = Chess::create();
+        chess.fen_options.args.insert(0, ("reserves".to_string(), Box::new(FenReserves)));
+        chess
+    }
+
+    #[test]
+    fn reserves_fen() {
+        let chess = create_reserve_chess();
+
+        let board = chess.from_fen("4k3/8/8/8/8/8/8/4K3 [QRpp] w - - 0 1");
+        assert_eq!(board.state.reserves[0], vec![0, 0, 0, 1, 1, 0]);
+        assert_eq!(board.state.reserves[1], vec![2, 0, 0, 0, 0, 0]);
+        assert_eq!(board.to_fen(), "4k3/8/8/8/8/8/8/4K3 [RQpp] w - - 0 1");
+
+        let board = chess.from_fen("4k3/8/8/8/8/8/8/4K3 [2N3b] w - - 0 1");
+        assert_eq!(board.to_fen(), "4k3/8/8/8/8/8/8/4K3 [NNbbb] w - - 0 1");
+
+        let board = chess.from_fen("4k3/8/8/8/8/8/8/4K3 - w - - 0 1");
+        assert_eq!(board.to_fen(), "4k3/8/8/8/8/8/8/4K3 [] w - - 0 1");
+    }
+
+    #[test]
+    fn drop_moves() {
+        let chess = create_reserve_chess();
+        let mut board = chess.from_fen("4k3/8/8/8/8/8/8/4K3 [Qn] w - - 0 1");
+
+        // The king has 5 moves, and the queen can be dropped on any of the 62 empty squares.
+        assert_eq!(board.generate_drop_moves(NORMAL_MODE).len(), 62);
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert_eq!(moves.len(), 67);
+
+        for action in moves {
+            let state = board.state.clone();
+            let hash = chess.zobrist.compute(&board);
+
+            let undo = board.make_move(&action);
+            if let Move::Action(action) = action {
+                if action.from.is_none() {
+                    assert_eq!(board.state.reserves[0][4], 0);
+                    assert_ne!(chess.zobrist.compute(&board), hash);
+                }
+            }
+
+            board.undo_move(undo);
+            assert_eq!(board.state, state);
+        }
+
+        board.assert_perfts([67, 3135]);
+    }
+}
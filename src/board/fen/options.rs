@@ -17,7 +17,9 @@ pub enum FenStateTeams {
 
 #[derive(Debug)]
 pub struct FenState {
-    pub first_moves: bool, pub gaps: char
+    pub first_moves: bool, pub gaps: char,
+    /// Whether the board is followed by each team's reserves in brackets, like `RNBQKBNR[Qn]` in Crazyhouse.
+    pub reserves: bool
 }
 
 pub trait PostProcess<const T: usize> : Debug + Send + Sync {
@@ -1,4 +1,4 @@
-use crate::{board::pieces::PieceSymbol, bitboard::BitBoard};
+use crate::{board::{pieces::PieceSymbol, fen::FenReserves}, bitboard::BitBoard};
 
 use super::super::{
     actions::{Action, HistoryMove, UndoMoveError},
@@ -17,6 +17,12 @@ impl<'a, const T: usize> Board<'a, T> {
 
         let mut board = Board::empty(game, (rows, cols));
 
+        // Reserves are written after the board in brackets, eg. `RNBQKBNR[Qn]`.
+        let (fen, reserves) = match fen.find('[') {
+            Some(ind) if game.fen_options.state.reserves => (&fen[..ind], Some(&fen[ind..])),
+            _ => (fen, None),
+        };
+
         let mut board_ind = 0;
         for row in fen.split("/") {
             let chars = row.chars().collect::<Vec<_>>();
@@ -24,11 +30,14 @@ impl<'a, const T: usize> Board<'a, T> {
             while i < chars.len() {
                 let char = chars[i];
 
-                if char.is_numeric() {
-                    board_ind += char.to_digit(10).expect(&format!(
-                        "Could not convert {char} to an integer in FEN state."
-                    )) as u16;
-                    i += 1;
+                // Runs of empty squares can take more than one digit on boards wider than 9 squares (eg. `10` in Capablanca chess.)
+                if char.is_ascii_digit() {
+                    let mut empty_squares = 0;
+                    while i < chars.len() && chars[i].is_ascii_digit() {
+                        empty_squares = (empty_squares * 10) + chars[i].to_digit(10).unwrap() as u16;
+                        i += 1;
+                    }
+                    board_ind += empty_squares;
                     continue;
                 }
 
@@ -41,15 +50,25 @@ impl<'a, const T: usize> Board<'a, T> {
                     continue;
                 }
 
+                // Any other symbol (like the `+` in Shogi's `+P`) is a prefix for the piece after it.
+                let mut prefix = None;
+                let mut char = char;
+                if !char.is_alphanumeric() {
+                    prefix = Some(char);
+                    i += 1;
+                    char = *chars.get(i).expect(&format!("Could not find the piece after the prefix '{char}' in FEN state."));
+                }
+
                 let lower_char = char.to_ascii_lowercase();
                 
                 let piece_type = pieces
                     .iter()
                     .position(|piece| match piece.get_piece_symbol() {
-                        PieceSymbol::Char(char) => char == lower_char,
-                        PieceSymbol::TeamSymbol(teams) => teams.contains(&char),
+                        PieceSymbol::Char(char) => prefix.is_none() && char == lower_char,
+                        PieceSymbol::TeamSymbol(teams) => prefix.is_none() && teams.contains(&char),
+                        PieceSymbol::Prefixed(piece_prefix, char) => prefix == Some(piece_prefix) && char == lower_char,
                     })
-                    .expect(&format!("Could not find the piece type of '{char}'"));
+                    .expect(&format!("Could not find the piece type of '{}{char}'", prefix.map(|el| el.to_string()).unwrap_or_default()));
 
                 let mut team: u32 = if char.is_ascii_uppercase() { 0 } else { 1 };
 
@@ -63,6 +82,13 @@ impl<'a, const T: usize> Board<'a, T> {
                         as u32;
                 }
 
+                if let Some(next_char) = chars.get(i + 1) {
+                    if next_char == &'~' {
+                        board.state.promoted |= pos_board;
+                        i += 1;
+                    }
+                }
+
                 let mut first_move = true;
 
                 if let Some(next_char) = chars.get(i + 1) {
@@ -98,6 +124,12 @@ impl<'a, const T: usize> Board<'a, T> {
             }
         }
 
+        if let Some(reserves) = reserves {
+            if let Err(err) = FenReserves::decode_reserves(&mut board, reserves) {
+                panic!("Could not decode the reserves of '{reserves}' in FEN state: {:?}", err);
+            }
+        }
+
         board
     }
 
@@ -124,6 +156,10 @@ impl<'a, const T: usize> Board<'a, T> {
             let bitboard = BitBoard::from_lsb(i);
 
             if (self.state.gaps & bitboard).is_set() {
+                if empty_spaces > 0 {
+                    fen_state.push_str(&empty_spaces.to_string());
+                }
+                empty_spaces = 0;
                 fen_state.push(self.game.fen_options.state.gaps);
                 continue;
             }
@@ -155,10 +191,11 @@ impl<'a, const T: usize> Board<'a, T> {
 
             let first_move = (self.state.first_move & bitboard).is_set();
 
+            let many_teams = self.state.teams.len() > 2;
             let mut piece_str = match self.game.pieces[piece_type].get_piece_symbol() {
                 PieceSymbol::Char(char) => {
-                    if self.state.teams.len() > 2 {
-                        format!("{}{{{}}}", char.to_ascii_lowercase(), team)
+                    if many_teams {
+                        char.to_ascii_lowercase().to_string()
                     } else if team == 0 {
                         char.to_ascii_uppercase().to_string()
                     } else {
@@ -166,14 +203,32 @@ impl<'a, const T: usize> Board<'a, T> {
                     }
                 }
                 PieceSymbol::TeamSymbol(teams) => teams[team].to_string(),
+                PieceSymbol::Prefixed(prefix, char) => {
+                    if team == 0 {
+                        format!("{prefix}{}", char.to_ascii_uppercase())
+                    } else {
+                        format!("{prefix}{}", char.to_ascii_lowercase())
+                    }
+                }
             };
+            if (self.state.promoted & bitboard).is_set() {
+                piece_str.push('~');
+            }
             if self.game.fen_options.state.first_moves && !first_move {
                 piece_str.push_str("!");
             }
+            // With more than two teams, the team is written after the piece in braces, starting from `{1}`.
+            if many_teams {
+                piece_str.push_str(&format!("{{{}}}", team + 1));
+            }
 
             fen_state.push_str(&piece_str);
         }
 
+        if self.game.fen_options.state.reserves {
+            fen_state.push_str(&FenReserves::encode_reserves(self));
+        }
+
         fen_state
     }
 }
@@ -1,8 +1,12 @@
 use std::fmt::Debug;
 
 pub const NORMAL_MODE: u16 = 0;
+/// `ATTACKS_MODE` asks pieces for every square they attack (eg. for check detection), whether or not there's a piece there to capture.
+pub const ATTACKS_MODE: u16 = 1;
 
-use super::{actions::{Action, ActionInfo, TheoreticalAction, Move, TheoreticalMove, HistoryMove, TurnUpdate, CounterUpdate}, fen::FenOptions, pieces::Piece, Board, Rows, Cols, zobrist::ZobristHashTable, BoardState};
+use crate::bitboard::BitBoard;
+
+use super::{actions::{Action, ActionInfo, TheoreticalAction, Move, TheoreticalMove, HistoryMove, TurnUpdate, CounterUpdate}, fen::FenOptions, pieces::Piece, regions::Region, Board, Rows, Cols, zobrist::ZobristHashTable, BoardState};
 
 pub fn get_theoretical_moves_bound<const T: usize>(board: &Board<T>, max_info: ActionInfo, can_pass: bool) -> Vec<TheoreticalMove> {
     let mut theoretical_moves = Vec::with_capacity(((
@@ -42,8 +46,22 @@ pub trait MoveController<const T: usize> : Debug + Send + Sync {
     fn use_pseudolegal(&self) -> bool;
 
     fn add_moves(&self, board: &Board<T>, actions: &mut Vec<Move>) {}
+    /// How many counters this game keeps in `BoardState::counters`.
+    fn get_counters(&self) -> usize {
+        0
+    }
+    /// Makes a move of a piece already on the board. By default, this is handled by the moving piece itself.
+    fn make_move(&self, board: &mut Board<T>, action: &Action) -> Option<HistoryMove<T>> {
+        board.game.pieces[action.piece_type as usize].make_move(board, action)
+    }
+    /// Drop moves (moves with no `from` square) place a piece from the team's reserves onto the board.
+    /// By default, this is handled by the dropped piece itself.
     fn make_drop_move(&self, board: &mut Board<T>, action: &Action) -> Option<HistoryMove<T>> {
-        panic!("Drop moves aren't supported. Make sure to override `make_drop_move` in your game's MoveController to support them.");
+        board.game.pieces[action.piece_type as usize].make_drop_move(board, action)
+    }
+    /// The squares a king of `team` can't castle through, since they're attacked by its opponents.
+    fn get_castling_attacks(&self, board: &Board<T>, team: u16) -> BitBoard<T> {
+        board.get_move_mask(board.get_next_team(team), ATTACKS_MODE)
     }
 
     fn encode_action(&self, board: &Board<T>, action: &Move) -> Vec<String>;
@@ -92,7 +110,7 @@ pub trait MoveController<const T: usize> : Debug + Send + Sync {
         }
     }
 
-    fn update(&self, action: &Move, state: &BoardState<T>) -> TurnUpdate {
+    fn update(&self, action: &Move, state: &BoardState<T>, game: &Game<T>) -> TurnUpdate {
         TurnUpdate {
             turns: CounterUpdate::Next,
             sub_moves: CounterUpdate::Next,
@@ -117,7 +135,7 @@ pub trait Resolution<const T: usize> : Debug + Send + Sync {
 
 pub trait ZobristController<const T: usize> : Debug + Send + Sync {
     fn get_extra_hashes(&self) -> usize { 0 }
-    fn apply(&self, hash: &mut u64, zobrist: &mut ZobristHashTable<T>, board: &mut Board<T>) {}
+    fn apply(&self, hash: &mut u64, zobrist: &ZobristHashTable<T>, board: &Board<T>) {}
 }
 
 #[derive(Debug)]
@@ -132,16 +150,25 @@ pub struct Game<const T: usize> {
     pub fen_options: FenOptions<T>,
     pub name: String,
     pub teams: u16,
-    pub turns: u16,
+    /// How many turns each team makes per sub-move, indexed by team.
+    pub turns: Vec<u16>,
     pub rows: Rows,
     pub cols: Cols,
     pub squares: u16,
     pub saved_last_moves: u16,
+    /// Named areas of the board that pieces can be confined to (see `Region`.)
+    pub regions: Vec<Region<T>>,
     /// Anything not covered by first_moves, pieces, and gaps should be zobrist_info
     pub zobrist_controller: Box<dyn ZobristController<T>>,
     pub zobrist: ZobristHashTable<T>
 }
 
+impl<const T: usize> Game<T> {
+    pub fn get_region(&self, name: &str) -> Option<&Region<T>> {
+        self.regions.iter().find(|region| region.name == name)
+    }
+}
+
 impl<const T: usize> PartialEq for Game<T> {
     fn eq(&self, other: &Self) -> bool {
         self.name == other.name
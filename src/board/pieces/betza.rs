@@ -0,0 +1,497 @@
+use crate::{
+    bitboard::BitBoard,
+    board::{game::ATTACKS_MODE, AttackDirections, Board, PieceType},
+};
+
+use super::{
+    get_hop_attacks, get_hop_landing, get_ray_attacks, get_symmetric_offsets, leap_by_offset, orient_offset, ride_by_offset, Offset, Piece,
+    PieceSymbol,
+};
+
+/*
+    Betza notation describes a piece's movement as a list of "atoms", each optionally prefixed by modifiers.
+
+    Atoms:
+        W (1, 0), F (1, 1), D (2, 0), N (2, 1), A (2, 2), H (3, 0), C (3, 1), Z (3, 2), G (3, 3)
+        K = WF, R = WW, B = FF, Q = WWFF
+
+    Doubling an atom (eg. `NN`) makes it a rider, and a number after an atom limits how far it rides (eg. `R4`, or `W0` for an unlimited rider.)
+
+    Modifiers:
+        m - only moves to empty squares, c - only captures
+        n - a lame leaper, which can be blocked on the way to its destination (eg. `nN` for the Xiangqi horse)
+        p - an unlimited rider that must hop over exactly one piece (eg. `mRcpR` for the Xiangqi cannon)
+        g - an unlimited rider that lands directly behind the first piece it meets (eg. `gQ` for the grasshopper)
+        f, b, l, r - forwards, backwards, left and right
+        v, s - vertical and sideways moves
+    Directions can be paired up (eg. `fl` for forward-left, or `ff` for the narrow forward moves of an oblique atom.)
+    If a pairing doesn't describe any of the atom's moves (eg. `fsW`), each direction is used on its own instead.
+*/
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BetzaRange {
+    /// Jumps directly to the destination square.
+    Leap,
+    /// Jumps to the destination square, but can be blocked by any piece on the way there.
+    Lame,
+    /// Repeats the leap until it's blocked, for at most the given amount of steps (if any.)
+    Ride(Option<u16>),
+    /// Rides over exactly one piece, landing on any square past it up to and including the next piece.
+    Hop,
+    /// Rides up to the first piece, landing on the square directly past it.
+    Grasshop,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BetzaRestriction {
+    Any,
+    MoveOnly,
+    CaptureOnly,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BetzaMove {
+    pub offset: Offset,
+    pub range: BetzaRange,
+    pub restriction: BetzaRestriction,
+}
+
+#[derive(Debug, Clone)]
+pub enum BetzaParseError {
+    UnknownAtom(char),
+    UnknownModifier(char),
+    /// The modifier exists, but can't be used on this move (eg. `p` on a leaper.)
+    UnsupportedModifier(char),
+    InvalidRange(String),
+    DanglingModifiers(String),
+    NoMoves(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct BetzaPiece {
+    pub symbol: char,
+    pub notation: String,
+    pub moves: Vec<BetzaMove>,
+}
+
+fn get_atoms(atom: char) -> Option<(Vec<Offset>, bool)> {
+    Some(match atom {
+        'W' => (vec![(1, 0)], false),
+        'F' => (vec![(1, 1)], false),
+        'D' => (vec![(2, 0)], false),
+        'N' => (vec![(2, 1)], false),
+        'A' => (vec![(2, 2)], false),
+        'H' => (vec![(3, 0)], false),
+        'C' => (vec![(3, 1)], false),
+        'Z' => (vec![(3, 2)], false),
+        'G' => (vec![(3, 3)], false),
+        'K' => (vec![(1, 0), (1, 1)], false),
+        'R' => (vec![(1, 0)], true),
+        'B' => (vec![(1, 1)], true),
+        'Q' => (vec![(1, 0), (1, 1)], true),
+        _ => return None,
+    })
+}
+
+fn matches_direction(direction: char, (d_row, d_col): Offset) -> bool {
+    match direction {
+        'f' => d_row > 0,
+        'b' => d_row < 0,
+        'l' => d_col < 0,
+        'r' => d_col > 0,
+        'v' => d_row != 0 && d_row.abs() >= d_col.abs(),
+        's' => d_col != 0 && d_col.abs() >= d_row.abs(),
+        _ => false,
+    }
+}
+
+fn matches_direction_group(group: &[char], offset: Offset) -> bool {
+    match group {
+        [direction] => matches_direction(*direction, offset),
+        [first, second] if first == second => {
+            let (d_row, d_col) = offset;
+            matches_direction(*first, offset)
+                && match first {
+                    'f' | 'b' | 'v' => d_row.abs() >= d_col.abs(),
+                    _ => d_col.abs() >= d_row.abs(),
+                }
+        }
+        [first, second] => matches_direction(*first, offset) && matches_direction(*second, offset),
+        _ => false,
+    }
+}
+
+fn get_direction_groups(directions: &[char]) -> Vec<Vec<char>> {
+    let mut groups = vec![];
+    let mut i = 0;
+    while i < directions.len() {
+        let current = directions[i];
+        if let Some(next) = directions.get(i + 1) {
+            let vertical = matches!(current, 'f' | 'b' | 'v');
+            let horizontal = matches!(next, 'l' | 'r' | 's');
+            if (vertical && horizontal) || *next == current {
+                groups.push(vec![current, *next]);
+                i += 2;
+                continue;
+            }
+        }
+
+        groups.push(vec![current]);
+        i += 1;
+    }
+    groups
+}
+
+fn filter_offsets(offsets: Vec<Offset>, directions: &[char]) -> Vec<Offset> {
+    if directions.is_empty() {
+        return offsets;
+    }
+
+    let mut groups = vec![];
+    for group in get_direction_groups(directions) {
+        if group.len() > 1 && !offsets.iter().any(|offset| matches_direction_group(&group, *offset)) {
+            groups.extend(group.iter().map(|direction| vec![*direction]));
+        } else {
+            groups.push(group);
+        }
+    }
+
+    offsets
+        .into_iter()
+        .filter(|offset| groups.iter().any(|group| matches_direction_group(group, *offset)))
+        .collect()
+}
+
+/// Finds every square a lame leaper passes over on the way to its destination.
+/// It steps orthogonally along its longer side first, and then diagonally (so `nN` is blocked like a Xiangqi horse, and `nA` like a Xiangqi elephant.)
+fn get_lame_path((d_row, d_col): Offset) -> Vec<Offset> {
+    let long = d_row.abs().max(d_col.abs());
+    let short = d_row.abs().min(d_col.abs());
+
+    let mut path = Vec::with_capacity(long as usize);
+    let (mut row, mut col) = (0, 0);
+    for step in 1..long {
+        if step <= long - short {
+            if d_row.abs() >= d_col.abs() {
+                row += d_row.signum();
+            } else {
+                col += d_col.signum();
+            }
+        } else {
+            row += d_row.signum();
+            col += d_col.signum();
+        }
+        path.push((row, col));
+    }
+    path
+}
+
+impl BetzaPiece {
+    pub fn parse(symbol: char, notation: &str) -> Result<BetzaPiece, BetzaParseError> {
+        let chars = notation.chars().collect::<Vec<_>>();
+        let mut moves: Vec<BetzaMove> = vec![];
+        let mut modifiers: Vec<char> = vec![];
+
+        let mut i = 0;
+        while i < chars.len() {
+            let char = chars[i];
+            i += 1;
+
+            if char.is_ascii_lowercase() {
+                if !"mcnpgfblrsv".contains(char) {
+                    return Err(BetzaParseError::UnknownModifier(char));
+                }
+                modifiers.push(char);
+                continue;
+            }
+
+            let (atoms, mut rides) = get_atoms(char).ok_or(BetzaParseError::UnknownAtom(char))?;
+            let mut max_range: Option<u16> = None;
+
+            if chars.get(i) == Some(&char) {
+                rides = true;
+                i += 1;
+            } else {
+                let start = i;
+                while i < chars.len() && chars[i].is_ascii_digit() {
+                    i += 1;
+                }
+
+                if i > start {
+                    let range = chars[start..i].iter().collect::<String>();
+                    let range = range
+                        .parse::<u16>()
+                        .map_err(|_| BetzaParseError::InvalidRange(range.clone()))?;
+                    match range {
+                        0 => {
+                            rides = true;
+                        }
+                        1 => {}
+                        _ => {
+                            rides = true;
+                            max_range = Some(range);
+                        }
+                    }
+                }
+            }
+
+            let restriction = match (modifiers.contains(&'m'), modifiers.contains(&'c')) {
+                (true, false) => BetzaRestriction::MoveOnly,
+                (false, true) => BetzaRestriction::CaptureOnly,
+                _ => BetzaRestriction::Any,
+            };
+
+            let hop = modifiers.iter().find(|modifier| "pg".contains(**modifier)).copied();
+            if let Some(hop) = hop {
+                if !rides || max_range.is_some() {
+                    return Err(BetzaParseError::UnsupportedModifier(hop));
+                }
+            }
+
+            let range = if hop == Some('p') {
+                BetzaRange::Hop
+            } else if hop == Some('g') {
+                BetzaRange::Grasshop
+            } else if rides {
+                BetzaRange::Ride(max_range)
+            } else if modifiers.contains(&'n') {
+                BetzaRange::Lame
+            } else {
+                BetzaRange::Leap
+            };
+
+            let directions = modifiers
+                .iter()
+                .filter(|modifier| "fblrsv".contains(**modifier))
+                .copied()
+                .collect::<Vec<_>>();
+
+            for atom in atoms {
+                for offset in filter_offsets(get_symmetric_offsets(atom), &directions) {
+                    let betza_move = BetzaMove { offset, range, restriction };
+                    if !moves.contains(&betza_move) {
+                        moves.push(betza_move);
+                    }
+                }
+            }
+
+            modifiers.clear();
+        }
+
+        if !modifiers.is_empty() {
+            return Err(BetzaParseError::DanglingModifiers(modifiers.iter().collect()));
+        }
+
+        if moves.is_empty() {
+            return Err(BetzaParseError::NoMoves(notation.to_string()));
+        }
+
+        Ok(BetzaPiece {
+            symbol: symbol.to_ascii_lowercase(),
+            notation: notation.to_string(),
+            moves,
+        })
+    }
+
+    /// Each team has two lookups for all of its plain leaps (ones that can move, and ones that can capture), followed by two lookups for every move.
+    fn get_team_stride(&self) -> usize {
+        2 + (2 * self.moves.len())
+    }
+}
+
+impl<const T: usize> Piece<T> for BetzaPiece {
+    fn get_piece_symbol(&self) -> PieceSymbol {
+        PieceSymbol::Char(self.symbol)
+    }
+
+    fn can_lookup(&self) -> bool {
+        true
+    }
+
+    fn generate_lookup_moves(&self, board: &Board<T>, from: BitBoard<T>) -> AttackDirections<T> {
+        let mut lookups = Vec::with_capacity(self.get_team_stride() * board.game.teams as usize);
+
+        for team in 0..board.game.teams {
+            let mut quiet_leaps = BitBoard::new();
+            let mut capture_leaps = BitBoard::new();
+            let mut move_lookups = Vec::with_capacity(2 * self.moves.len());
+
+            for betza_move in &self.moves {
+                let offset = orient_offset(betza_move.offset, team, board.game.teams);
+                let (primary, secondary) = match betza_move.range {
+                    BetzaRange::Leap => {
+                        let leap = leap_by_offset(board, from, offset);
+                        if betza_move.restriction != BetzaRestriction::CaptureOnly {
+                            quiet_leaps |= leap;
+                        }
+                        if betza_move.restriction != BetzaRestriction::MoveOnly {
+                            capture_leaps |= leap;
+                        }
+                        (leap, BitBoard::new())
+                    }
+                    BetzaRange::Lame => {
+                        let leap = leap_by_offset(board, from, offset);
+                        let mut path = BitBoard::new();
+                        if leap.is_set() {
+                            for step in get_lame_path(offset) {
+                                path |= leap_by_offset(board, from, step);
+                            }
+                        }
+                        (leap, path)
+                    }
+                    BetzaRange::Ride(max_range) => (
+                        ride_by_offset(board, from, offset, None),
+                        ride_by_offset(board, from, offset, max_range),
+                    ),
+                    BetzaRange::Hop | BetzaRange::Grasshop => (
+                        ride_by_offset(board, from, offset, None),
+                        BitBoard::new(),
+                    ),
+                };
+
+                move_lookups.push(primary);
+                move_lookups.push(secondary);
+            }
+
+            lookups.push(quiet_leaps);
+            lookups.push(capture_leaps);
+            lookups.extend(move_lookups);
+        }
+
+        lookups
+    }
+
+    fn get_moves(
+        &self,
+        board: &Board<T>,
+        from: BitBoard<T>,
+        piece_type: PieceType,
+        team: u16,
+        mode: u16,
+    ) -> BitBoard<T> {
+        let lookup = self
+            .get_attack_lookup(board, piece_type)
+            .expect("Could not find the Betza piece attack lookup.");
+
+        let from_bit = from.bitscan_forward() as usize;
+        let base = (team as usize) * self.get_team_stride();
+        let directions = &lookup[from_bit];
+        let occupied = board.state.all_pieces;
+        let attacks_only = mode == ATTACKS_MODE;
+
+        let mut moves = if attacks_only {
+            directions[base + 1]
+        } else {
+            (directions[base] & !occupied) | (directions[base + 1] & occupied)
+        };
+
+        for (ind, betza_move) in self.moves.iter().enumerate() {
+            let dir = base + 2 + (2 * ind);
+            let targets = match betza_move.range {
+                BetzaRange::Leap => continue,
+                BetzaRange::Lame => {
+                    if (directions[dir + 1] & occupied).is_set() {
+                        continue;
+                    }
+                    directions[dir]
+                }
+                BetzaRange::Ride(_) => {
+                    get_ray_attacks(board, from, from_bit, dir as u32, lookup) & directions[dir + 1]
+                }
+                BetzaRange::Hop => get_hop_attacks(board, from, from_bit, dir as u32, lookup),
+                BetzaRange::Grasshop => get_hop_landing(board, from, from_bit, dir as u32, lookup),
+            };
+
+            moves |= match (betza_move.restriction, attacks_only) {
+                (BetzaRestriction::MoveOnly, true) => BitBoard::new(),
+                (_, true) | (BetzaRestriction::Any, false) => targets,
+                (BetzaRestriction::MoveOnly, false) => targets & !occupied,
+                (BetzaRestriction::CaptureOnly, false) => targets & occupied,
+            };
+        }
+
+        moves
+    }
+}
+
+/// Builds a piece from the given Betza notation (eg. `"WN"`, `"mRcB"`, `"fsW"` or `"nN"`.)
+/// `Game` needs its pieces to be `'static`, so you'll likely want to `Box::leak` the result when adding it to a game.
+pub fn build_betza_piece<const T: usize>(
+    symbol: char,
+    notation: &str,
+) -> Result<Box<dyn Piece<T>>, BetzaParseError> {
+    Ok(Box::new(BetzaPiece::parse(symbol, notation)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{board::pieces::Piece, games::chess::Chess};
+
+    use super::{BetzaPiece, BetzaRange, BetzaRestriction};
+
+    #[test]
+    fn parse_directions() {
+        let piece = BetzaPiece::parse('x', "fsW").unwrap();
+        assert_eq!(piece.moves.len(), 3);
+        assert!(piece.moves.iter().all(|el| el.offset.0 >= 0));
+
+        let piece = BetzaPiece::parse('x', "ffN").unwrap();
+        let mut offsets = piece.moves.iter().map(|el| el.offset).collect::<Vec<_>>();
+        offsets.sort();
+        assert_eq!(offsets, vec![(2, -1), (2, 1)]);
+
+        let piece = BetzaPiece::parse('x', "fN").unwrap();
+        assert_eq!(piece.moves.len(), 4);
+
+        assert!(BetzaPiece::parse('x', "Y").is_err());
+        assert!(BetzaPiece::parse('x', "Wf").is_err());
+    }
+
+    #[test]
+    fn parse_modifiers() {
+        let piece = BetzaPiece::parse('x', "mRcB").unwrap();
+        assert!(piece.moves.iter().all(|el| match el.offset.0.abs() == el.offset.1.abs() {
+            true => el.restriction == BetzaRestriction::CaptureOnly,
+            false => el.restriction == BetzaRestriction::MoveOnly,
+        }));
+
+        let piece = BetzaPiece::parse('x', "nN").unwrap();
+        assert!(piece.moves.iter().all(|el| el.range == BetzaRange::Lame));
+
+        let piece = BetzaPiece::parse('x', "R4").unwrap();
+        assert!(piece.moves.iter().all(|el| el.range == BetzaRange::Ride(Some(4))));
+
+        let piece = BetzaPiece::parse('x', "NN").unwrap();
+        assert!(piece.moves.iter().all(|el| el.range == BetzaRange::Ride(None)));
+    }
+
+    #[test]
+    fn betza_chess_perft() {
+        let mut chess = Chess::create();
+        for (ind, (symbol, notation)) in [('n', "N"), ('b', "B"), ('r', "R"), ('q', "Q")].iter().enumerate() {
+            let piece: &'static dyn Piece<1> = Box::leak(Box::new(BetzaPiece::parse(*symbol, notation).unwrap()));
+            chess.pieces[ind + 1] = piece;
+        }
+
+        chess.default().assert_perfts([20, 400, 8902]);
+        chess
+            .from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1")
+            .assert_perfts([48, 2039]);
+    }
+
+    #[test]
+    fn betza_restrictions() {
+        let mut chess = Chess::create();
+        let piece: &'static dyn Piece<1> = Box::leak(Box::new(BetzaPiece::parse('q', "mRcB").unwrap()));
+        chess.pieces[4] = piece;
+
+        // The white "queen" on d4 can move to the 14 empty squares on its rank and file, and can only capture diagonally on c5 and f6.
+        let mut board = chess.from_fen("4k3/8/5p2/2p5/3Q4/8/8/4K3 w - - 0 1");
+        let moves = board.generate_legal_moves(0);
+        let queen_moves = moves.iter().filter(|el| match el {
+            crate::board::actions::Move::Action(action) => action.piece_type == 4,
+            _ => false,
+        }).count();
+        assert_eq!(queen_moves, 16);
+    }
+}
@@ -0,0 +1,344 @@
+use crate::{
+    bitboard::BitBoard,
+    board::{
+        actions::{Action, HistoryMove, HistoryState, HistoryUpdate, IndexedPreviousBoard, PreviousBoard, Move, TurnInfo},
+        game::{ATTACKS_MODE, NORMAL_MODE},
+        AttackDirections, Board, BoardState, PieceType,
+    },
+};
+
+use super::{BetzaPiece, Piece, PieceSymbol};
+
+/// How a piece captures opposing pieces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaptureStyle {
+    /// Moves onto the captured piece's square, like every piece in chess.
+    Replace,
+    /// Trades places with the opposing piece it moves onto, instead of capturing it.
+    Swap,
+    /// Moves to an empty square, capturing the piece right past it in the direction it moved (eg. the approach captures of Fanorona.)
+    Approach,
+    /// Moves to an empty square, capturing the piece right behind the square it left, if it moved directly away from it (eg. Ultima's withdrawer.)
+    Withdrawal,
+    /// Moves to an empty square, capturing any piece it sandwiches against another piece of its team along a rank or file (eg. Ultima's pincer pawn, or tafl.)
+    Custodian,
+    /// Moves to an empty square, capturing the pieces where its rank and file cross the file and rank of its team's piece of the given type (eg. Ultima's coordinator and king.)
+    Coordinator(PieceType),
+}
+
+/// A piece that moves like `movement`, but captures with the given `style`.
+/// Every style other than `Replace` and `Swap` can only move to empty squares, and captures the pieces around it once it's there.
+#[derive(Debug)]
+pub struct CapturingPiece {
+    pub movement: BetzaPiece,
+    pub style: CaptureStyle,
+}
+
+impl CapturingPiece {
+    pub fn new(movement: BetzaPiece, style: CaptureStyle) -> CapturingPiece {
+        CapturingPiece { movement, style }
+    }
+
+    /// Finds the opposing pieces that are captured when this piece of `team` moves from `from` to the empty square `to`.
+    pub fn get_captures<const T: usize>(&self, state: &BoardState<T>, from: u16, to: u16, team: u16) -> BitBoard<T> {
+        let mut opponents = BitBoard::new();
+        for (ind, team_board) in state.teams.iter().enumerate() {
+            if ind != team as usize {
+                opponents |= *team_board;
+            }
+        }
+        // The piece has already left the square it moved from.
+        let allies = state.teams[team as usize] & !BitBoard::from_lsb(from);
+
+        let (rows, cols) = (state.rows as i32, state.cols as i32);
+        let square_at = |row: i32, col: i32| -> BitBoard<T> {
+            if row < 0 || col < 0 || row >= rows || col >= cols {
+                BitBoard::new()
+            } else {
+                BitBoard::from_lsb((row * cols + col) as u16)
+            }
+        };
+
+        let (from_row, from_col) = ((from as i32) / cols, (from as i32) % cols);
+        let (to_row, to_col) = ((to as i32) / cols, (to as i32) % cols);
+        let (d_row, d_col) = (to_row - from_row, to_col - from_col);
+        // Approach and withdrawal captures only happen along the line the piece moved on.
+        let in_line = d_row == 0 || d_col == 0 || d_row.abs() == d_col.abs();
+        let (step_row, step_col) = (d_row.signum(), d_col.signum());
+
+        let captures = match self.style {
+            CaptureStyle::Replace | CaptureStyle::Swap => BitBoard::new(),
+            CaptureStyle::Approach if in_line => square_at(to_row + step_row, to_col + step_col),
+            CaptureStyle::Withdrawal if in_line => square_at(from_row - step_row, from_col - step_col),
+            CaptureStyle::Approach | CaptureStyle::Withdrawal => BitBoard::new(),
+            CaptureStyle::Custodian => {
+                let mut captures = BitBoard::new();
+                for (step_row, step_col) in [(0, 1), (0, -1), (1, 0), (-1, 0)] {
+                    let captured = square_at(to_row + step_row, to_col + step_col);
+                    let partner = square_at(to_row + (2 * step_row), to_col + (2 * step_col));
+                    if (captured & opponents).is_set() && (partner & allies).is_set() {
+                        captures |= captured;
+                    }
+                }
+                captures
+            }
+            CaptureStyle::Coordinator(partner_type) => {
+                let partner = state.pieces[partner_type as usize] & allies;
+                if partner.is_empty() {
+                    BitBoard::new()
+                } else {
+                    let partner = partner.bitscan_forward() as i32;
+                    let (partner_row, partner_col) = (partner / cols, partner % cols);
+                    square_at(to_row, partner_col) | square_at(partner_row, to_col)
+                }
+            }
+        };
+
+        captures & opponents
+    }
+}
+
+impl<const T: usize> Piece<T> for CapturingPiece {
+    fn get_piece_symbol(&self) -> PieceSymbol {
+        Piece::<T>::get_piece_symbol(&self.movement)
+    }
+
+    fn can_lookup(&self) -> bool {
+        true
+    }
+
+    fn generate_lookup_moves(&self, board: &Board<T>, from: BitBoard<T>) -> AttackDirections<T> {
+        Piece::<T>::generate_lookup_moves(&self.movement, board, from)
+    }
+
+    fn get_moves(
+        &self,
+        board: &Board<T>,
+        from: BitBoard<T>,
+        piece_type: PieceType,
+        team: u16,
+        mode: u16,
+    ) -> BitBoard<T> {
+        let moves = Piece::<T>::get_moves(&self.movement, board, from, piece_type, team, mode);
+        match self.style {
+            CaptureStyle::Replace => moves,
+            // Swapping places with a piece doesn't capture it, so it doesn't attack anything.
+            CaptureStyle::Swap if mode == ATTACKS_MODE => BitBoard::new(),
+            CaptureStyle::Swap => moves,
+            _ if mode == ATTACKS_MODE => {
+                // The squares this piece attacks are the ones it would capture on after any of its moves.
+                let from_bit = from.bitscan_forward();
+                let quiet_moves = Piece::<T>::get_moves(&self.movement, board, from, piece_type, team, NORMAL_MODE)
+                    & !board.state.all_pieces & !board.state.gaps;
+
+                let mut attacks = BitBoard::new();
+                for to in quiet_moves.iter_set_bits(board.state.squares) {
+                    attacks |= self.get_captures(&board.state, from_bit, to, team);
+                }
+                attacks
+            }
+            _ => moves & !board.state.all_pieces,
+        }
+    }
+
+    fn is_capture(&self, state: &BoardState<T>, action: &Action) -> bool {
+        match self.style {
+            CaptureStyle::Replace => Piece::<T>::is_capture(&self.movement, state, action),
+            // The swapped piece stays on the board.
+            CaptureStyle::Swap => false,
+            _ => match action.from {
+                Some(from) => self.get_captures(state, from, action.to, action.team).is_set(),
+                // Dropping a piece doesn't capture anything.
+                None => false,
+            },
+        }
+    }
+
+    fn make_capture_move(
+        &self,
+        board: &mut Board<T>,
+        action: &Action,
+        piece_type: PieceType,
+        from: BitBoard<T>,
+        to: BitBoard<T>,
+        turn_info: TurnInfo
+    ) -> Option<HistoryMove<T>> {
+        if self.style != CaptureStyle::Swap {
+            return Piece::<T>::make_capture_move(&self.movement, board, action, piece_type, from, to, turn_info);
+        }
+
+        let color = action.team as usize;
+        let piece_type = piece_type as usize;
+        let swapped_color = board.state.get_team(to).expect("The swapped piece should belong to a team.");
+        let swapped_piece_type = (0..board.game.pieces.len())
+            .find(|&ind| (board.state.pieces[ind] & to).is_set())
+            .expect("The swapped piece should have a piece type.");
+
+        let history_move = HistoryMove {
+            action: Move::Action(*action),
+            first_history_move: board.retrieve_first_history_move(Move::Action(*action)),
+            turn_info,
+            state: HistoryState::Any {
+                all_pieces: PreviousBoard(board.state.all_pieces),
+                first_move: PreviousBoard(board.state.first_move),
+                updates: vec![
+                    HistoryUpdate::Team(IndexedPreviousBoard(color, board.state.teams[color])),
+                    HistoryUpdate::Team(IndexedPreviousBoard(swapped_color, board.state.teams[swapped_color])),
+                    HistoryUpdate::Piece(IndexedPreviousBoard(piece_type, board.state.pieces[piece_type])),
+                    HistoryUpdate::Piece(IndexedPreviousBoard(swapped_piece_type, board.state.pieces[swapped_piece_type])),
+                ],
+            },
+        };
+
+        // Both squares stay occupied, so only the pieces on them change.
+        let squares = from | to;
+        board.state.teams[color] ^= squares;
+        board.state.teams[swapped_color] ^= squares;
+        if piece_type != swapped_piece_type {
+            board.state.pieces[piece_type] ^= squares;
+            board.state.pieces[swapped_piece_type] ^= squares;
+        }
+
+        board.state.first_move &= !squares;
+
+        Some(history_move)
+    }
+
+    fn make_normal_move(
+        &self,
+        board: &mut Board<T>,
+        action: &Action,
+        piece_type: PieceType,
+        from: BitBoard<T>,
+        to: BitBoard<T>,
+        turn_info: TurnInfo
+    ) -> Option<HistoryMove<T>> {
+        let captures = self.get_captures(&board.state, from.bitscan_forward(), action.to, action.team);
+        if captures.is_empty() {
+            return Piece::<T>::make_normal_move(&self.movement, board, action, piece_type, from, to, turn_info);
+        }
+
+        // The captured pieces are saved before the move is made, so undoing it restores them no matter which order the updates are applied in.
+        let mut updates = vec![];
+        for captured in captures.iter_set_bits(board.state.squares) {
+            let captured = BitBoard::from_lsb(captured);
+            let captured_color = board.state.get_team(captured).expect("The captured piece should belong to a team.");
+            let captured_piece_type = (0..board.game.pieces.len())
+                .find(|&ind| (board.state.pieces[ind] & captured).is_set())
+                .expect("The captured piece should have a piece type.");
+
+            updates.push(HistoryUpdate::Team(IndexedPreviousBoard(captured_color, board.state.teams[captured_color])));
+            updates.push(HistoryUpdate::Piece(IndexedPreviousBoard(captured_piece_type, board.state.pieces[captured_piece_type])));
+        }
+
+        let mut history_move = Piece::<T>::make_normal_move(&self.movement, board, action, piece_type, from, to, turn_info)?;
+        history_move.add_updates(updates);
+
+        for team_board in board.state.teams.iter_mut() {
+            *team_board &= !captures;
+        }
+        for piece_board in board.state.pieces.iter_mut() {
+            *piece_board &= !captures;
+        }
+        board.state.all_pieces &= !captures;
+        board.state.first_move &= !captures;
+
+        Some(history_move)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::{game::{Game, ATTACKS_MODE, NORMAL_MODE}, pieces::{test_utils::leak, BetzaPiece}, Board},
+        games::chess::{pieces::{KING_PIECE_TYPE, QUEEN_PIECE_TYPE}, Chess},
+    };
+
+    use super::{CaptureStyle, CapturingPiece};
+
+    fn create_chess(style: CaptureStyle) -> Game<1> {
+        let mut chess = Chess::create();
+        chess.pieces[QUEEN_PIECE_TYPE as usize] = leak(CapturingPiece::new(BetzaPiece::parse('q', "Q").unwrap(), style));
+        chess
+    }
+
+    /// Makes `action`, checks the position it leads to, and then checks that undoing it brings back the original position.
+    fn assert_move(board: &mut Board<1>, action: &str, fen: &str) {
+        let original = board.to_fen();
+        let action = board.decode_action(action, NORMAL_MODE).expect("The move should be generated.");
+        let undo = board.make_move(&action);
+        assert_eq!(board.to_fen(), fen);
+        board.undo_move(undo);
+        assert_eq!(board.to_fen(), original);
+    }
+
+    #[test]
+    fn swap() {
+        let chess = create_chess(CaptureStyle::Swap);
+        let mut board = chess.from_fen("4k3/8/8/8/3p4/8/8/3QK3 w - - 0 1");
+        assert_move(&mut board, "d1d4", "4k3/8/8/8/3Q4/8/8/3pK3 b - - 1 1");
+    }
+
+    #[test]
+    fn approach_and_withdrawal() {
+        let chess = create_chess(CaptureStyle::Approach);
+        let mut board = chess.from_fen("4k3/8/8/3p4/8/8/8/3QK3 w - - 0 1");
+        // The pawn can't be captured by moving onto it.
+        assert!(board.decode_action("d1d5", NORMAL_MODE).is_none());
+        assert_move(&mut board, "d1d4", "4k3/8/8/8/3Q4/8/8/4K3 b - - 0 1");
+        assert_move(&mut board, "d1d3", "4k3/8/8/3p4/8/3Q4/8/4K3 b - - 1 1");
+
+        let chess = create_chess(CaptureStyle::Withdrawal);
+        let mut board = chess.from_fen("4k3/8/8/3p4/3Q4/8/8/4K3 w - - 0 1");
+        assert_move(&mut board, "d4d1", "4k3/8/8/8/8/8/8/3QK3 b - - 0 1");
+        assert_move(&mut board, "d4a4", "4k3/8/8/3p4/Q7/8/8/4K3 b - - 1 1");
+    }
+
+    #[test]
+    fn custodian() {
+        let chess = create_chess(CaptureStyle::Custodian);
+        let mut board = chess.from_fen("4k3/8/8/3p4/1Q1pP3/8/8/4K3 w - - 0 1");
+        // The pawn on d4 is sandwiched between the queen and the pawn on e4, but the pawn on d5 isn't.
+        assert_move(&mut board, "b4c4", "4k3/8/8/3p4/2Q1P3/8/8/4K3 b - - 0 1");
+    }
+
+    #[test]
+    fn coordinator() {
+        let chess = create_chess(CaptureStyle::Coordinator(KING_PIECE_TYPE));
+
+        // The queen's rank crosses the king's file on e4, and its file crosses the king's rank on b1.
+        let mut board = chess.from_fen("4k3/8/8/8/Q3p3/8/8/1n2K3 w - - 0 1");
+        assert_move(&mut board, "a4b4", "4k3/8/8/8/1Q6/8/8/4K3 b - - 0 1");
+    }
+
+    #[test]
+    fn undo_every_style() {
+        for style in [
+            CaptureStyle::Swap,
+            CaptureStyle::Approach,
+            CaptureStyle::Withdrawal,
+            CaptureStyle::Custodian,
+            CaptureStyle::Coordinator(KING_PIECE_TYPE),
+        ] {
+            let chess = create_chess(style);
+            let mut board = chess.from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1");
+            let state = board.state.clone();
+            board.perft(3, true);
+            assert_eq!(board.state, state);
+        }
+    }
+
+    #[test]
+    fn attacks() {
+        // The queen attacks the king, since it could capture it by approaching it along the e file.
+        let chess = create_chess(CaptureStyle::Approach);
+        let board = chess.from_fen("4k3/8/8/8/8/8/8/4QK2 b - - 0 1");
+        let king = board.state.pieces[KING_PIECE_TYPE as usize] & board.state.teams[1];
+        assert!(board.can_move(0, king, ATTACKS_MODE));
+
+        // Swapping places with the king doesn't capture it.
+        let chess = create_chess(CaptureStyle::Swap);
+        let board = chess.from_fen("4k3/8/8/8/8/8/8/4QK2 b - - 0 1");
+        assert!(!board.can_move(0, king, ATTACKS_MODE));
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{
+    bitboard::BitBoard,
+    board::{AttackDirections, Board, PieceType},
+};
+
+use super::{BetzaPiece, Piece, PieceSymbol};
+
+/// A piece that can only move within one of the game's regions (eg. the Xiangqi general, which can't leave its palace.)
+/// `region` is the index of the region in `Game::regions`.
+#[derive(Debug)]
+pub struct ConfinedPiece {
+    pub movement: BetzaPiece,
+    pub region: usize,
+}
+
+impl ConfinedPiece {
+    pub fn new(movement: BetzaPiece, region: usize) -> ConfinedPiece {
+        ConfinedPiece { movement, region }
+    }
+}
+
+impl<const T: usize> Piece<T> for ConfinedPiece {
+    fn get_piece_symbol(&self) -> PieceSymbol {
+        Piece::<T>::get_piece_symbol(&self.movement)
+    }
+
+    fn can_lookup(&self) -> bool {
+        true
+    }
+
+    fn generate_lookup_moves(&self, board: &Board<T>, from: BitBoard<T>) -> AttackDirections<T> {
+        Piece::<T>::generate_lookup_moves(&self.movement, board, from)
+    }
+
+    fn get_moves(
+        &self,
+        board: &Board<T>,
+        from: BitBoard<T>,
+        piece_type: PieceType,
+        team: u16,
+        mode: u16,
+    ) -> BitBoard<T> {
+        Piece::<T>::get_moves(&self.movement, board, from, piece_type, team, mode)
+            & board.game.regions[self.region].get(team)
+    }
+}
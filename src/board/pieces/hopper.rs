@@ -0,0 +1,211 @@
+use crate::{
+    bitboard::BitBoard,
+    board::{game::ATTACKS_MODE, AttackDirections, AttackLookup, Board, PieceType},
+};
+
+use super::{
+    generate_ray_lookups, get_hop_attacks, get_hop_landing, get_ray_attacks, orient_offset, Offset,
+    Piece, PieceSymbol,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HopStyle {
+    /// Moves like a rider, but can only capture by jumping over exactly one piece (eg. the Xiangqi cannon.)
+    Cannon,
+    /// Must jump over the first piece in its path, landing on the square directly behind it (eg. the grasshopper.)
+    Grasshopper,
+}
+
+/// A piece that moves along its `directions` by hopping over other pieces.
+/// Directions are written from the first team's perspective; see `Offset`.
+#[derive(Debug)]
+pub struct HopperPiece {
+    pub symbol: char,
+    pub directions: Vec<Offset>,
+    pub style: HopStyle,
+}
+
+impl HopperPiece {
+    pub fn new(symbol: char, directions: Vec<Offset>, style: HopStyle) -> HopperPiece {
+        HopperPiece {
+            symbol: symbol.to_ascii_lowercase(),
+            directions,
+            style,
+        }
+    }
+
+    /// Each team has a ray for every direction, followed by all of those rays combined.
+    fn get_team_stride(&self) -> usize {
+        self.directions.len() + 1
+    }
+
+    fn get_direction_moves<const T: usize>(
+        &self,
+        board: &Board<T>,
+        from: BitBoard<T>,
+        from_bit: usize,
+        dir: u32,
+        lookup: &AttackLookup<T>,
+        mode: u16,
+    ) -> BitBoard<T> {
+        match self.style {
+            HopStyle::Cannon => {
+                let hops = get_hop_attacks(board, from, from_bit, dir, lookup);
+                if mode == ATTACKS_MODE {
+                    hops
+                } else {
+                    let occupied = board.state.all_pieces;
+                    (get_ray_attacks(board, from, from_bit, dir, lookup) & !occupied) | (hops & occupied)
+                }
+            }
+            HopStyle::Grasshopper => get_hop_landing(board, from, from_bit, dir, lookup),
+        }
+    }
+}
+
+impl<const T: usize> Piece<T> for HopperPiece {
+    fn get_piece_symbol(&self) -> PieceSymbol {
+        PieceSymbol::Char(self.symbol)
+    }
+
+    fn can_lookup(&self) -> bool {
+        true
+    }
+
+    fn generate_lookup_moves(&self, board: &Board<T>, from: BitBoard<T>) -> AttackDirections<T> {
+        let mut lookups = Vec::with_capacity(self.get_team_stride() * board.game.teams as usize);
+        for team in 0..board.game.teams {
+            let directions = self
+                .directions
+                .iter()
+                .map(|direction| orient_offset(*direction, team, board.game.teams))
+                .collect::<Vec<_>>();
+            lookups.extend(generate_ray_lookups(board, from, &directions));
+        }
+        lookups
+    }
+
+    #[allow(unused_variables)]
+    fn can_move_mask(
+        &self,
+        board: &Board<T>,
+        from: BitBoard<T>,
+        from_bit: u16,
+        piece_type: PieceType,
+        team: u16,
+        mode: u16,
+        to: BitBoard<T>,
+    ) -> BitBoard<T> {
+        let lookup = self
+            .get_attack_lookup(board, piece_type)
+            .expect("Could not find the hopper attack lookup.");
+
+        let from_bit = from_bit as usize;
+        let base = (team as usize) * self.get_team_stride();
+        let directions = &lookup[from_bit];
+        if (directions[base + self.directions.len()] & to).is_empty() {
+            return BitBoard::new();
+        }
+
+        let mut moves = BitBoard::new();
+        let team_directions = &directions[base..(base + self.directions.len())];
+        for (dir, &ray) in (base..).zip(team_directions) {
+            if (ray & to).is_set() {
+                moves |= self.get_direction_moves(board, from, from_bit, dir as u32, lookup, mode);
+            }
+        }
+
+        moves
+    }
+
+    fn get_moves(
+        &self,
+        board: &Board<T>,
+        from: BitBoard<T>,
+        piece_type: PieceType,
+        team: u16,
+        mode: u16,
+    ) -> BitBoard<T> {
+        let lookup = self
+            .get_attack_lookup(board, piece_type)
+            .expect("Could not find the hopper attack lookup.");
+
+        let from_bit = from.bitscan_forward() as usize;
+        let base = (team as usize) * self.get_team_stride();
+
+        let mut moves = BitBoard::new();
+        for dir in base..(base + self.directions.len()) {
+            moves |= self.get_direction_moves(board, from, from_bit, dir as u32, lookup, mode);
+        }
+
+        moves
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::{actions::Move, game::ATTACKS_MODE, pieces::{test_utils::{count_piece_moves, leak}, BetzaPiece}},
+        games::chess::{
+            pieces::{QUEEN_DIRECTIONS, ROOK_DIRECTIONS},
+            Chess,
+        },
+    };
+
+    use super::{HopStyle, HopperPiece};
+
+    #[test]
+    fn cannon_moves() {
+        let mut chess = Chess::create();
+        chess.pieces[2] = leak(HopperPiece::new('b', ROOK_DIRECTIONS.to_vec(), HopStyle::Cannon));
+
+        // The cannon on a1 can move to a2 and b1-g1, and capture on a6 by jumping over a3.
+        let fen = "7k/8/p7/8/8/P7/8/B6K w - - 0 1";
+        assert_eq!(count_piece_moves(&chess, fen, 2), 8);
+
+        chess.pieces[2] = leak(BetzaPiece::parse('b', "mRcpR").unwrap());
+        assert_eq!(count_piece_moves(&chess, fen, 2), 8);
+    }
+
+    #[test]
+    fn cannon_checks() {
+        let mut chess = Chess::create();
+        chess.pieces[2] = leak(HopperPiece::new('b', ROOK_DIRECTIONS.to_vec(), HopStyle::Cannon));
+
+        // With a screen on e4, the cannon on e1 attacks the king on e8.
+        let board = chess.from_fen("4k3/8/8/8/4P3/8/8/4B2K b - - 0 1");
+        let king = board.state.pieces[5] & board.state.teams[1];
+        assert!(board.can_move(0, king, ATTACKS_MODE));
+        assert!((board.get_move_mask(0, ATTACKS_MODE) & king).is_set());
+
+        // Without one, it doesn't.
+        let board = chess.from_fen("4k3/8/8/8/8/8/8/4B2K b - - 0 1");
+        let king = board.state.pieces[5] & board.state.teams[1];
+        assert!(!board.can_move(0, king, ATTACKS_MODE));
+
+        // Black has to block with the rook or step out of the line.
+        let mut board = chess.from_fen("4k3/8/8/8/4P3/8/3r4/4B2K b - - 0 1");
+        let moves = board.generate_legal_moves(1);
+        assert!(moves.iter().all(|el| match el {
+            // e2 is the 52nd square, counting from a8.
+            Move::Action(action) => action.piece_type == 5 || action.to == 52,
+            _ => false,
+        }));
+    }
+
+    #[test]
+    fn grasshopper_moves() {
+        let mut chess = Chess::create();
+        chess.pieces[2] = leak(HopperPiece::new('b', QUEEN_DIRECTIONS.to_vec(), HopStyle::Grasshopper));
+
+        // The grasshopper on d4 can hop over d5 to d6, over e5 to capture on f6, and over c3 to b2.
+        let fen = "7k/8/5p2/3pP3/3B4/2p5/8/7K w - - 0 1";
+        assert_eq!(count_piece_moves(&chess, fen, 2), 3);
+
+        chess.pieces[2] = leak(BetzaPiece::parse('b', "gQ").unwrap());
+        assert_eq!(count_piece_moves(&chess, fen, 2), 3);
+
+        assert!(BetzaPiece::parse('b', "pN").is_err());
+        assert!(BetzaPiece::parse('b', "pR4").is_err());
+    }
+}
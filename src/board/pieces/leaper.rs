@@ -0,0 +1,61 @@
+use crate::{
+    bitboard::BitBoard,
+    board::{AttackDirections, Board, PieceType},
+};
+
+use super::{leap_by_offset, orient_offset, Offset, Piece, PieceSymbol};
+
+/// A piece that jumps straight to any of its `offsets`, regardless of what's in between (eg. a knight, camel, zebra or wazir.)
+/// Offsets are written from the first team's perspective; see `Offset`.
+#[derive(Debug)]
+pub struct LeaperPiece {
+    pub symbol: char,
+    pub offsets: Vec<Offset>,
+}
+
+impl LeaperPiece {
+    pub fn new(symbol: char, offsets: Vec<Offset>) -> LeaperPiece {
+        LeaperPiece {
+            symbol: symbol.to_ascii_lowercase(),
+            offsets,
+        }
+    }
+}
+
+impl<const T: usize> Piece<T> for LeaperPiece {
+    fn get_piece_symbol(&self) -> PieceSymbol {
+        PieceSymbol::Char(self.symbol)
+    }
+
+    fn can_lookup(&self) -> bool {
+        true
+    }
+
+    /// Each team gets its own lookup, since offsets may not be symmetrical between teams.
+    fn generate_lookup_moves(&self, board: &Board<T>, from: BitBoard<T>) -> AttackDirections<T> {
+        let mut lookups = Vec::with_capacity(board.game.teams as usize);
+        for team in 0..board.game.teams {
+            let mut moves = BitBoard::new();
+            for offset in &self.offsets {
+                moves |= leap_by_offset(board, from, orient_offset(*offset, team, board.game.teams));
+            }
+            lookups.push(moves);
+        }
+        lookups
+    }
+
+    #[allow(unused_variables)]
+    fn get_moves(
+        &self,
+        board: &Board<T>,
+        from: BitBoard<T>,
+        piece_type: PieceType,
+        team: u16,
+        mode: u16,
+    ) -> BitBoard<T> {
+        let lookup = self
+            .get_attack_lookup(board, piece_type)
+            .expect("Could not find the leaper attack lookup.");
+        lookup[from.bitscan_forward() as usize][team as usize]
+    }
+}
@@ -1,3 +1,23 @@
+mod betza;
+mod capturing;
+mod confined;
+mod hopper;
+mod leaper;
+mod offsets;
+mod rays;
+mod rider;
+#[cfg(test)]
+mod test_utils;
+
+pub use betza::*;
+pub use capturing::*;
+pub use confined::*;
+pub use hopper::*;
+pub use leaper::*;
+pub use offsets::*;
+pub use rays::*;
+pub use rider::*;
+
 use std::fmt::Debug;
 
 use crate::bitboard::BitBoard;
@@ -5,7 +25,7 @@ use crate::bitboard::BitBoard;
 use super::{
     actions::{
         Action, HistoryMove, HistoryState, HistoryUpdate, IndexedPreviousBoard, PreviousBoard,
-        UndoMoveError, Move, ActionInfo, TurnInfo,
+        PreviousReserve, UndoMoveError, Move, ActionInfo, TurnInfo,
     },
     game::Game,
     AttackDirections, AttackLookup, Board, BoardState, Cols, PieceType, Rows, update_turns, reverse_turns,
@@ -14,6 +34,8 @@ use super::{
 pub enum PieceSymbol {
     Char(char),
     TeamSymbol(Vec<char>),
+    /// A piece written as a prefix followed by a character, such as Shogi's promoted pieces (`Prefixed('+', 'p')` for `+P`.)
+    Prefixed(char, char),
 }
 
 const NORMAL_MOVE: u16 = 0;
@@ -64,6 +86,12 @@ pub trait Piece<const T: usize> : Debug + Send + Sync {
         Vec::new()
     }
 
+    /// Whether `action` captures anything, checked before it's made so that controllers can reset the half-move clock.
+    /// Most pieces capture by moving onto an occupied square.
+    fn is_capture(&self, state: &BoardState<T>, action: &Action) -> bool {
+        (BitBoard::<T>::from_lsb(action.to) & state.all_pieces).is_set()
+    }
+
     fn make_capture_move(
         &self,
         board: &mut Board<T>,
@@ -75,11 +103,7 @@ pub trait Piece<const T: usize> : Debug + Send + Sync {
     ) -> Option<HistoryMove<T>> {
         let color = action.team as usize;
         let piece_type = piece_type as usize;
-        let captured_color: usize = if (to & board.state.teams[0]).is_set() {
-            0
-        } else {
-            1
-        };
+        let captured_color = board.state.get_team(to).expect("The captured piece should belong to a team.");
         let mut captured_piece_type: PieceType = 0;
         for i in 0..(board.game.pieces.len()) {
             if (board.state.pieces[i] & to).is_set() {
@@ -190,8 +214,48 @@ pub trait Piece<const T: usize> : Debug + Send + Sync {
         }
     }
 
+    /// Drops this piece from the team's reserves onto the (empty) square of `action.to`.
+    fn make_drop_move(&self, board: &mut Board<T>, action: &Action) -> Option<HistoryMove<T>> {
+        let to = BitBoard::from_lsb(action.to);
+        let color = action.team as usize;
+        let piece_type = action.piece_type as usize;
+
+        let turn_info = board.get_turn_info();
+        update_turns(&mut board.state, board.game, &Move::Action(*action));
+
+        let history_move = HistoryMove {
+            action: Move::Action(*action),
+            first_history_move: board.retrieve_first_history_move(Move::Action(*action)),
+            turn_info,
+            state: HistoryState::Any {
+                all_pieces: PreviousBoard(board.state.all_pieces),
+                first_move: PreviousBoard(board.state.first_move),
+                updates: vec![
+                    HistoryUpdate::Team(IndexedPreviousBoard(color, board.state.teams[color])),
+                    HistoryUpdate::Piece(IndexedPreviousBoard(
+                        piece_type,
+                        board.state.pieces[piece_type],
+                    )),
+                    HistoryUpdate::Reserve(PreviousReserve(
+                        color,
+                        piece_type,
+                        board.state.reserves[color][piece_type],
+                    )),
+                ],
+            },
+        };
+
+        board.state.teams[color] |= to;
+        board.state.pieces[piece_type] |= to;
+        board.state.all_pieces |= to;
+        board.state.reserves[color][piece_type] -= 1;
+
+        Some(history_move)
+    }
+
+    #[allow(unused_variables)]
     fn undo_move(&self, state: &mut BoardState<T>, game: &Game<T>, history_move: &HistoryMove<T>) {
-        reverse_turns(state, game, &history_move);
+        reverse_turns(state, &history_move);
 
         match &history_move.state {
             HistoryState::Single {
@@ -220,6 +284,21 @@ pub trait Piece<const T: usize> : Debug + Send + Sync {
                         HistoryUpdate::Piece(piece) => {
                             state.pieces[piece.0] = piece.1;
                         }
+                        HistoryUpdate::Reserve(reserve) => {
+                            state.reserves[reserve.0][reserve.1] = reserve.2;
+                        }
+                        HistoryUpdate::Promoted(promoted) => {
+                            state.promoted = promoted.0;
+                        }
+                        HistoryUpdate::Counter(counter) => {
+                            state.counters[counter.0] = counter.1;
+                        }
+                        HistoryUpdate::Gaps(gaps) => {
+                            state.gaps = gaps.0;
+                        }
+                        HistoryUpdate::Position => {
+                            state.positions.pop();
+                        }
                     }
                 }
             }
@@ -256,4 +335,28 @@ pub trait Piece<const T: usize> : Debug + Send + Sync {
             }));
         }
     }
+
+    /// Adds every square this piece can be dropped onto from the team's reserves. By default, that's any empty square.
+    #[allow(unused_variables)]
+    fn add_drop_actions(
+        &self,
+        actions: &mut Vec<Move>,
+        board: &Board<T>,
+        piece_type: PieceType,
+        team: u16,
+        mode: u16,
+    ) {
+        let empty_squares = !board.state.all_pieces & !board.state.gaps;
+
+        for bit in empty_squares.iter_set_bits(board.state.squares) {
+            actions.push(Move::Action(Action {
+                from: None,
+                to: bit,
+                team,
+                info: NORMAL_MOVE,
+                move_type: NORMAL_MOVE,
+                piece_type,
+            }));
+        }
+    }
 }
@@ -0,0 +1,106 @@
+use crate::{bitboard::BitBoard, board::{Board, Cols}};
+
+/// An `Offset` is a relative movement of `(d_row, d_col)`, written from the perspective of the first team.
+/// A positive `d_row` moves up the board (towards `edges.top`, the way the first team's pawns move), and a positive `d_col` moves to the right.
+pub type Offset = (i16, i16);
+
+/// Orients an offset written from the first team's perspective for the given team, out of `teams` teams.
+/// With two teams, the second team plays from the other side of the board, so its offsets are mirrored vertically.
+/// With four teams (eg. four-player chess), the teams sit clockwise around the board, so each team's offsets are turned a quarter turn further than the team before it.
+pub fn orient_offset(offset: Offset, team: u16, teams: u16) -> Offset {
+    match (teams, team) {
+        (4, 1) => (-offset.1, offset.0),
+        (4, 2) => (-offset.0, -offset.1),
+        (4, 3) => (offset.1, -offset.0),
+        (_, 1) => (-offset.0, offset.1),
+        _ => offset,
+    }
+}
+
+/// Generates every reflection of an offset, such as all eight moves of a knight from `(2, 1)`.
+pub fn get_symmetric_offsets((a, b): Offset) -> Vec<Offset> {
+    let mut offsets: Vec<Offset> = Vec::with_capacity(8);
+    for (d_row, d_col) in [(a, b), (b, a)] {
+        for d_row in [d_row, -d_row] {
+            for d_col in [d_col, -d_col] {
+                if !offsets.contains(&(d_row, d_col)) {
+                    offsets.push((d_row, d_col));
+                }
+            }
+        }
+    }
+    offsets
+}
+
+/// Shifts every bit of the given bitboard by an offset, without checking whether it would leave the board.
+pub fn shift_by_offset<const T: usize>(bitboard: BitBoard<T>, (d_row, d_col): Offset, cols: Cols) -> BitBoard<T> {
+    let shift = (d_col as i32) - ((d_row as i32) * (cols as i32));
+    if shift >= 0 {
+        bitboard << (shift as u16)
+    } else {
+        bitboard >> ((-shift) as u16)
+    }
+}
+
+/// Finds all squares that would end up off of the board if they moved by the given offset.
+pub fn get_offset_edges<const T: usize>(board: &Board<T>, (d_row, d_col): Offset) -> BitBoard<T> {
+    let edges = &board.state.edges;
+    let mut mask = BitBoard::new();
+
+    if d_row != 0 {
+        match edges.get((d_row.unsigned_abs() - 1) as usize) {
+            Some(row_edges) => {
+                mask |= if d_row > 0 { row_edges.top } else { row_edges.bottom };
+            }
+            None => return BitBoard::max(),
+        }
+    }
+
+    if d_col != 0 {
+        match edges.get((d_col.unsigned_abs() - 1) as usize) {
+            Some(col_edges) => {
+                mask |= if d_col > 0 { col_edges.right } else { col_edges.left };
+            }
+            None => return BitBoard::max(),
+        }
+    }
+
+    mask
+}
+
+/// Leaps every bit of `from` by the given offset, dropping any bits that would leave the board.
+pub fn leap_by_offset<const T: usize>(board: &Board<T>, from: BitBoard<T>, offset: Offset) -> BitBoard<T> {
+    shift_by_offset(from & !get_offset_edges(board, offset), offset, board.state.cols)
+}
+
+/// Repeats a leap by the given offset until it leaves the board (or until `max_range` leaps have been made), returning every square along the way.
+/// This doesn't account for blockers; it's meant for generating attack lookups.
+pub fn ride_by_offset<const T: usize>(
+    board: &Board<T>,
+    mut from: BitBoard<T>,
+    offset: Offset,
+    max_range: Option<u16>,
+) -> BitBoard<T> {
+    let edges = get_offset_edges(board, offset);
+    let cols = board.state.cols;
+
+    let mut moves = BitBoard::new();
+    let mut steps = 0;
+    loop {
+        if let Some(max_range) = max_range {
+            if steps >= max_range {
+                break;
+            }
+        }
+
+        from = shift_by_offset(from & !edges, offset, cols);
+        if from.is_empty() {
+            break;
+        }
+
+        moves |= from;
+        steps += 1;
+    }
+
+    moves
+}
@@ -0,0 +1,156 @@
+use std::ops::Range;
+
+use crate::{board::{AttackDirections, AttackLookup, Board}, bitboard::BitBoard};
+
+use super::{ride_by_offset, Offset};
+
+pub fn get_moves_ray<const T: usize>(
+    mut from: BitBoard<T>,
+    slider: impl Fn(BitBoard<T>) -> BitBoard<T>,
+    can_stop: impl Fn(BitBoard<T>) -> bool,
+) -> BitBoard<T> {
+    let mut moves = BitBoard::new();
+    if can_stop(from) {
+        return moves;
+    }
+
+    loop {
+        from = slider(from);
+        moves |= from;
+
+        if can_stop(from) {
+            break;
+        }
+    }
+
+    moves
+}
+
+pub fn get_ray_attacks<const T: usize>(
+    board: &Board<T>,
+    from: BitBoard<T>,
+    from_bit: usize,
+    dir: u32,
+    ray_attacks: &AttackLookup<T>,
+) -> BitBoard<T> {
+    let dir_usize = dir as usize;
+    let mut attacks = ray_attacks[from_bit][dir_usize];
+    let blocker = attacks & board.state.all_pieces;
+    if blocker.is_set() {
+        let square = if from < blocker {
+            blocker.bitscan_forward()
+        } else {
+            blocker.bitscan_reverse()
+        };
+
+        attacks ^= ray_attacks[square as usize][dir_usize];
+    }
+    attacks
+}
+
+
+/// Generates a ray for every direction (ignoring any blockers), followed by all of those rays combined.
+pub fn generate_ray_lookups<const T: usize>(
+    board: &Board<T>,
+    from: BitBoard<T>,
+    directions: &[Offset],
+) -> AttackDirections<T> {
+    let mut lookups = Vec::with_capacity(directions.len() + 1);
+    let mut combined_lookup = BitBoard::new();
+    for direction in directions {
+        let ray = ride_by_offset(board, from, *direction, None);
+        combined_lookup |= ray;
+        lookups.push(ray);
+    }
+    lookups.push(combined_lookup);
+    lookups
+}
+
+/// Finds every square attacked along the rays in `dirs`, up to and including the first blocker of each ray.
+pub fn get_rays_attacks<const T: usize>(
+    board: &Board<T>,
+    from: BitBoard<T>,
+    from_bit: usize,
+    dirs: Range<u32>,
+    ray_attacks: &AttackLookup<T>,
+) -> BitBoard<T> {
+    let mut attacks = BitBoard::new();
+    for dir in dirs {
+        attacks |= get_ray_attacks(board, from, from_bit, dir, ray_attacks);
+    }
+    attacks
+}
+
+/// Finds the ray (out of `dirs`) which attacks `to`, using the combined lookup at `combined_dir` to skip squares that no ray can reach.
+pub fn get_ray_move_mask<const T: usize>(
+    board: &Board<T>,
+    from: BitBoard<T>,
+    from_bit: usize,
+    dirs: Range<u32>,
+    combined_dir: usize,
+    ray_attacks: &AttackLookup<T>,
+    to: BitBoard<T>,
+) -> BitBoard<T> {
+    if (ray_attacks[from_bit][combined_dir] & to).is_empty() {
+        return BitBoard::new();
+    }
+
+    for dir in dirs {
+        let ray = get_ray_attacks(board, from, from_bit, dir, ray_attacks);
+        if (ray & to).is_set() {
+            return ray;
+        }
+    }
+
+    BitBoard::new()
+}
+
+/// Finds the closest blocker to `from`, given the blockers along a single ray.
+pub fn get_first_blocker<const T: usize>(from: BitBoard<T>, blockers: BitBoard<T>) -> Option<u16> {
+    if blockers.is_empty() {
+        return None;
+    }
+
+    Some(if from < blockers {
+        blockers.bitscan_forward()
+    } else {
+        blockers.bitscan_reverse()
+    })
+}
+
+/// Finds every square a hopper could reach by jumping over the first piece (the "screen") along a ray.
+/// This includes every empty square past the screen, up to and including the next piece.
+pub fn get_hop_attacks<const T: usize>(
+    board: &Board<T>,
+    from: BitBoard<T>,
+    from_bit: usize,
+    dir: u32,
+    ray_attacks: &AttackLookup<T>,
+) -> BitBoard<T> {
+    let ray = ray_attacks[from_bit][dir as usize];
+    match get_first_blocker(from, ray & board.state.all_pieces) {
+        Some(screen) => get_ray_attacks(board, BitBoard::from_lsb(screen), screen as usize, dir, ray_attacks),
+        None => BitBoard::new(),
+    }
+}
+
+/// Finds the square directly past the first piece (the "hurdle") along a ray, where a grasshopper would land.
+pub fn get_hop_landing<const T: usize>(
+    board: &Board<T>,
+    from: BitBoard<T>,
+    from_bit: usize,
+    dir: u32,
+    ray_attacks: &AttackLookup<T>,
+) -> BitBoard<T> {
+    let ray = ray_attacks[from_bit][dir as usize];
+    let hurdle = match get_first_blocker(from, ray & board.state.all_pieces) {
+        Some(hurdle) => hurdle,
+        None => return BitBoard::new(),
+    };
+
+    let hurdle_board = BitBoard::from_lsb(hurdle);
+    match get_first_blocker(hurdle_board, ray_attacks[hurdle as usize][dir as usize]) {
+        Some(landing) => BitBoard::from_lsb(landing),
+        None => BitBoard::new(),
+    }
+}
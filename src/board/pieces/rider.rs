@@ -0,0 +1,170 @@
+use crate::{
+    bitboard::BitBoard,
+    board::{AttackDirections, Board, PieceType},
+};
+
+use super::{get_ray_attacks, orient_offset, ride_by_offset, Offset, Piece, PieceSymbol};
+
+/// A piece that repeats a leap in any of its `directions` until it's blocked (eg. a rook, bishop or nightrider.)
+/// If `max_range` is set, the piece can make at most that many leaps in a single move.
+/// Directions are written from the first team's perspective; see `Offset`.
+#[derive(Debug)]
+pub struct RiderPiece {
+    pub symbol: char,
+    pub directions: Vec<Offset>,
+    pub max_range: Option<u16>,
+}
+
+impl RiderPiece {
+    pub fn new(symbol: char, directions: Vec<Offset>, max_range: Option<u16>) -> RiderPiece {
+        RiderPiece {
+            symbol: symbol.to_ascii_lowercase(),
+            directions,
+            max_range,
+        }
+    }
+
+    /// Each team has an unlimited ray for every direction (used to find blockers), then every ray limited to `max_range`, then all of the limited rays combined.
+    fn get_team_stride(&self) -> usize {
+        (2 * self.directions.len()) + 1
+    }
+}
+
+impl<const T: usize> Piece<T> for RiderPiece {
+    fn get_piece_symbol(&self) -> PieceSymbol {
+        PieceSymbol::Char(self.symbol)
+    }
+
+    fn can_lookup(&self) -> bool {
+        true
+    }
+
+    fn generate_lookup_moves(&self, board: &Board<T>, from: BitBoard<T>) -> AttackDirections<T> {
+        let mut lookups = Vec::with_capacity(self.get_team_stride() * board.game.teams as usize);
+
+        for team in 0..board.game.teams {
+            let mut limited_rays = Vec::with_capacity(self.directions.len());
+            let mut combined_lookup = BitBoard::new();
+
+            for direction in &self.directions {
+                let direction = orient_offset(*direction, team, board.game.teams);
+                lookups.push(ride_by_offset(board, from, direction, None));
+
+                let limited_ray = ride_by_offset(board, from, direction, self.max_range);
+                combined_lookup |= limited_ray;
+                limited_rays.push(limited_ray);
+            }
+
+            lookups.extend(limited_rays);
+            lookups.push(combined_lookup);
+        }
+
+        lookups
+    }
+
+    #[allow(unused_variables)]
+    fn can_move_mask(
+        &self,
+        board: &Board<T>,
+        from: BitBoard<T>,
+        from_bit: u16,
+        piece_type: PieceType,
+        team: u16,
+        mode: u16,
+        to: BitBoard<T>,
+    ) -> BitBoard<T> {
+        let lookup = self
+            .get_attack_lookup(board, piece_type)
+            .expect("Could not find the rider attack lookup.");
+
+        let from_bit = from_bit as usize;
+        let directions = self.directions.len();
+        let base = (team as usize) * self.get_team_stride();
+        if (lookup[from_bit][base + (2 * directions)] & to).is_empty() {
+            return BitBoard::new();
+        }
+
+        for dir in base..(base + directions) {
+            let ray = get_ray_attacks(board, from, from_bit, dir as u32, lookup)
+                & lookup[from_bit][dir + directions];
+            if (ray & to).is_set() {
+                return ray;
+            }
+        }
+
+        BitBoard::new()
+    }
+
+    #[allow(unused_variables)]
+    fn get_moves(
+        &self,
+        board: &Board<T>,
+        from: BitBoard<T>,
+        piece_type: PieceType,
+        team: u16,
+        mode: u16,
+    ) -> BitBoard<T> {
+        let lookup = self
+            .get_attack_lookup(board, piece_type)
+            .expect("Could not find the rider attack lookup.");
+
+        let from_bit = from.bitscan_forward() as usize;
+        let directions = self.directions.len();
+        let base = (team as usize) * self.get_team_stride();
+
+        let mut attacks = BitBoard::new();
+        for dir in base..(base + directions) {
+            attacks |= get_ray_attacks(board, from, from_bit, dir as u32, lookup)
+                & lookup[from_bit][dir + directions];
+        }
+
+        attacks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::pieces::{get_symmetric_offsets, test_utils::{count_piece_moves, leak}, LeaperPiece},
+        games::chess::{
+            pieces::{BISHOP_DIRECTIONS, KNIGHT_OFFSETS, QUEEN_DIRECTIONS, ROOK_DIRECTIONS},
+            Chess,
+        },
+    };
+
+    use super::RiderPiece;
+
+    #[test]
+    fn generic_chess_perft() {
+        let mut chess = Chess::create();
+        chess.pieces[1] = leak(LeaperPiece::new('n', KNIGHT_OFFSETS.to_vec()));
+        chess.pieces[2] = leak(RiderPiece::new('b', BISHOP_DIRECTIONS.to_vec(), None));
+        chess.pieces[3] = leak(RiderPiece::new('r', ROOK_DIRECTIONS.to_vec(), None));
+        chess.pieces[4] = leak(RiderPiece::new('q', QUEEN_DIRECTIONS.to_vec(), None));
+
+        chess.default().assert_perfts([20, 400, 8902]);
+        chess
+            .from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1")
+            .assert_perfts([48, 2039]);
+    }
+
+    #[test]
+    fn fairy_pieces() {
+        let mut chess = Chess::create();
+
+        // A camel on d4 has all eight of its (3, 1) leaps available.
+        chess.pieces[1] = leak(LeaperPiece::new('n', get_symmetric_offsets((3, 1))));
+        assert_eq!(count_piece_moves(&chess, "4k3/8/8/8/3N4/8/8/7K w - - 0 1", 1), 8);
+
+        // A nightrider on a1 rides through b3, c5 and d7, and through c2, e3 and g4.
+        chess.pieces[1] = leak(RiderPiece::new('n', KNIGHT_OFFSETS.to_vec(), None));
+        assert_eq!(count_piece_moves(&chess, "7k/8/8/8/8/8/8/N6K w - - 0 1", 1), 6);
+
+        // Blocking c5 stops the nightrider from reaching d7.
+        assert_eq!(count_piece_moves(&chess, "7k/8/8/2P5/8/8/8/N6K w - - 0 1", 1), 4);
+
+        // A rook limited to two squares on d4 reaches eight squares.
+        chess.pieces[3] = leak(RiderPiece::new('r', ROOK_DIRECTIONS.to_vec(), Some(2)));
+        assert_eq!(count_piece_moves(&chess, "4k3/8/8/8/3R4/8/8/4K3 w - - 0 1", 3), 8);
+    }
+}
@@ -0,0 +1,21 @@
+use crate::board::{actions::Move, game::Game};
+
+use super::Piece;
+
+/// `Game` needs its pieces to be `'static`, so tests leak the pieces they swap in.
+pub fn leak(piece: impl Piece<1> + 'static) -> &'static dyn Piece<1> {
+    Box::leak(Box::new(piece))
+}
+
+/// Counts the legal moves for the first team made by pieces of the given type.
+pub fn count_piece_moves(game: &Game<1>, fen: &str, piece_type: u16) -> usize {
+    let mut board = game.from_fen(fen);
+    board
+        .generate_legal_moves(0)
+        .iter()
+        .filter(|el| match el {
+            Move::Action(action) => action.piece_type == piece_type,
+            _ => false,
+        })
+        .count()
+}
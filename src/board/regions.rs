@@ -0,0 +1,38 @@
+use std::ops::Range;
+
+use crate::bitboard::BitBoard;
+
+use super::{Cols, Rows};
+
+/// A region is a named set of squares for each team, such as each team's palace in Xiangqi.
+/// Games list their regions in `Game::regions`, so pieces can be confined to them (see `ConfinedPiece`.)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region<const T: usize> {
+    pub name: String,
+    /// The squares of the region, indexed by team.
+    pub squares: Vec<BitBoard<T>>,
+}
+
+impl<const T: usize> Region<T> {
+    pub fn new(name: &str, squares: Vec<BitBoard<T>>) -> Region<T> {
+        Region {
+            name: name.to_string(),
+            squares,
+        }
+    }
+
+    pub fn get(&self, team: u16) -> BitBoard<T> {
+        self.squares[team as usize]
+    }
+}
+
+/// Gets every square within the given rows and columns, where row 0 is the top of the board.
+pub fn get_rectangle<const T: usize>(rows: Range<Rows>, cols: Range<Cols>, board_cols: Cols) -> BitBoard<T> {
+    let mut rectangle = BitBoard::new();
+    for row in rows {
+        for col in cols.clone() {
+            rectangle |= BitBoard::from_lsb((row * board_cols) + col);
+        }
+    }
+    rectangle
+}
@@ -13,7 +13,7 @@ use super::actions::{Move, TurnInfo, CounterUpdate};
 use super::{
     actions::{Action, HistoryMove, UndoMoveError, HistoryState},
     edges::{generate_edge_list, Edges},
-    game::Game,
+    game::{Game, NORMAL_MODE},
     pieces::Piece, zobrist::ZobristHashTable,
 };
 
@@ -25,14 +25,15 @@ pub type Rows = u16;
 pub type Cols = u16;
 
 pub fn update_turns<const T: usize>(state: &mut BoardState<T>, game: &Game<T>, action: &Move) {
-    let update = game.controller.update(action, state);
+    let update = game.controller.update(action, state, game);
 
     state.turns += 1;
-    state.current_turn = state.turn_lookup[state.current_turn as usize];
+    state.current_turn = state.turn_lookup[state.moving_team as usize][state.current_turn as usize];
     if state.current_turn == 0 {
         state.sub_moves += 1;
 
-        if state.moving_team == 0 {
+        // A full move is over once the last team has moved.
+        if state.moving_team == game.teams - 1 {
             state.full_moves += 1;
         }
         state.moving_team = state.team_lookup[state.moving_team as usize];
@@ -51,15 +52,16 @@ pub fn update_turns<const T: usize>(state: &mut BoardState<T>, game: &Game<T>, a
     }
 }
 
-pub fn reverse_turns<const T: usize>(state: &mut BoardState<T>, game: &Game<T>, action: &HistoryMove<T>) {
+pub fn reverse_turns<const T: usize>(state: &mut BoardState<T>, action: &HistoryMove<T>) {
     state.turns = action.turn_info.turns;
     state.sub_moves = action.turn_info.sub_moves;
     state.full_moves = action.turn_info.full_moves;
 
-    state.current_turn = state.turn_reverse_lookup[state.current_turn as usize];
-    if state.current_turn == game.turns - 1 {
+    // Going back from a team's first turn brings us to the previous team's last turn, so the team has to be found first.
+    if state.current_turn == 0 {
         state.moving_team = state.team_reverse_lookup[state.moving_team as usize];
     }
+    state.current_turn = state.turn_reverse_lookup[state.moving_team as usize][state.current_turn as usize];
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -90,19 +92,41 @@ pub struct BoardState<const T: usize> {
     pub cols: Cols,
     pub squares: u16,
 
-    pub turn_lookup: HeapVec<u16, 16>,
+    /// The turn lookups are indexed by team, then by turn, since each team can make a different amount of turns (see `Game::turns`.)
+    pub turn_lookup: Vec<HeapVec<u16, 16>>,
     pub team_lookup: HeapVec<u16, 16>,
-    pub turn_reverse_lookup: HeapVec<u16, 16>,
+    pub turn_reverse_lookup: Vec<HeapVec<u16, 16>>,
     pub team_reverse_lookup: HeapVec<u16, 16>,
 
     pub ranks: Vec<BitBoard<T>>,
-    pub files: Vec<BitBoard<T>>
+    pub files: Vec<BitBoard<T>>,
+
+    /// Reserves are the pieces each team holds off of the board, which can be dropped onto it later (eg. captured pieces in Crazyhouse or Shogi.)
+    /// They're indexed by team, then by piece type, storing how many of that piece the team holds.
+    pub reserves: Vec<Vec<u16>>,
+
+    /// Promoted pieces are pieces that were promoted from another piece type, and are written with a `~` after them in FENs (eg. `Q~`.)
+    /// Only games that need to know this (like Crazyhouse, where promoted pieces are captured as pawns) keep track of them.
+    pub promoted: BitBoard<T>,
+
+    /// Counters are any extra numbers a game needs to keep track of (eg. how many checks each team has given in Three-check.)
+    /// There are `MoveController::get_counters` of them, and they're undone through `HistoryUpdate::Counter`.
+    pub counters: Vec<u16>,
+
+    /// Positions are the zobrist hashes of the positions reached after each move, for games that need to find repetitions (eg. perpetual checks in Xiangqi.)
+    /// Only games that need them record them, and undoing a `HistoryUpdate::Position` removes the latest one.
+    pub positions: Vec<u64>
 }
 
 impl<const T: usize> BoardState<T> {
     pub fn get_piece_team_board(&self, piece: usize, team: usize) -> BitBoard<T> {
         self.pieces[piece] & self.teams[team]
     }
+
+    /// Finds the team of the piece on `square`, such as the team of a piece that's being captured.
+    pub fn get_team(&self, square: BitBoard<T>) -> Option<usize> {
+        self.teams.iter().position(|team| (*team & square).is_set())
+    }
 }
 
 pub type AttackDirections<const T: usize> = Vec<BitBoard<T>>;
@@ -177,8 +201,8 @@ impl<'a, const T: usize> Board<'a, T> {
             .collect::<Vec<_>>()
             .clone();
 
-        let turn_lookup = generate_forward_lookup(game.turns);
-        let turn_reverse_lookup = generate_reverse_lookup(game.turns);
+        let turn_lookup = game.turns.iter().map(|turns| generate_forward_lookup(*turns)).collect::<Vec<_>>();
+        let turn_reverse_lookup = game.turns.iter().map(|turns| generate_reverse_lookup(*turns)).collect::<Vec<_>>();
         let team_lookup = generate_forward_lookup(game.teams);
         let team_reverse_lookup = generate_reverse_lookup(game.teams);
 
@@ -206,7 +230,11 @@ impl<'a, const T: usize> Board<'a, T> {
                 turn_lookup,
                 turn_reverse_lookup,
                 ranks: generate_ranks(cols, rows),
-                files: generate_files(cols, rows)
+                files: generate_files(cols, rows),
+                reserves: (0..game.teams).map(|_| vec![0; game.pieces.len()]).collect::<Vec<_>>(),
+                promoted: BitBoard::new(),
+                counters: vec![0; game.controller.get_counters()],
+                positions: Vec::new()
             }
         };
 
@@ -231,6 +259,40 @@ impl<'a, const T: usize> Board<'a, T> {
         bitboard
     }
 
+    /// Finds every square `team` can see in fog-of-war games (eg. dark chess): the squares its pieces are on, and any square they could move to.
+    pub fn get_visibility_mask(&self, team: u16) -> BitBoard<T> {
+        self.get_move_mask(team, NORMAL_MODE) | self.state.teams[team as usize]
+    }
+
+    /// Redacts the board down to what `team` can see, removing every piece outside of its visibility mask.
+    /// Moves the other teams made out of sight are replaced with passes in the history, so they can't be leaked either (eg. through en passant squares.)
+    /// The redacted FEN can be found with `to_fen` on the observed board.
+    pub fn observe(&self, team: u16) -> Board<'a, T> {
+        let visible = self.get_visibility_mask(team);
+        let mut board = self.clone();
+
+        // Gaps are never hidden, so they keep blocking pieces (eg. games that add gaps to `all_pieces`, like Omega chess.)
+        board.state.all_pieces &= visible | board.state.gaps;
+        board.state.first_move &= visible;
+        board.state.promoted &= visible;
+        for piece in board.state.pieces.iter_mut() {
+            *piece &= visible;
+        }
+        for team_board in board.state.teams.iter_mut() {
+            *team_board &= visible;
+        }
+
+        for action in board.history.iter_mut() {
+            if let Move::Action(last_action) = action {
+                if last_action.team != team && (BitBoard::<T>::from_lsb(last_action.to) & visible).is_empty() {
+                    *action = Move::Pass;
+                }
+            }
+        }
+
+        board
+    }
+
     pub fn can_move(&self, team: u16, target: BitBoard<T>, mode: u16) -> bool {
         let board_len = self.state.squares;
 
@@ -272,16 +334,27 @@ impl<'a, const T: usize> Board<'a, T> {
         let piece = &self.game.pieces[piece_type];
         piece.add_actions(&mut actions, self, piece_type as PieceType, from, team, mode);
 
-        vec![]
+        actions
     }
 
+    /// Generates every move that drops a piece from the moving team's reserves onto the board.
     pub fn generate_drop_moves(&self, mode: u16) -> Vec<Move> {
-        let team = self.state.moving_team;
         let mut actions: Vec<Move> = Vec::with_capacity(self.state.squares as usize);
+        self.add_drop_actions(&mut actions, mode);
+        actions
+    }
 
-        self.game.controller.add_moves(self, &mut actions);
+    fn add_drop_actions(&self, actions: &mut Vec<Move>, mode: u16) {
+        let team = self.state.moving_team;
 
-        vec![]
+        for (ind, count) in self.state.reserves[team as usize].iter().enumerate() {
+            if *count == 0 {
+                continue;
+            }
+
+            let piece = &self.game.pieces[ind];
+            piece.add_drop_actions(actions, self, ind as PieceType, team, mode);
+        }
     }
 
     pub fn generate_moves(&self, mode: u16) -> Vec<Move> {
@@ -299,6 +372,7 @@ impl<'a, const T: usize> Board<'a, T> {
             }
         }
 
+        self.add_drop_actions(&mut actions, mode);
         self.game.controller.add_moves(self, &mut actions);
 
         actions
@@ -322,16 +396,26 @@ impl<'a, const T: usize> Board<'a, T> {
         }
     }
 
-    pub fn get_previous_team(&self, mut team: u16) -> u16 {
-        team -= 1;
-
-        if team == u16::MAX {
+    pub fn get_previous_team(&self, team: u16) -> u16 {
+        if team == 0 {
             (self.state.teams.len() - 1) as u16
         } else {
-            team
+            team - 1
         }
     }
 
+    /// Finds the actions the previous team made on their last sub-move (eg. the opponent's last move in chess.)
+    /// These are only found on the moving team's first turn, since the moving team's own turns come after them otherwise.
+    pub fn get_last_sub_move(&self) -> impl Iterator<Item = &Move> {
+        let turns = if self.state.current_turn == 0 {
+            self.game.turns[self.get_previous_team(self.state.moving_team) as usize] as usize
+        } else {
+            0
+        };
+
+        self.history.iter().skip(self.history.len().saturating_sub(turns))
+    }
+
     pub fn retrieve_first_history_move(&mut self, action: Move) -> Option<Move> {
         if self.game.saved_last_moves == 0 {
             return None;
@@ -351,8 +435,9 @@ impl<'a, const T: usize> Board<'a, T> {
             return;
         }
 
+        // The move being undone is always the latest one in the history, even if nothing had to be removed to make space for it.
+        self.history.pop_back();
         if let Some(first_history_move) = first_history_move {
-            self.history.pop_back();
             self.history.push_front(first_history_move);
         }
     }
@@ -361,7 +446,7 @@ impl<'a, const T: usize> Board<'a, T> {
         TurnInfo {
             turns: self.state.turns,
             sub_moves: self.state.sub_moves,
-            full_moves: self.state.sub_moves
+            full_moves: self.state.full_moves
         }
     }
 
@@ -369,7 +454,7 @@ impl<'a, const T: usize> Board<'a, T> {
         match action {
             Move::Action(action) => {
                 if action.from.is_some() {
-                    self.game.pieces[action.piece_type as usize].make_move(self, action)
+                    self.game.controller.make_move(self, action)
                 } else {
                     self.game.controller.make_drop_move(self, action)
                 }
@@ -401,7 +486,7 @@ impl<'a, const T: usize> Board<'a, T> {
                         );
                     }
                     Move::Pass => {
-                        reverse_turns(&mut self.state, &self.game, &history_move);
+                        reverse_turns(&mut self.state, &history_move);
                     }
                 };
             }
@@ -415,4 +500,31 @@ impl<'a, const T: usize> Board<'a, T> {
             }
         }
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use crate::{board::game::NORMAL_MODE, games::chess::Chess};
+
+    #[test]
+    fn full_moves_advance_after_last_team() {
+        let chess = Chess::create();
+        let mut board = chess.default();
+
+        // White moving doesn't finish the full move; it's only over once black has replied.
+        let action = board.decode_action("e2e4", NORMAL_MODE).expect("e2e4 should be legal.");
+        let first_undo = board.make_move(&action);
+        assert_eq!(board.state.full_moves, 1);
+        assert_eq!(board.get_turn_info().full_moves, 1);
+
+        let action = board.decode_action("e7e5", NORMAL_MODE).expect("e7e5 should be legal.");
+        let second_undo = board.make_move(&action);
+        assert_eq!(board.state.full_moves, 2);
+        assert_eq!(board.get_turn_info().full_moves, 2);
+        assert_eq!(board.to_fen(), "rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq e6 0 2");
+
+        board.undo_move(second_undo);
+        assert_eq!(board.state.full_moves, 1);
+        board.undo_move(first_undo);
+        assert_eq!(board.to_fen(), "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1");
+    }
+}
@@ -27,6 +27,10 @@ impl<const T: usize> ZobristHashTable<T> {
         (moving_team as usize) + (position + (self.squares * (1 + (piece_type + (self.pieces * team))))) as usize
     }
 
+    fn get_reserve_index(&self, team: u16, piece_type: u16) -> usize {
+        self.base_len - (self.teams * self.pieces) as usize + (piece_type + (self.pieces * team)) as usize
+    }
+
     pub fn compute(&self, board: &Board<T>) -> u64 {
         let mut hash = 0;
         let moving_team = board.state.moving_team;
@@ -50,12 +54,24 @@ impl<const T: usize> ZobristHashTable<T> {
             }
         }       
 
+        for team in 0..self.teams {
+            for piece_type in 0..self.pieces {
+                let count = board.state.reserves[team as usize][piece_type as usize];
+                if count > 0 {
+                    // Rotating the hash gives each count its own hash, without needing a table entry for every possible count.
+                    hash ^= self.table[self.get_reserve_index(team, piece_type)].rotate_left(count as u32);
+                }
+            }
+        }
+
+        board.game.zobrist_controller.apply(&mut hash, self, board);
+
         hash
     }
 
     pub fn generate(squares: u16, teams: u16, pieces: u16, extra_hashes: usize, get_random: impl Fn() -> u64) -> ZobristHashTable<T> {
         let base_len = (
-            (teams - 1) + (squares) + (squares * (1 + ((pieces - 1) + (pieces * (teams - 1))))) + 1
+            (teams - 1) + (squares) + (squares * (1 + ((pieces - 1) + (pieces * (teams - 1))))) + 1 + (teams * pieces)
         ) as usize;
         let hashes = base_len + (extra_hashes as usize);
         let mut zobrist = ZobristHashTable {
@@ -0,0 +1,101 @@
+use crate::{
+    bitboard::{BitBoard, Direction},
+    board::{
+        actions::{Action, HistoryMove, Move, TheoreticalMove, TurnUpdate},
+        game::{Game, MoveController},
+        Board, BoardState,
+    },
+    games::chess::{
+        pieces::{get_castling_squares, CASTLING_MOVE, KING_PIECE_TYPE},
+        ChessMoveController,
+    },
+};
+
+use super::layers::get_other_layer_square;
+
+/// Gets the squares the pieces moved by `action` land on, before they're sent through to the other layer.
+/// Castling moves both the king and the rook, which both go through.
+pub fn get_landing_squares<const T: usize>(board: &Board<T>, action: &Action) -> BitBoard<T> {
+    match action.from {
+        Some(from) if action.piece_type == KING_PIECE_TYPE && action.move_type == CASTLING_MOVE => {
+            let dir = if from < action.to { Direction::RIGHT } else { Direction::LEFT };
+            let (king_dest, rook_dest) = get_castling_squares(board, action.team, dir);
+            BitBoard::from_lsb(king_dest) | BitBoard::from_lsb(rook_dest)
+        }
+        _ => BitBoard::from_lsb(action.to),
+    }
+}
+
+/// Gets the squares on the other layer that are in the same place as `squares`.
+pub fn get_other_layer<const T: usize>(board: &Board<T>, squares: BitBoard<T>) -> BitBoard<T> {
+    let mut other_layer = BitBoard::new();
+    for square in squares.iter_set_bits(board.state.squares) {
+        other_layer |= BitBoard::from_lsb(get_other_layer_square(square));
+    }
+    other_layer
+}
+
+/// In Alice chess, pieces move just like in chess on the layer they're on, and then go through the looking glass to the same square on the other layer.
+/// A move is only legal if the square it goes through to is empty, and the team's king isn't left in check once it's there.
+#[derive(Debug)]
+pub struct AliceMoveController;
+
+impl<const T: usize> MoveController<T> for AliceMoveController {
+    #[allow(unused_variables)]
+    fn transform_moves(&self, board: &mut Board<T>, mode: u16, actions: Vec<Move>) -> Vec<Move> {
+        actions.into_iter().filter(|action| self.is_legal(board, action)).collect()
+    }
+
+    fn is_legal(&self, board: &mut Board<T>, action: &Move) -> bool {
+        if let Move::Action(action) = action {
+            let destinations = get_other_layer(board, get_landing_squares(board, action));
+            if (destinations & board.state.all_pieces).is_set() {
+                return false;
+            }
+        }
+
+        ChessMoveController::<T>.is_legal(board, action)
+    }
+
+    fn use_pseudolegal(&self) -> bool {
+        true
+    }
+
+    fn make_move(&self, board: &mut Board<T>, action: &Action) -> Option<HistoryMove<T>> {
+        let landing_squares = get_landing_squares(board, action);
+        let history_move = board.game.pieces[action.piece_type as usize].make_move(board, action)?;
+
+        // The pieces only change squares within the team's and their own bitboards, which the move already saved.
+        let team = action.team as usize;
+        for square in landing_squares.iter_set_bits(board.state.squares) {
+            let square_board = BitBoard::from_lsb(square);
+            let piece_type = (0..board.game.pieces.len())
+                .find(|&piece_type| (board.state.pieces[piece_type] & square_board).is_set())
+                .expect("A piece should have landed on the square it moved to.");
+
+            let through = square_board | BitBoard::from_lsb(get_other_layer_square(square));
+            board.state.pieces[piece_type] ^= through;
+            board.state.teams[team] ^= through;
+            board.state.all_pieces ^= through;
+            board.state.first_move &= !through;
+        }
+
+        Some(history_move)
+    }
+
+    fn encode_action(&self, board: &Board<T>, action: &Move) -> Vec<String> {
+        ChessMoveController::<T>.encode_action(board, action)
+    }
+
+    fn update(&self, action: &Move, state: &BoardState<T>, game: &Game<T>) -> TurnUpdate {
+        ChessMoveController::<T>.update(action, state, game)
+    }
+
+    fn get_theoretical_moves(&self, board: &Board<T>) -> Vec<TheoreticalMove> {
+        ChessMoveController::<T>.get_theoretical_moves(board)
+    }
+
+    fn get_max_available_moves(&self) -> u32 {
+        ChessMoveController::<T>.get_max_available_moves()
+    }
+}
@@ -0,0 +1,147 @@
+use crate::{
+    board::{
+        fen::{FenFullMoves, FenOptions, FenState, FenSubMoves, FenTeamArgument},
+        game::{DefaultZobristController, Game},
+        pieces::Piece,
+        zobrist::ZobristHashTable,
+    },
+    games::chess::{
+        pieces::{BishopPiece, KingPiece, KnightPiece, PawnPiece, QueenPiece, RookPiece},
+        ChessCastlingRights, ChessEnPassant, ChessResolution,
+    },
+};
+
+use super::{layers::{get_alice_regions, COLS, ROWS}, AliceMoveController, AlicePostProcess};
+
+const PAWN: &dyn Piece<2> = &PawnPiece { king_promotion: false, first_move_steps: 2, promotion_pieces: None };
+const KNIGHT: &dyn Piece<2> = &KnightPiece;
+const BISHOP: &dyn Piece<2> = &BishopPiece;
+const ROOK: &dyn Piece<2> = &RookPiece;
+const QUEEN: &dyn Piece<2> = &QueenPiece;
+const KING: &dyn Piece<2> = &KingPiece;
+
+pub struct Alice;
+
+impl Alice {
+    /// Alice chess starts with chess's position on the first layer, and an empty second layer (see `layers` for how they're laid out.)
+    ///
+    /// A pawn that steps two squares always ends up on the other layer from the square it stepped over, so pawns can't be captured en passant.
+    pub fn create() -> Game<2> {
+        let squares = ROWS * COLS;
+
+        Game {
+            teams: 2,
+            turns: vec![1, 1],
+            rows: ROWS,
+            cols: COLS,
+            squares,
+            saved_last_moves: 1,
+            regions: get_alice_regions(),
+            zobrist_controller: Box::new(DefaultZobristController),
+            zobrist: ZobristHashTable::<2>::generate(squares, 2, 6, squares as usize + 1, || fastrand::u64(0..u64::MAX)),
+            name: String::from("Alice"),
+            pieces: vec![PAWN, KNIGHT, BISHOP, ROOK, QUEEN, KING],
+            controller: Box::new(AliceMoveController),
+            resolution: Box::new(ChessResolution),
+            fen_options: FenOptions {
+                state: FenState { first_moves: false, gaps: '*', reserves: false },
+                args: vec![
+                    (
+                        "team to move".to_string(),
+                        Box::new(FenTeamArgument::Teams(vec!['w', 'b'])),
+                    ),
+                    ("castling rights".to_string(), Box::new(ChessCastlingRights::XFen)),
+                    ("en passant".to_string(), Box::new(ChessEnPassant)),
+                    ("half moves".to_string(), Box::new(FenSubMoves)),
+                    ("full moves".to_string(), Box::new(FenFullMoves)),
+                ],
+                post_process: Box::new(AlicePostProcess),
+                default_fen: String::from("rnbqkbnr**8/pppppppp**8/8**8/8**8/8**8/8**8/PPPPPPPP**8/RNBQKBNR**8 w KQkq - 0 1")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::game::NORMAL_MODE;
+
+    use super::Alice;
+
+    fn encode_moves(fen: &str) -> Vec<String> {
+        let alice = Alice::create();
+        let mut board = alice.from_fen(fen);
+        board
+            .generate_legal_moves(NORMAL_MODE)
+            .iter()
+            .map(|action| board.encode_action(action))
+            .collect()
+    }
+
+    #[test]
+    fn fen() {
+        let alice = Alice::create();
+        for fen in [
+            "rnbqkbnr**8/pppppppp**8/8**8/8**8/8**8/8**8/PPPPPPPP**8/RNBQKBNR**8 w KQkq - 0 1",
+            "rnb1kbnr**8/pppp1ppp**8/8**4p3/8**8/8**4P2q/8**8/PPPP1PPP**8/RNBQKBNR**8 w KQkq - 1 3",
+        ] {
+            assert_eq!(alice.from_fen(fen).to_fen(), fen);
+        }
+    }
+
+    #[test]
+    fn through_the_looking_glass() {
+        let alice = Alice::create();
+        let mut board = alice.default();
+
+        // Pieces move on their own layer, then go through to the same square on the other layer (the `e` file is the `o` file there.)
+        let action = board.decode_action("e2e4", NORMAL_MODE).expect("e2e4 should be legal.");
+        board.make_move(&action);
+        assert_eq!(board.to_fen(), "rnbqkbnr**8/pppppppp**8/8**8/8**8/8**4P3/8**8/PPPP1PPP**8/RNBQKBNR**8 b KQkq - 0 1");
+
+        let action = board.decode_action("e7e5", NORMAL_MODE).expect("e7e5 should be legal.");
+        board.make_move(&action);
+        assert_eq!(board.to_fen(), "rnbqkbnr**8/pppp1ppp**8/8**8/8**4p3/8**4P3/8**8/PPPP1PPP**8/RNBQKBNR**8 w KQkq - 0 2");
+
+        // Both pawns are on the second layer now, so white's pawn is blocked.
+        let moves = encode_moves(&board.to_fen());
+        assert!(moves.iter().all(|action| !action.starts_with("o4")));
+    }
+
+    #[test]
+    fn destination_must_be_empty() {
+        // Moves can only be made if the square on the other layer is empty, even if it's an opposing piece.
+        let moves = encode_moves("4k3**8/8**8/8**8/8**8/8**n7/8**8/8**8/R3K3**8 w - - 0 1");
+        assert!(moves.contains(&"a1a3".to_string()));
+        assert!(!moves.contains(&"a1a4".to_string()));
+        assert!(moves.contains(&"a1a5".to_string()));
+    }
+
+    #[test]
+    fn check_across_layers() {
+        // The bishop can't move, since it would go through to the other layer and leave its king in check.
+        let moves = encode_moves("4r3**4k3/8**8/8**8/8**8/8**8/8**8/4B3**8/4K3**8 w - - 0 1");
+        assert!(moves.iter().all(|action| !action.starts_with("e2")));
+
+        // The king isn't in check from the rook on the other layer, but it can't go through to a square the rook attacks.
+        let mut moves = encode_moves("4k3**8/8**8/8**8/8**8/8**8/8**8/8**8/4K3**4r3 w - - 0 1");
+        moves.sort();
+        assert_eq!(moves, vec!["e1d2", "e1f2"]);
+    }
+
+    #[test]
+    fn castling() {
+        let alice = Alice::create();
+        let mut board = alice.from_fen("r3k2r**8/8**8/8**8/8**8/8**8/8**8/8**8/R3K2R**8 w KQkq - 0 1");
+
+        // Both the king and the rook go through to the other layer.
+        let action = board.decode_action("e1g1", NORMAL_MODE).expect("Kingside castling should be legal.");
+        board.make_move(&action);
+        assert_eq!(board.to_fen(), "r3k2r**8/8**8/8**8/8**8/8**8/8**8/8**8/R7**5RK1 b kq - 1 1");
+
+        // Castling is only legal if the squares the king and rook go through to are empty.
+        let moves = encode_moves("r3k2r**8/8**8/8**8/8**8/8**8/8**8/8**8/R3K2R**5N2 w KQkq - 0 1");
+        assert!(moves.contains(&"e1a1".to_string()));
+        assert!(!moves.contains(&"e1h1".to_string()));
+    }
+}
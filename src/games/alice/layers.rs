@@ -0,0 +1,43 @@
+use crate::{
+    bitboard::BitBoard,
+    board::{regions::{get_rectangle, Region}, Cols, Rows},
+    games::chess::pieces::CASTLING_REGION,
+};
+
+/// Alice chess is played on two 8x8 boards, which are laid out side by side as layers of one 8x18 grid (`Game<2>`):
+/// the first board is on the `a`-`h` files, the second board is on the `k`-`r` files, and the `i` and `j` files between them are walls (`*` in FENs.)
+///
+/// The walls are two files wide so that no piece can reach the other board, not even a knight jumping two files.
+/// Since both boards share their ranks, each team's back rank (and the rank its pawns promote on) spans both boards.
+pub const ROWS: Rows = 8;
+pub const COLS: Cols = 18;
+
+/// How many files wide each layer is.
+pub const LAYER_COLS: Cols = 8;
+/// How many files of walls there are between the two layers.
+pub const WALL_COLS: Cols = 2;
+
+/// Gets the square in the same place as `square` on the other layer, where pieces are sent to after they move.
+pub fn get_other_layer_square(square: u16) -> u16 {
+    if square % COLS < LAYER_COLS {
+        square + LAYER_COLS + WALL_COLS
+    } else {
+        square - LAYER_COLS - WALL_COLS
+    }
+}
+
+/// Gets the walls between the two layers.
+pub fn get_walls<const T: usize>() -> BitBoard<T> {
+    get_rectangle(0..ROWS, LAYER_COLS..LAYER_COLS + WALL_COLS, COLS)
+}
+
+/// Gets the regions of the Alice chess board.
+/// Kings can only castle on the first layer (where they start), and castle to the usual `c` and `g` files instead of the edges of the grid.
+pub fn get_alice_regions<const T: usize>() -> Vec<Region<T>> {
+    vec![
+        Region::new(CASTLING_REGION, vec![
+            get_rectangle(ROWS - 1..ROWS, 2..3, COLS) | get_rectangle(ROWS - 1..ROWS, 6..7, COLS),
+            get_rectangle(0..1, 2..3, COLS) | get_rectangle(0..1, 6..7, COLS),
+        ]),
+    ]
+}
@@ -0,0 +1,9 @@
+mod game;
+mod controller;
+mod process;
+mod suite;
+pub mod layers;
+
+pub use game::*;
+pub use controller::*;
+pub use process::*;
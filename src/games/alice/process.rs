@@ -0,0 +1,15 @@
+use crate::{board::{fen::PostProcess, Board}, games::chess::ChessPostProcess};
+
+use super::layers::get_walls;
+
+#[derive(Debug)]
+pub struct AlicePostProcess;
+impl<const T: usize> PostProcess<T> for AlicePostProcess {
+    fn apply(&self, board: &mut Board<T>) {
+        PostProcess::<T>::apply(&ChessPostProcess, board);
+
+        // The walls between the layers block sliding pieces, just like pieces would.
+        board.state.gaps |= get_walls();
+        board.state.all_pieces |= board.state.gaps;
+    }
+}
@@ -0,0 +1,13 @@
+use crate::board::{tests::run_tests};
+use super::Alice;
+
+const TEST_STR: &str = r#"rnbqkbnr**8/pppppppp**8/8**8/8**8/8**8/8**8/PPPPPPPP**8/RNBQKBNR**8 w KQkq - 0 1 ;D1 20 ;D2 400 ;D3 9388 ;D4 219533
+rnbqkbnr**8/pppp1ppp**8/8**8/8**4p3/8**4P3/8**8/PPPP1PPP**8/RNBQKBNR**8 w KQkq - 0 2 ;D1 29 ;D2 835 ;D3 26412
+r1bqk2r**8/pppp1ppp**8/2n2n2**8/8**2b1p3/8**2B1P3/5N2**8/PPPP1PPP**8/RNBQK2R**8 w KQkq - 4 4 ;D1 31 ;D2 1051 ;D3 33752
+r3k2r**8/8**8/8**8/8**8/8**8/8**8/8**8/R3K2R**8 w KQkq - 0 1 ;D1 26 ;D2 623 ;D3 16521
+4k3**8/1P6**8/8**8/8**3q4/8**8/8**8/8**6p1/R3K3**8 w Q - 0 1 ;D1 17 ;D2 555 ;D3 9957"#;
+
+#[test]
+fn alice_perft_suite() {
+    run_tests("Alice", Alice::create(), TEST_STR);
+}
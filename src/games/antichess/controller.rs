@@ -0,0 +1,74 @@
+use crate::{
+    bitboard::BitBoard,
+    board::{
+        actions::{Move, TheoreticalMove, TurnUpdate},
+        game::{Game, MoveController, NORMAL_MODE},
+        Board, BoardState,
+    },
+    games::chess::{pieces::EN_PASSANT_MOVE, ChessMoveController},
+};
+
+const PAWN: u16 = 0;
+
+/// Antichess (or losing chess) is played with the rules of chess, except captures are compulsory and there's no check.
+/// The king is an ordinary piece, so it can be captured, and pawns can promote to it.
+#[derive(Debug)]
+pub struct AntichessMoveController;
+
+impl AntichessMoveController {
+    pub fn is_capture<const T: usize>(board: &Board<T>, action: &Move) -> bool {
+        match action {
+            Move::Action(action) => {
+                let opponent = board.get_next_team(action.team) as usize;
+                let is_en_passant = action.piece_type == PAWN && action.move_type == EN_PASSANT_MOVE;
+                is_en_passant || (BitBoard::<T>::from_lsb(action.to) & board.state.teams[opponent]).is_set()
+            }
+            Move::Pass => false,
+        }
+    }
+}
+
+impl<const T: usize> MoveController<T> for AntichessMoveController {
+    #[allow(unused_variables)]
+    fn transform_moves(&self, board: &mut Board<T>, mode: u16, actions: Vec<Move>) -> Vec<Move> {
+        // If any capture can be made, one of them has to be.
+        if actions.iter().any(|action| AntichessMoveController::is_capture(board, action)) {
+            actions.into_iter().filter(|action| AntichessMoveController::is_capture(board, action)).collect()
+        } else {
+            actions
+        }
+    }
+
+    fn is_legal(&self, board: &mut Board<T>, action: &Move) -> bool {
+        if let Move::Pass = action {
+            return false;
+        }
+
+        if AntichessMoveController::is_capture(board, action) {
+            return true;
+        }
+
+        // Other moves can only be made if there aren't any captures.
+        !board.generate_moves(NORMAL_MODE).iter().any(|action| AntichessMoveController::is_capture(board, action))
+    }
+
+    fn use_pseudolegal(&self) -> bool {
+        true
+    }
+
+    fn encode_action(&self, board: &Board<T>, action: &Move) -> Vec<String> {
+        ChessMoveController::<T>.encode_action(board, action)
+    }
+
+    fn update(&self, action: &Move, state: &BoardState<T>, game: &Game<T>) -> TurnUpdate {
+        ChessMoveController::<T>.update(action, state, game)
+    }
+
+    fn get_theoretical_moves(&self, board: &Board<T>) -> Vec<TheoreticalMove> {
+        ChessMoveController::<T>.get_theoretical_moves(board)
+    }
+
+    fn get_max_available_moves(&self) -> u32 {
+        ChessMoveController::<T>.get_max_available_moves()
+    }
+}
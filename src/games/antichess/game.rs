@@ -0,0 +1,65 @@
+use crate::{
+    board::{game::Game, pieces::Piece},
+    games::chess::{pieces::PawnPiece, Chess},
+};
+
+use super::{AntichessMoveController, AntichessResolution};
+
+const PAWN: &dyn Piece<1> = &PawnPiece { king_promotion: true, first_move_steps: 2, promotion_pieces: None };
+
+pub struct Antichess;
+
+impl Antichess {
+    pub fn create() -> Game<1> {
+        let mut game = Chess::create();
+        game.name = String::from("Antichess");
+        game.pieces[0] = PAWN;
+        game.controller = Box::new(AntichessMoveController);
+        game.resolution = Box::new(AntichessResolution);
+        game.fen_options.default_fen = String::from("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w - - 0 1");
+        game
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::{actions::Move, game::{GameResults, NORMAL_MODE}};
+
+    use super::Antichess;
+
+    #[test]
+    fn forced_captures() {
+        let antichess = Antichess::create();
+        let mut board = antichess.from_fen("rnbqkbnr/pppp1ppp/8/4p3/3P4/8/PPP1PPPP/RNBQKBNR w - e6 0 2");
+
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert_eq!(moves.len(), 1);
+        assert_eq!(board.encode_action(&moves[0]), "d4e5");
+
+        let quiet_move = board.decode_action("e2e4", NORMAL_MODE).expect("e2e4 should be generated.");
+        assert!(!antichess.controller.is_legal(&mut board, &quiet_move));
+    }
+
+    #[test]
+    fn king_promotion() {
+        let antichess = Antichess::create();
+        let mut board = antichess.from_fen("8/1P6/8/8/8/8/6p1/8 w - - 0 1");
+
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert!(moves.iter().any(|action| board.encode_action(action) == "b7b8k"));
+        assert!(moves.iter().all(|action| matches!(action, Move::Action(_))));
+    }
+
+    #[test]
+    fn losing_all_pieces_wins() {
+        let antichess = Antichess::create();
+        let mut board = antichess.from_fen("8/8/8/8/8/8/p7/1R6 b - - 0 1");
+
+        let action = board.decode_action("a2b1q", NORMAL_MODE).expect("a2xb1=Q should be legal.");
+        board.make_move(&action);
+
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert!(moves.is_empty());
+        assert!(matches!(antichess.resolution.resolve(&mut board, &moves), GameResults::Win(0)));
+    }
+}
@@ -0,0 +1,18 @@
+use crate::{
+    board::{game::{Resolution, GameResults}, Board, actions::Move},
+    games::chess::ChessResolution,
+};
+
+#[derive(Debug)]
+pub struct AntichessResolution;
+
+impl<const T: usize> Resolution<T> for AntichessResolution {
+    fn resolve(&self, board: &mut Board<T>, legal_moves: &Vec<Move>) -> GameResults {
+        // A team that has lost all of their pieces or has been stalemated wins.
+        if legal_moves.is_empty() {
+            return GameResults::Win(board.state.moving_team);
+        }
+
+        ChessResolution::<T>.resolve(board, legal_moves)
+    }
+}
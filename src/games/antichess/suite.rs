@@ -0,0 +1,15 @@
+use crate::board::{tests::run_tests};
+use super::Antichess;
+
+const TEST_STR: &str = r#"rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w - - 0 1 ;D1 20 ;D2 400 ;D3 8067 ;D4 153299 ;D5 2732672
+rnbqkbnr/pppp1ppp/8/4p3/3P4/8/PPP1PPPP/RNBQKBNR w - e6 0 2 ;D1 1 ;D2 29 ;D3 42 ;D4 215 ;D5 2145
+rnb1kbnr/pppp1ppp/8/4p3/4P2q/8/PPPP1PPP/RNBQKBNR w - - 2 3 ;D1 28 ;D2 92 ;D3 655 ;D4 3714 ;D5 26843
+8/1P6/8/8/8/8/6p1/8 w - - 0 1 ;D1 5 ;D2 25 ;D3 250 ;D4 2112 ;D5 25573
+8/8/8/8/8/8/p7/1R6 w - - 0 1 ;D1 14 ;D2 65 ;D3 520 ;D4 3797 ;D5 42100
+8/2P5/8/8/8/8/8/1n6 b - - 0 1 ;D1 3 ;D2 15 ;D3 90 ;D4 819 ;D5 4134
+8/p7/8/1P6/8/8/8/8 b - - 0 1 ;D1 2 ;D2 2 ;D3 0 ;D4 0"#;
+
+#[test]
+fn antichess_perft_suite() {
+    run_tests("Antichess", Antichess::create(), TEST_STR);
+}
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use crate::{board::{game::{MoveController, NORMAL_MODE, get_theoretical_moves_bound}, Board, actions::{Action, TheoreticalAction, Move, TheoreticalMove, TurnUpdate, CounterUpdate}, BoardState}, bitboard::BitBoard};
+use crate::{board::{game::{Game, MoveController, NORMAL_MODE, get_theoretical_moves_bound}, Board, actions::{Action, TheoreticalAction, Move, TheoreticalMove, TurnUpdate, CounterUpdate}, BoardState}, bitboard::BitBoard};
 
 use super::is_single_move;
 
@@ -85,7 +85,7 @@ impl<const T: usize> MoveController<T> for AtaxxMoveController {
         ]
     }
 
-    fn update(&self, action: &Move, state: &BoardState<T>) -> TurnUpdate {
+    fn update(&self, action: &Move, state: &BoardState<T>, game: &Game<T>) -> TurnUpdate {
         TurnUpdate {
             turns: CounterUpdate::Next,
             sub_moves: match action {
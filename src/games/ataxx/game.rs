@@ -20,11 +20,12 @@ impl Ataxx {
     pub fn create() -> Game<1> {
         Game {
             teams: 2,
-            turns: 1,
+            turns: vec![1, 1],
             rows: 7,
             cols: 7,
             squares: 49,
             saved_last_moves: 0,
+            regions: vec![],
             zobrist_controller: Box::new(DefaultZobristController),
             zobrist: ZobristHashTable::<1>::generate(49, 2, 1, 0, || fastrand::u64(0..u64::MAX)),
             name: String::from("Ataxx"),
@@ -32,7 +33,7 @@ impl Ataxx {
             controller: Box::new(AtaxxMoveController),
             resolution: Box::new(AtaxxResolution),
             fen_options: FenOptions {
-                state: FenState { first_moves: false, gaps: '-' },
+                state: FenState { first_moves: false, gaps: '-', reserves: false },
                 args: vec![
                     (
                         "team to move".to_string(),
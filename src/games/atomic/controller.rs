@@ -0,0 +1,191 @@
+use crate::{
+    bitboard::BitBoard,
+    board::{
+        actions::{Action, HistoryMove, HistoryState, HistoryUpdate, IndexedPreviousBoard, Move, TheoreticalMove, TurnUpdate},
+        game::{Game, MoveController, ATTACKS_MODE},
+        Board, BoardState,
+    },
+    games::chess::{pieces::{KING_PIECE_TYPE, PAWN_PIECE_TYPE}, ChessMoveController},
+};
+
+/// Atomic chess is played with the rules of chess, except every capture causes an explosion on the captured square.
+/// The explosion removes the capturing piece and all pieces other than pawns next to the captured square.
+/// Exploding the opponent's king wins the game.
+#[derive(Debug)]
+pub struct AtomicMoveController;
+
+impl AtomicMoveController {
+    /// Finds all of the squares next to `square`.
+    fn get_neighbors<const T: usize>(board: &Board<T>, square: BitBoard<T>) -> BitBoard<T> {
+        board.game.pieces[KING_PIECE_TYPE as usize].get_moves(board, square, KING_PIECE_TYPE, 0, ATTACKS_MODE)
+    }
+
+    /// Whether or not `team` has lost their king to an explosion.
+    pub fn is_exploded<const T: usize>(board: &Board<T>, team: u16) -> bool {
+        (board.state.pieces[KING_PIECE_TYPE as usize] & board.state.teams[team as usize]).is_empty()
+    }
+
+    /// Whether or not `team`'s king is in check.
+    /// Kings that touch can't be in check, since capturing either of them would explode both.
+    pub fn is_in_check<const T: usize>(board: &Board<T>, team: u16) -> bool {
+        let opponent = board.get_next_team(team);
+        let king = board.state.pieces[KING_PIECE_TYPE as usize] & board.state.teams[team as usize];
+        let opponent_king = board.state.pieces[KING_PIECE_TYPE as usize] & board.state.teams[opponent as usize];
+
+        if king.is_empty() || opponent_king.is_empty() {
+            return false;
+        }
+
+        if (AtomicMoveController::get_neighbors(board, king) & opponent_king).is_set() {
+            return false;
+        }
+
+        board.can_move(opponent, king, ATTACKS_MODE)
+    }
+
+    /// Removes every piece in `blast` from the board.
+    /// Every bitboard the explosion changes is recorded on `history_move` (unless it already was), so it can be undone.
+    fn explode<const T: usize>(board: &mut Board<T>, history_move: &mut HistoryMove<T>, blast: BitBoard<T>) {
+        let (recorded_teams, recorded_pieces): (Vec<usize>, Vec<usize>) = match &history_move.state {
+            HistoryState::Any { updates, .. } => (
+                updates.iter().filter_map(|update| match update {
+                    HistoryUpdate::Team(team) => Some(team.0),
+                    _ => None,
+                }).collect(),
+                updates.iter().filter_map(|update| match update {
+                    HistoryUpdate::Piece(piece) => Some(piece.0),
+                    _ => None,
+                }).collect(),
+            ),
+            HistoryState::Single { team, piece, .. } => (vec![team.0], vec![piece.0]),
+            HistoryState::None => (vec![], vec![]),
+        };
+
+        let mut updates = vec![];
+        for (team, team_board) in board.state.teams.iter_mut().enumerate() {
+            if (*team_board & blast).is_set() {
+                if !recorded_teams.contains(&team) {
+                    updates.push(HistoryUpdate::Team(IndexedPreviousBoard(team, *team_board)));
+                }
+                *team_board &= !blast;
+            }
+        }
+
+        for (piece_type, piece_board) in board.state.pieces.iter_mut().enumerate() {
+            if (*piece_board & blast).is_set() {
+                if !recorded_pieces.contains(&piece_type) {
+                    updates.push(HistoryUpdate::Piece(IndexedPreviousBoard(piece_type, *piece_board)));
+                }
+                *piece_board &= !blast;
+            }
+        }
+
+        board.state.all_pieces &= !blast;
+        board.state.first_move &= !blast;
+
+        history_move.add_updates(updates);
+    }
+}
+
+impl<const T: usize> MoveController<T> for AtomicMoveController {
+    #[allow(unused_variables)]
+    fn transform_moves(&self, board: &mut Board<T>, mode: u16, actions: Vec<Move>) -> Vec<Move> {
+        // Once a king has exploded, the game is over.
+        if (0..board.game.teams).any(|team| AtomicMoveController::is_exploded(board, team)) {
+            return vec![];
+        }
+
+        let mut legal_moves = Vec::with_capacity(actions.len());
+        for action in actions {
+            if self.is_legal(board, &action) {
+                legal_moves.push(action);
+            }
+        }
+        legal_moves
+    }
+
+    fn is_legal(&self, board: &mut Board<T>, action: &Move) -> bool {
+        match action {
+            Move::Action(action) => {
+                let team = action.team;
+                let opponent = board.get_next_team(team);
+
+                // Kings can't capture, since they would explode themselves.
+                let to = BitBoard::from_lsb(action.to);
+                if action.piece_type == KING_PIECE_TYPE && (to & board.state.teams[opponent as usize]).is_set() {
+                    return false;
+                }
+
+                let undo = board.make_move(&Move::Action(*action));
+                // Exploding your own king is never legal, but exploding the opponent's king wins, even if you're left in check.
+                let is_legal = !AtomicMoveController::is_exploded(board, team)
+                    && (AtomicMoveController::is_exploded(board, opponent) || !AtomicMoveController::is_in_check(board, team));
+                board.undo_move(undo);
+
+                is_legal
+            }
+            Move::Pass => false,
+        }
+    }
+
+    fn use_pseudolegal(&self) -> bool {
+        true
+    }
+
+    fn get_castling_attacks(&self, board: &Board<T>, team: u16) -> BitBoard<T> {
+        let opponent = board.get_next_team(team);
+        let opponent_king = board.state.pieces[KING_PIECE_TYPE as usize] & board.state.teams[opponent as usize];
+
+        // Kings can't attack anything, and a king next to the opponent's king can't be checked.
+        let mut attacks = BitBoard::new();
+        for (piece_type, pieces) in board.state.pieces.iter().enumerate() {
+            if piece_type == KING_PIECE_TYPE as usize {
+                continue;
+            }
+
+            let pieces = *pieces & board.state.teams[opponent as usize];
+            for square in pieces.iter_set_bits(board.state.squares) {
+                attacks |= board.game.pieces[piece_type].get_moves(board, BitBoard::from_lsb(square), piece_type as u16, opponent, ATTACKS_MODE);
+            }
+        }
+
+        if opponent_king.is_set() {
+            attacks &= !AtomicMoveController::get_neighbors(board, opponent_king);
+        }
+
+        attacks
+    }
+
+    fn make_move(&self, board: &mut Board<T>, action: &Action) -> Option<HistoryMove<T>> {
+        let opponent = board.get_next_team(action.team) as usize;
+        let to = BitBoard::from_lsb(action.to);
+
+        let opponent_pieces = board.state.teams[opponent];
+        let mut history_move = board.game.pieces[action.piece_type as usize].make_move(board, action)?;
+
+        // Castling moves onto a piece of the same team, so a capture is only made if the opponent lost a piece.
+        if (opponent_pieces & !board.state.teams[opponent]).is_set() {
+            let neighbors = AtomicMoveController::get_neighbors(board, to);
+            let blast = to | (neighbors & board.state.all_pieces & !board.state.pieces[PAWN_PIECE_TYPE as usize]);
+            AtomicMoveController::explode(board, &mut history_move, blast);
+        }
+
+        Some(history_move)
+    }
+
+    fn encode_action(&self, board: &Board<T>, action: &Move) -> Vec<String> {
+        ChessMoveController::<T>.encode_action(board, action)
+    }
+
+    fn update(&self, action: &Move, state: &BoardState<T>, game: &Game<T>) -> TurnUpdate {
+        ChessMoveController::<T>.update(action, state, game)
+    }
+
+    fn get_theoretical_moves(&self, board: &Board<T>) -> Vec<TheoreticalMove> {
+        ChessMoveController::<T>.get_theoretical_moves(board)
+    }
+
+    fn get_max_available_moves(&self) -> u32 {
+        ChessMoveController::<T>.get_max_available_moves()
+    }
+}
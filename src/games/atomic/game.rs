@@ -0,0 +1,63 @@
+use crate::{board::game::Game, games::chess::Chess};
+
+use super::{AtomicMoveController, AtomicResolution};
+
+pub struct Atomic;
+
+impl Atomic {
+    pub fn create() -> Game<1> {
+        let mut game = Chess::create();
+        game.name = String::from("Atomic");
+        game.controller = Box::new(AtomicMoveController);
+        game.resolution = Box::new(AtomicResolution);
+        game
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::game::{GameResults, NORMAL_MODE};
+
+    use super::Atomic;
+
+    #[test]
+    fn explosions() {
+        let atomic = Atomic::create();
+        let fen = "rnbqkb1r/pp1p1ppp/2p5/4P3/2B5/8/PPP1NnPP/RNBQK2R w KQkq - 0 6";
+        let mut board = atomic.from_fen(fen);
+
+        let state = board.state.clone();
+        let hash = atomic.zobrist.compute(&board);
+
+        // Bxf7 explodes the bishop, along with the black king and bishop next to f7. The pawns next to it survive.
+        let action = board.decode_action("c4f7", NORMAL_MODE).expect("Bxf7 should be legal.");
+        let undo = board.make_move(&action);
+        assert_eq!(board.to_fen(), "rnbq3r/pp1p2pp/2p5/4P3/8/8/PPP1NnPP/RNBQK2R b KQ - 0 6");
+
+        board.undo_move(undo);
+        assert_eq!(board.state, state);
+        assert_eq!(atomic.zobrist.compute(&board), hash);
+        assert_eq!(board.to_fen(), fen);
+    }
+
+    #[test]
+    fn kings_cant_capture() {
+        let atomic = Atomic::create();
+        let mut board = atomic.from_fen("4k3/8/8/8/8/8/3p4/4K3 w - - 0 1");
+        assert!(board.decode_action("e1d2", NORMAL_MODE).is_some());
+        assert_eq!(board.generate_legal_moves(NORMAL_MODE).len(), 4);
+    }
+
+    #[test]
+    fn exploded_king_loses() {
+        let atomic = Atomic::create();
+        let mut board = atomic.from_fen("4k3/4p3/8/8/8/8/8/4RK2 w - - 0 1");
+
+        let action = board.decode_action("e1e7", NORMAL_MODE).expect("Rxe7 should be legal.");
+        board.make_move(&action);
+
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert!(moves.is_empty());
+        assert!(matches!(atomic.resolution.resolve(&mut board, &moves), GameResults::Win(0)));
+    }
+}
@@ -0,0 +1,24 @@
+use crate::{
+    board::{game::{Resolution, GameResults}, Board, actions::Move},
+    games::chess::ChessResolution,
+};
+
+use super::AtomicMoveController;
+
+#[derive(Debug)]
+pub struct AtomicResolution;
+
+impl<const T: usize> Resolution<T> for AtomicResolution {
+    fn resolve(&self, board: &mut Board<T>, legal_moves: &Vec<Move>) -> GameResults {
+        let team = board.state.moving_team;
+        if legal_moves.is_empty() {
+            return if AtomicMoveController::is_exploded(board, team) || AtomicMoveController::is_in_check(board, team) {
+                GameResults::Win(board.get_next_team(team))
+            } else {
+                GameResults::Draw
+            };
+        }
+
+        ChessResolution::<T>.resolve(board, legal_moves)
+    }
+}
@@ -0,0 +1,19 @@
+use crate::board::{tests::run_tests};
+use super::Atomic;
+
+const TEST_STR: &str = r#"rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1 ;D1 20 ;D2 400 ;D3 8902 ;D4 197326 ;D5 4864979
+rnbqkb1r/pp1p1ppp/2p5/4P3/2B5/8/PPP1NnPP/RNBQK2R w KQkq - 0 6 ;D1 41 ;D2 1318 ;D3 50067 ;D4 1646711
+r4b1r/2kb1N2/p2Bpnp1/8/2Pp3p/1P1PPP2/P5PP/R3K2R b KQ - 0 1 ;D1 4 ;D2 148 ;D3 4462 ;D4 155172
+rn2kb1r/1pp1p2p/p2q1pp1/3P4/2P3b1/4PN2/PP3PPP/R2QKB1R b KQkq - 0 1 ;D1 40 ;D2 1238 ;D3 45237 ;D4 1434825
+rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3 ;D1 31 ;D2 705 ;D3 21511 ;D4 521584
+r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1 ;D1 26 ;D2 593 ;D3 14295 ;D4 322445 ;D5 7681576
+r3k2r/8/8/8/8/8/3q4/R3K2R w KQkq - 0 1 ;D1 1 ;D2 49 ;D3 756 ;D4 32724 ;D5 554217
+8/8/8/8/8/3k4/8/R3K2R w KQ - 0 1 ;D1 26 ;D2 189 ;D3 5259 ;D4 32796 ;D5 956860
+8/8/8/3k4/4K3/8/8/8 w - - 0 1 ;D1 7 ;D2 54 ;D3 413 ;D4 3212 ;D5 25042
+4k3/8/8/8/8/8/3pK3/8 w - - 0 1 ;D1 6 ;D2 50 ;D3 278 ;D4 3477 ;D5 20653"#;
+
+#[test]
+fn atomic_perft_suite() {
+    run_tests("Atomic", Atomic::create(), TEST_STR);
+}
+
@@ -0,0 +1,124 @@
+use crate::board::{
+    actions::{HistoryMove, Move, PreviousReserve},
+    game::{Game, GameResults},
+    Board,
+};
+
+use super::get_captured_pieces;
+
+/// The names of the two boards, as used in BPGN (eg. `A:e2e4` for a move on the first board.)
+pub const BOARD_NAMES: [char; 2] = ['A', 'B'];
+
+/// Gets the board and team of the partner of `team` on `board`.
+/// Partners sit on different boards and play opposite colors, so white on one board is partnered with black on the other.
+pub fn get_partner(board: usize, team: u16) -> (usize, u16) {
+    (1 - board, 1 - team)
+}
+
+/// A move made on one of the boards of a bughouse game, which can be undone with `BughouseBoards::undo_move`.
+#[derive(Debug)]
+pub struct BughouseHistoryMove {
+    pub board: usize,
+    pub history_move: Option<HistoryMove<1>>,
+    /// The reserves of the partner on the other board before any captured pieces were handed over.
+    pub transfers: Vec<PreviousReserve>,
+}
+
+/// The two boards of a bughouse game, which share captured pieces between partners.
+/// Each board is played on its own (see `Bughouse`), so moves, legal move generation and FENs all name the board they're for.
+pub struct BughouseBoards<'a> {
+    pub boards: [Board<'a, 1>; 2],
+}
+
+impl<'a> BughouseBoards<'a> {
+    pub fn new(game: &'a Game<1>) -> BughouseBoards<'a> {
+        BughouseBoards {
+            boards: [game.default(), game.default()],
+        }
+    }
+
+    /// Decodes both boards from a bughouse FEN (bfen), which is the FENs of both boards separated by `|`.
+    pub fn from_fen(game: &'a Game<1>, fen: &str) -> BughouseBoards<'a> {
+        let fens = fen.split('|').map(|fen| fen.trim()).collect::<Vec<_>>();
+        if fens.len() != 2 {
+            panic!("'{fen}' should have the FENs of both boards, separated by '|'.");
+        }
+
+        BughouseBoards {
+            boards: [game.from_fen(fens[0]), game.from_fen(fens[1])],
+        }
+    }
+
+    pub fn to_fen(&self) -> String {
+        format!("{} | {}", self.boards[0].to_fen(), self.boards[1].to_fen())
+    }
+
+    pub fn generate_legal_moves(&mut self, board: usize, mode: u16) -> Vec<Move> {
+        self.boards[board].generate_legal_moves(mode)
+    }
+
+    /// Makes a move on `board`, handing any pieces it captures over to the partner's hand on the other board.
+    pub fn make_move(&mut self, board: usize, action: &Move) -> BughouseHistoryMove {
+        let history_move = self.boards[board].make_move(action);
+
+        let mut transfers = vec![];
+        if let (Some(history_move), Move::Action(action)) = (&history_move, action) {
+            let (partner_board, partner) = get_partner(board, action.team);
+            let reserves = &mut self.boards[partner_board].state.reserves[partner as usize];
+            for piece_type in get_captured_pieces(history_move) {
+                let piece_type = piece_type as usize;
+                transfers.push(PreviousReserve(partner as usize, piece_type, reserves[piece_type]));
+                reserves[piece_type] += 1;
+            }
+        }
+
+        BughouseHistoryMove {
+            board,
+            history_move,
+            transfers,
+        }
+    }
+
+    pub fn undo_move(&mut self, undo: BughouseHistoryMove) {
+        let other_board = &mut self.boards[1 - undo.board];
+        for transfer in undo.transfers.iter().rev() {
+            other_board.state.reserves[transfer.0][transfer.1] = transfer.2;
+        }
+
+        self.boards[undo.board].undo_move(undo.history_move);
+    }
+
+    /// Encodes a move on `board`, prefixed by the board's name (eg. `A:e2e4` or `B:P@f7`.)
+    pub fn encode_action(&self, board: usize, action: &Move) -> String {
+        format!("{}:{}", BOARD_NAMES[board], self.boards[board].encode_action(action))
+    }
+
+    /// Decodes a move prefixed by the name of the board it's on, giving the board and the move.
+    pub fn decode_action(&mut self, action: &str, mode: u16) -> Option<(usize, Move)> {
+        let (name, action) = action.split_once(':')?;
+        let name = name.chars().next()?.to_ascii_uppercase();
+        let board = BOARD_NAMES.iter().position(|board_name| *board_name == name)?;
+
+        let action = self.boards[board].decode_action(action, mode)?;
+        Some((board, action))
+    }
+
+    /// The game is over as soon as either board is over. Partnerships are numbered by their team on the first board,
+    /// so `Win(0)` is a win for white on the first board and black on the second.
+    pub fn resolve(&mut self) -> GameResults {
+        for board in 0..2 {
+            let game = self.boards[board].game;
+            let legal_moves = self.boards[board].generate_legal_moves(0);
+            match game.resolution.resolve(&mut self.boards[board], &legal_moves) {
+                GameResults::Win(team) => {
+                    let partnership = if board == 0 { team } else { get_partner(board, team).1 };
+                    return GameResults::Win(partnership);
+                }
+                GameResults::Draw => return GameResults::Draw,
+                GameResults::Ongoing => {}
+            }
+        }
+
+        GameResults::Ongoing
+    }
+}
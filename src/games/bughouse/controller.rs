@@ -0,0 +1,78 @@
+use crate::{
+    board::{
+        actions::{Action, HistoryMove, HistoryState, HistoryUpdate, Move, TheoreticalMove, TurnUpdate},
+        game::{Game, MoveController},
+        Board, BoardState, PieceType,
+    },
+    games::crazyhouse::CrazyhouseMoveController,
+};
+
+/// Gets the types of the pieces captured by a move made with `BughouseMoveController`, which go to the partner's hand on the other board.
+/// Promoted pieces are demoted back to pawns, just like in Crazyhouse.
+pub fn get_captured_pieces<const T: usize>(history_move: &HistoryMove<T>) -> Vec<PieceType> {
+    // Drops also update the reserves, but they can't capture anything.
+    if let Move::Action(Action { from: None, .. }) = history_move.action {
+        return vec![];
+    }
+
+    match &history_move.state {
+        HistoryState::Any { updates, .. } => updates
+            .iter()
+            .filter_map(|update| match update {
+                HistoryUpdate::Reserve(reserve) => Some(reserve.1 as PieceType),
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// Each board of a bughouse game is played with the rules of Crazyhouse, except captured pieces don't stay on the same board.
+/// Moves still record the captured pieces (see `get_captured_pieces`), so `BughouseBoards` can hand them over to the partner.
+#[derive(Debug)]
+pub struct BughouseMoveController;
+
+impl<const T: usize> MoveController<T> for BughouseMoveController {
+    fn transform_moves(&self, board: &mut Board<T>, mode: u16, actions: Vec<Move>) -> Vec<Move> {
+        CrazyhouseMoveController.transform_moves(board, mode, actions)
+    }
+
+    fn is_legal(&self, board: &mut Board<T>, action: &Move) -> bool {
+        CrazyhouseMoveController.is_legal(board, action)
+    }
+
+    fn use_pseudolegal(&self) -> bool {
+        true
+    }
+
+    fn make_move(&self, board: &mut Board<T>, action: &Action) -> Option<HistoryMove<T>> {
+        let team = action.team as usize;
+        let reserves = board.state.reserves[team].clone();
+
+        // The history move has already saved the reserves from before the capture, so they're restored on undo either way.
+        let history_move = CrazyhouseMoveController.make_move(board, action)?;
+        board.state.reserves[team] = reserves;
+
+        Some(history_move)
+    }
+
+    fn make_drop_move(&self, board: &mut Board<T>, action: &Action) -> Option<HistoryMove<T>> {
+        CrazyhouseMoveController.make_drop_move(board, action)
+    }
+
+    fn encode_action(&self, board: &Board<T>, action: &Move) -> Vec<String> {
+        CrazyhouseMoveController.encode_action(board, action)
+    }
+
+    fn update(&self, action: &Move, state: &BoardState<T>, game: &Game<T>) -> TurnUpdate {
+        CrazyhouseMoveController.update(action, state, game)
+    }
+
+    fn get_theoretical_moves(&self, board: &Board<T>) -> Vec<TheoreticalMove> {
+        CrazyhouseMoveController.get_theoretical_moves(board)
+    }
+
+    fn get_max_available_moves(&self) -> u32 {
+        MoveController::<T>::get_max_available_moves(&CrazyhouseMoveController)
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{board::game::Game, games::crazyhouse::Crazyhouse};
+
+use super::BughouseMoveController;
+
+pub struct Bughouse;
+
+impl Bughouse {
+    /// Creates the game each board of a bughouse game is played with, which is Crazyhouse except captured pieces leave the board.
+    /// Both boards are played together with `BughouseBoards`, which hands captured pieces over to the capturer's partner.
+    pub fn create() -> Game<1> {
+        let mut game = Crazyhouse::create();
+        game.name = String::from("Bughouse");
+        game.controller = Box::new(BughouseMoveController);
+        game
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::game::{GameResults, NORMAL_MODE};
+
+    use super::{super::BughouseBoards, Bughouse};
+
+    #[test]
+    fn fen() {
+        let bughouse = Bughouse::create();
+        let boards = BughouseBoards::new(&bughouse);
+        assert_eq!(
+            boards.to_fen(),
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR[] w KQkq - 0 1 | rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR[] w KQkq - 0 1"
+        );
+
+        let fen = "r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R[P] w KQkq - 2 3 | rnbqkbnr/ppp2ppp/8/3p4/8/8/PPPP1PPP/RNBQKBNR[n] b KQkq - 0 2";
+        assert_eq!(BughouseBoards::from_fen(&bughouse, fen).to_fen(), fen);
+    }
+
+    #[test]
+    fn captures_go_to_partner() {
+        let bughouse = Bughouse::create();
+        let mut boards = BughouseBoards::from_fen(
+            &bughouse,
+            "rnbqkbnr/ppp1pppp/8/3p4/4P3/8/PPPP1PPP/RNBQKBNR[] w KQkq - 0 2 | rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR[] w KQkq - 0 1",
+        );
+        let fen = boards.to_fen();
+
+        // White captures on the first board, so the pawn goes to black's hand on the second board.
+        let (board, action) = boards.decode_action("A:e4d5", NORMAL_MODE).expect("exd5 should be legal on the first board.");
+        assert_eq!(boards.encode_action(board, &action), "A:e4d5");
+        let undo = boards.make_move(board, &action);
+        assert_eq!(
+            boards.to_fen(),
+            "rnbqkbnr/ppp1pppp/8/3P4/8/8/PPPP1PPP/RNBQKBNR[] b KQkq - 0 2 | rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR[p] w KQkq - 0 1"
+        );
+
+        // The partner has to wait for their turn on their own board to drop it.
+        assert!(boards.decode_action("B:P@e4", NORMAL_MODE).is_none());
+        let (board, action) = boards.decode_action("B:e2e4", NORMAL_MODE).expect("e4 should be legal on the second board.");
+        let second_undo = boards.make_move(board, &action);
+        let (board, action) = boards.decode_action("b:P@e5", NORMAL_MODE).expect("Black should be able to drop its pawn.");
+        assert_eq!(boards.encode_action(board, &action), "B:P@e5");
+        let third_undo = boards.make_move(board, &action);
+        assert_eq!(
+            boards.to_fen(),
+            "rnbqkbnr/ppp1pppp/8/3P4/8/8/PPPP1PPP/RNBQKBNR[] b KQkq - 0 2 | rnbqkbnr/pppppppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR[] w KQkq - 0 2"
+        );
+
+        boards.undo_move(third_undo);
+        boards.undo_move(second_undo);
+        boards.undo_move(undo);
+        assert_eq!(boards.to_fen(), fen);
+    }
+
+    #[test]
+    fn checkmate_ends_both_boards() {
+        let bughouse = Bughouse::create();
+        let mut boards = BughouseBoards::from_fen(
+            &bughouse,
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR[] w KQkq - 0 1 | rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR[] w KQkq - 1 3",
+        );
+
+        // White is checkmated on the second board, which is a win for white on the first board and black on the second.
+        assert!(boards.generate_legal_moves(1, NORMAL_MODE).is_empty());
+        assert!(matches!(boards.resolve(), GameResults::Win(0)));
+    }
+}
@@ -0,0 +1,8 @@
+mod game;
+mod controller;
+mod boards;
+mod suite;
+
+pub use game::*;
+pub use controller::*;
+pub use boards::*;
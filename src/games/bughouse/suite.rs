@@ -0,0 +1,12 @@
+use crate::board::{tests::run_tests};
+use super::Bughouse;
+
+const TEST_STR: &str = r#"rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR[] w KQkq - 0 1 ;D1 20 ;D2 400 ;D3 8902 ;D4 197281
+r1bqk2r/pppp1ppp/2n2n2/2b1p3/2B1P3/5N2/PPPP1PPP/RNBQK2R[Nbp] w KQkq - 4 4 ;D1 65 ;D2 5910 ;D3 292732
+4k3/8/8/8/8/8/8/4K3[QRBNPqrbnp] w - - 0 1 ;D1 301 ;D2 75840
+r3k2r/8/8/8/8/8/8/R3K2R[Pp] w KQkq - 0 1 ;D1 74 ;D2 5015 ;D3 196792"#;
+
+#[test]
+fn bughouse_perft_suite() {
+    run_tests("Bughouse", Bughouse::create(), TEST_STR);
+}
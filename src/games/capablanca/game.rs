@@ -0,0 +1,86 @@
+use crate::{
+    board::{game::Game, zobrist::ZobristHashTable},
+    games::chess::Chess,
+};
+
+use super::pieces::get_capablanca_pieces;
+
+pub struct Capablanca;
+
+impl Capablanca {
+    /// Capablanca chess is played with the rules of chess on a 10x8 board, with an archbishop and chancellor for each team.
+    /// Pawns can also promote to either of them, and castling moves the king three squares.
+    pub fn create() -> Game<1> {
+        let mut game = Chess::create();
+        game.name = String::from("Capablanca");
+        game.cols = 10;
+        game.squares = 80;
+
+        let pieces = get_capablanca_pieces();
+        game.pieces.push(&pieces.archbishop);
+        game.pieces.push(&pieces.chancellor);
+        game.zobrist = ZobristHashTable::<1>::generate(80, 2, 8, 81, || fastrand::u64(0..u64::MAX));
+
+        game.fen_options.default_fen = String::from("rnabqkbcnr/pppppppppp/10/10/10/10/PPPPPPPPPP/RNABQKBCNR w KQkq - 0 1");
+        game
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{board::{fen::FenArgument, game::NORMAL_MODE}, games::chess::ChessCastlingRights};
+
+    use super::Capablanca;
+
+    fn encode_moves(fen: &str) -> Vec<String> {
+        let capablanca = Capablanca::create();
+        let mut board = capablanca.from_fen(fen);
+        board
+            .generate_legal_moves(NORMAL_MODE)
+            .iter()
+            .map(|action| board.encode_action(action))
+            .collect()
+    }
+
+    #[test]
+    fn fen() {
+        let capablanca = Capablanca::create();
+        for fen in [
+            "rnabqkbcnr/pppppppppp/10/10/10/10/PPPPPPPPPP/RNABQKBCNR w KQkq - 0 1",
+            "r3k4r/1p3a2p1/p1cp1p1n2/2p1P3Pb/10/1PN1A1B3/P1P2PP2P/R3K1C2R w KQkq - 0 1",
+        ] {
+            assert_eq!(capablanca.from_fen(fen).to_fen(), fen);
+        }
+
+        // Castling rights can use any of the board's ten files.
+        let mut board = capablanca.from_fen("r4k3r/10/10/10/10/10/10/R4K3R w - - 0 1");
+        ChessCastlingRights::Shredder.decode(&mut board, "JAja").expect("JAja should be valid castling rights.");
+        assert_eq!(ChessCastlingRights::Shredder.encode(&board), "JAja");
+        assert_eq!(ChessCastlingRights::XFen.encode(&board), "KQkq");
+    }
+
+    #[test]
+    fn castling() {
+        let capablanca = Capablanca::create();
+        let mut board = capablanca.from_fen("r4k3r/10/10/10/10/10/10/R4K3R w KQkq - 0 1");
+
+        // The king moves three squares when castling on a 10-wide board.
+        let action = board.decode_action("f1i1", NORMAL_MODE).expect("Kingside castling should be legal.");
+        assert_eq!(board.encode_action(&action), "f1j1");
+        board.make_move(&action);
+        assert_eq!(board.to_fen(), "r4k3r/10/10/10/10/10/10/R6RK1 b kq - 1 1");
+
+        let action = board.decode_action("f8c8", NORMAL_MODE).expect("Queenside castling should be legal.");
+        board.make_move(&action);
+        assert_eq!(board.to_fen(), "2kr5r/10/10/10/10/10/10/R6RK1 w - - 2 2");
+    }
+
+    #[test]
+    fn promotion() {
+        let moves = encode_moves("4k5/1P8/10/10/10/10/10/4K5 w - - 0 1");
+        for promotion in ["b7b8n", "b7b8b", "b7b8r", "b7b8q", "b7b8a", "b7b8c"] {
+            assert!(moves.contains(&promotion.to_string()));
+        }
+        assert!(!moves.contains(&"b7b8k".to_string()));
+    }
+}
@@ -0,0 +1,15 @@
+use crate::board::game::Game;
+
+use super::Capablanca;
+
+pub struct Gothic;
+
+impl Gothic {
+    /// Gothic chess is Capablanca chess with a different starting position, where every pawn is defended.
+    pub fn create() -> Game<1> {
+        let mut game = Capablanca::create();
+        game.name = String::from("Gothic");
+        game.fen_options.default_fen = String::from("rnbqckabnr/pppppppppp/10/10/10/10/PPPPPPPPPP/RNBQCKABNR w KQkq - 0 1");
+        game
+    }
+}
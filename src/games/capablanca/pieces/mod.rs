@@ -0,0 +1,23 @@
+use std::sync::OnceLock;
+
+use crate::board::{pieces::BetzaPiece, PieceType};
+
+/// Capablanca chess adds its pieces after chess's pieces, so the chess piece types (eg. `KING_PIECE_TYPE`) stay the same.
+pub const ARCHBISHOP_PIECE_TYPE: PieceType = 6;
+pub const CHANCELLOR_PIECE_TYPE: PieceType = 7;
+
+pub struct CapablancaPieces {
+    /// The archbishop moves as a bishop or a knight.
+    pub archbishop: BetzaPiece,
+    /// The chancellor moves as a rook or a knight.
+    pub chancellor: BetzaPiece,
+}
+
+/// Gets the pieces Capablanca chess adds to chess.
+pub fn get_capablanca_pieces() -> &'static CapablancaPieces {
+    static PIECES: OnceLock<CapablancaPieces> = OnceLock::new();
+    PIECES.get_or_init(|| CapablancaPieces {
+        archbishop: BetzaPiece::parse('a', "BN").expect("The archbishop should have valid Betza notation."),
+        chancellor: BetzaPiece::parse('c', "RN").expect("The chancellor should have valid Betza notation."),
+    })
+}
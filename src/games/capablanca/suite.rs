@@ -0,0 +1,15 @@
+use crate::board::{tests::run_tests};
+use super::Capablanca;
+
+const TEST_STR: &str = r#"rnabqkbcnr/pppppppppp/10/10/10/10/PPPPPPPPPP/RNABQKBCNR w KQkq - 0 1 ;D1 28 ;D2 784 ;D3 25228 ;D4 805128
+rnbqckabnr/pppppppppp/10/10/10/10/PPPPPPPPPP/RNBQCKABNR w KQkq - 0 1 ;D1 28 ;D2 784 ;D3 25283 ;D4 808984
+r4k3r/pppppppppp/10/10/10/10/PPPPPPPPPP/R4K3R w KQkq - 0 1 ;D1 31 ;D2 961 ;D3 29210
+r3k4r/1p3a2p1/p1cp1p1n2/2p1P3Pb/10/1PN1A1B3/P1P2PP2P/R3K1C2R w KQkq - 0 1 ;D1 53 ;D2 3195 ;D3 165154
+4k5/1P6p1/10/3pP5/10/10/6p3/4K5 w - d6 0 1 ;D1 12 ;D2 128 ;D3 1339 ;D4 16870
+r4k2br/10/10/2b7/10/10/10/R4K3R w KQkq - 0 1 ;D1 25 ;D2 941 ;D3 23352 ;D4 884399
+1r2k4r/P8P/10/10/10/10/p8p/R3K4R b KQk - 0 1 ;D1 20 ;D2 478 ;D3 11192 ;D4 300402"#;
+
+#[test]
+fn capablanca_perft_suite() {
+    run_tests("Capablanca", Capablanca::create(), TEST_STR);
+}
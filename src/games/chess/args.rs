@@ -13,112 +13,124 @@ use crate::{
     },
 };
 
-use super::pieces::{down, up};
-
+use super::pieces::{down, get_back_rank, get_en_passant_steps, up, KING_PIECE_TYPE, PAWN_PIECE_TYPE, ROOK_PIECE_TYPE};
+
+
+/// How castling rights are written in a FEN.
+/// Both notations can always be decoded; this only changes how they're encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChessCastlingRights {
+    /// `KQkq`, using the rook's file (eg. `Gkq`) only when the castling rook isn't the outermost rook on its side (X-FEN.)
+    /// This is identical to standard FEN for normal chess positions.
+    XFen,
+    /// The rook's file for every castling right, eg. `HAha` (Shredder-FEN.)
+    Shredder,
+}
 
-#[derive(Debug)]
-pub struct ChessCastlingRights;
 impl<const T: usize> FenArgument<T> for ChessCastlingRights {
     fn decode(&self, board: &mut Board<T>, arg: &str) -> Result<(), FenDecodeError> {
+        // Only rooks with castling rights keep their first move.
+        board.state.first_move &= !board.state.pieces[ROOK_PIECE_TYPE as usize];
         if arg == "-" {
-            board.state.first_move &= !board.state.pieces[3];
-            Ok(())
-        } else {
-            let mut lost_castling_rights = vec!['Q', 'K', 'q', 'k'];
-            let initial_lost_castling_rights = ['Q', 'K', 'q', 'k'];
-
-            for char in arg.chars() {
-                if !lost_castling_rights.contains(&char) {
-                    if initial_lost_castling_rights.contains(&char) {
-                        return Err(FenDecodeError::InvalidArgument(format!(
-                            "The castling rights of '{char}' have already been specified."
-                        )));
-                    }
-
-                    return Err(FenDecodeError::InvalidArgument(format!(
-                        "'{char}' is not a valid castling rights character."
-                    )));
-                }
+            return Ok(());
+        }
 
-                lost_castling_rights.retain(|el| el != &char);
+        let cols = board.state.cols;
+        let mut castling_rights: Vec<char> = Vec::with_capacity(4);
+        for char in arg.chars() {
+            if castling_rights.contains(&char) {
+                return Err(FenDecodeError::InvalidArgument(format!(
+                    "The castling rights of '{char}' have already been specified."
+                )));
             }
+            castling_rights.push(char);
 
-            for char in lost_castling_rights {
-                let (team, scan_dir) = match char {
-                    'Q' => (0, Direction::LEFT),
-                    'K' => (0, Direction::RIGHT),
-                    'q' => (1, Direction::LEFT),
-                    'k' => (1, Direction::RIGHT),
-                    _ => {
-                        return Err(FenDecodeError::InvalidArgument(format!(
-                            "'{char}' is not a valid castling rights character."
-                        )));
-                    }
-                };
+            let team: usize = if char.is_ascii_uppercase() { 0 } else { 1 };
+            let back_rank = get_back_rank(board, team as u16);
+            let rooks = board.state.pieces[ROOK_PIECE_TYPE as usize] & board.state.teams[team] & back_rank;
+            let king_board = board.state.pieces[KING_PIECE_TYPE as usize] & board.state.teams[team] & back_rank;
 
-                let rook_board = board.state.pieces[3] & board.state.teams[team];
-                if rook_board.is_empty() {
-                    continue;
-                }
-
-                let rook = rook_board.bitscan(scan_dir);
-                let rook_board = BitBoard::from_lsb(rook);
-
-                let king_board = board.state.pieces[5] & board.state.teams[team];
-                let king = king_board.bitscan_forward();
+            let rook = match char.to_ascii_lowercase() {
+                'k' | 'q' => {
+                    if king_board.is_empty() {
+                        continue;
+                    }
 
-                if (rook < king && scan_dir == Direction::LEFT) || (rook > king && scan_dir == Direction::RIGHT) {
-                    board.state.first_move &= !rook_board;
+                    let left_of_king = king_board - BitBoard::from_element(1);
+                    let (scan_dir, side) = if char.eq_ignore_ascii_case(&'k') {
+                        (Direction::RIGHT, rooks & !left_of_king & !king_board)
+                    } else {
+                        (Direction::LEFT, rooks & left_of_king)
+                    };
 
+                    if side.is_empty() {
+                        continue;
+                    }
+                    side.bitscan(scan_dir)
                 }
-            }
-            Ok(())
+                file @ 'a'..='z' if ((file as u16) - ('a' as u16)) < cols => {
+                    let file = board.state.files[((file as u16) - ('a' as u16)) as usize];
+                    let rook = rooks & file;
+                    if rook.is_empty() {
+                        continue;
+                    }
+                    rook.bitscan_forward()
+                }
+                _ => {
+                    return Err(FenDecodeError::InvalidArgument(format!(
+                        "'{char}' is not a valid castling rights character."
+                    )));
+                }
+            };
+
+            board.state.first_move |= BitBoard::from_lsb(rook);
         }
+
+        Ok(())
     }
 
     fn encode(&self, board: &Board<T>) -> String {
         let mut castling_rights: Vec<char> = Vec::with_capacity(4);
         for team in 0..board.state.teams.len() {
-            let king = board.state.pieces[5] & board.state.teams[team] & board.state.first_move;
-            if king.is_empty() {
+            let back_rank = get_back_rank(board, team as u16);
+            let king_board = board.state.pieces[KING_PIECE_TYPE as usize] & board.state.teams[team] & back_rank & board.state.first_move;
+            if king_board.is_empty() {
                 continue;
             }
+            let king = king_board.bitscan_forward();
+
+            let all_rooks = board.state.pieces[ROOK_PIECE_TYPE as usize] & board.state.teams[team] & back_rank;
+            let rooks = all_rooks & board.state.first_move;
+
+            // The rightmost rooks (kingside) are listed first, as in `KQkq`.
+            let mut one_bits = rooks.iter_set_bits(board.state.squares).collect::<Vec<_>>();
+            one_bits.reverse();
+            for rook in one_bits {
+                let outermost = if rook > king {
+                    all_rooks.bitscan_reverse()
+                } else {
+                    all_rooks.bitscan_forward()
+                };
 
-            let rooks = board.state.pieces[3] & board.state.teams[team] & board.state.first_move;
-            let mut one_bits = rooks
-                .iter_set_bits(board.state.rows * board.state.cols)
-                .collect::<Vec<_>>();
-            if one_bits.len() == 1 {
-                let mut side_castling_rights = if rooks > king { 'k' } else { 'q' };
-
-                if team == 0 {
-                    side_castling_rights = side_castling_rights.to_ascii_uppercase();
-                }
-
-                castling_rights.push(side_castling_rights);
-            } else if one_bits.len() > 1 {
-                one_bits.reverse();
-                for bit in one_bits {
-                    let bit = BitBoard::from_lsb(bit);
-                    let mut side_castling_rights = if bit > king { 'k' } else { 'q' };
-
-                    if team == 0 {
-                        side_castling_rights = side_castling_rights.to_ascii_uppercase();
+                let side_castling_rights = match self {
+                    ChessCastlingRights::XFen if rook == outermost => {
+                        if rook > king { 'k' } else { 'q' }
                     }
+                    _ => (b'a' + (rook % board.state.cols) as u8) as char,
+                };
 
-                    castling_rights.push(side_castling_rights);
-                }
+                castling_rights.push(if team == 0 {
+                    side_castling_rights.to_ascii_uppercase()
+                } else {
+                    side_castling_rights
+                });
             }
         }
 
         if castling_rights.len() == 0 {
             String::from("-")
         } else {
-            castling_rights
-                .iter()
-                .map(|el| format!("{}", el))
-                .collect::<Vec<_>>()
-                .join("")
+            castling_rights.iter().collect::<String>()
         }
     }
 }
@@ -140,57 +152,50 @@ impl<const T: usize> FenArgument<T> for ChessEnPassant {
             ))
         })?;
 
-        let cols = board.state.cols;
+        // The pawn may have stepped over more than one square (eg. in Omega chess), so it's the first piece in front of the target.
+        let target = BitBoard::from_lsb(en_passant_target);
+        let from = down(board, &target, 1, previous_team);
+        let mut to = up(board, &target, 1, previous_team);
+        while to.is_set() && (to & board.state.all_pieces).is_empty() {
+            to = up(board, &to, 1, previous_team);
+        }
 
-        let to = up::<T>(&BitBoard::from_lsb(en_passant_target), 1, cols, 1);
-        let from = down::<T>(&to, 2, cols, previous_team);
+        if (to & board.state.get_piece_team_board(PAWN_PIECE_TYPE as usize, previous_team as usize)).is_empty() {
+            return Err(FenDecodeError::InvalidArgument(format!(
+                "'{arg}' is not a valid en passant position, as there's no pawn in front of it."
+            )));
+        }
 
         let action = Action {
             from: Some(from.bitscan_forward()),
             to: to.bitscan_forward(),
             team: previous_team,
-            piece_type: 0,
+            piece_type: PAWN_PIECE_TYPE,
             info: 0,
             move_type: 0
         };
 
         board.history.push_back(Move::Action(action));
+        // Any other turns the previous team took after their double step are unknown.
+        for _ in 1..board.game.turns[previous_team as usize] {
+            board.history.push_back(Move::Pass).expect("The history should have space for every turn of a sub-move.");
+        }
 
         Ok(())
     }
 
     fn encode(&self, board: &Board<T>) -> String {
-        let last_move = (&board.history).iter().last();
-        if let None = last_move {
-            return "-".to_string();
-        }
-
-        let last_move =
-            last_move.expect("The last move for exporting an en passant FEN must be Some.");
-
-        match last_move {
-            Move::Action(last_action) => {
-                if last_action.piece_type != 0 {
-                    return "-".to_string();
-                }
-
-                match last_action.from {
-                    Some(from) => {
-                        if from.abs_diff(last_action.to) != (2 * board.state.cols) {
-                            return "-".to_string();
-                        }
-    
-                        let to_bitboard = BitBoard::<1>::from_lsb(last_action.to);
-                        let en_passant = down(&to_bitboard, 1, board.state.cols, board.state.team_reverse_lookup[board.state.moving_team as usize]);
-
-                        return board.encode_position(en_passant.bitscan_forward());
-                    }
-                    None => "-".to_string()
-                }
-            }
-            Move::Pass => {
-                return "-".to_string();
+        // FENs can only store one en passant square, so the latest double step is used.
+        match get_en_passant_steps(board).last() {
+            Some(last_action) => {
+                // The en passant square is the first square the pawn stepped over.
+                let from = last_action.from.expect("En passant steps should have a from square.");
+                let from_bitboard = BitBoard::<T>::from_lsb(from);
+                let en_passant = up(board, &from_bitboard, 1, last_action.team);
+
+                board.encode_position(en_passant.bitscan_forward())
             }
+            None => "-".to_string()
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,111 @@
+use crate::board::{fen::FenArgument, game::Game};
+
+use super::{Chess, ChessCastlingRights};
+
+/// The placements of the two knights among the five squares left over after placing the bishops and queen, indexed by Scharnagl's `N5N` table.
+const KNIGHT_PLACEMENTS: [(usize, usize); 10] = [
+    (0, 1), (0, 2), (0, 3), (0, 4), (1, 2),
+    (1, 3), (1, 4), (2, 3), (2, 4), (3, 4),
+];
+
+pub struct Chess960;
+
+impl Chess960 {
+    /// Chess960 is played with the same rules as Chess, but castling rights are written with Shredder-FEN.
+    /// Positions using X-FEN or standard FEN castling rights can still be loaded.
+    pub fn create() -> Game<1> {
+        let mut game = Chess::create();
+        game.name = String::from("Chess960");
+
+        for (name, arg) in game.fen_options.args.iter_mut() {
+            if name == "castling rights" {
+                *arg = Box::new(ChessCastlingRights::Shredder) as Box<dyn FenArgument<1>>;
+            }
+        }
+
+        game.fen_options.default_fen = Chess960::get_start_fen(518);
+        game
+    }
+
+    /// Generates the back rank of the starting position with the given Scharnagl index (from `0` to `959`.)
+    /// Index `518` is the standard chess starting position.
+    pub fn get_back_rank(index: u16) -> String {
+        assert!(index < 960, "The Scharnagl index of a Chess960 position must be below 960, not {index}.");
+
+        let mut back_rank: [Option<char>; 8] = [None; 8];
+        let index = index as usize;
+
+        // Bishops go on opposite-colored squares.
+        back_rank[(2 * (index % 4)) + 1] = Some('b');
+        let index = index / 4;
+        back_rank[2 * (index % 4)] = Some('b');
+        let index = index / 4;
+
+        let mut empty = (0..8).filter(|&file| back_rank[file].is_none()).collect::<Vec<_>>();
+        back_rank[empty[index % 6]] = Some('q');
+        let index = index / 6;
+
+        empty = (0..8).filter(|&file| back_rank[file].is_none()).collect::<Vec<_>>();
+        let (first_knight, second_knight) = KNIGHT_PLACEMENTS[index];
+        back_rank[empty[first_knight]] = Some('n');
+        back_rank[empty[second_knight]] = Some('n');
+
+        // The king always ends up between the two rooks.
+        let empty = (0..8).filter(|&file| back_rank[file].is_none()).collect::<Vec<_>>();
+        for (file, piece) in empty.into_iter().zip(['r', 'k', 'r']) {
+            back_rank[file] = Some(piece);
+        }
+
+        back_rank.iter().map(|piece| piece.expect("Every square of the back rank must be filled.")).collect()
+    }
+
+    /// Generates the FEN of the starting position with the given Scharnagl index (from `0` to `959`.)
+    pub fn get_start_fen(index: u16) -> String {
+        let back_rank = Chess960::get_back_rank(index);
+        format!(
+            "{back_rank}/pppppppp/8/8/8/8/PPPPPPPP/{} w KQkq - 0 1",
+            back_rank.to_ascii_uppercase()
+        )
+    }
+
+    /// Generates the FEN of a random Chess960 starting position.
+    pub fn get_random_fen() -> String {
+        Chess960::get_start_fen(fastrand::u16(0..960))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{board::fen::FenArgument, games::chess::{Chess, ChessCastlingRights}};
+
+    use super::Chess960;
+
+    #[test]
+    fn scharnagl_index() {
+        assert_eq!(Chess960::get_back_rank(0), "bbqnnrkr");
+        assert_eq!(Chess960::get_back_rank(518), "rnbqkbnr");
+        assert_eq!(Chess960::get_back_rank(959), "rkrnnqbb");
+        assert_eq!(Chess960::create().default().to_fen(), "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w HAha - 0 1");
+    }
+
+    #[test]
+    fn castling_rights_notation() {
+        let chess = Chess::create();
+        let chess960 = Chess960::create();
+
+        // X-FEN and Shredder-FEN castling rights can be read by either game.
+        for fen in ["rk5r/8/8/8/8/8/8/RK5R w KQkq - 0 1", "rk5r/8/8/8/8/8/8/RK5R w HAha - 0 1"] {
+            assert_eq!(chess.from_fen(fen).to_fen(), "rk5r/8/8/8/8/8/8/RK5R w KQkq - 0 1");
+            assert_eq!(chess960.from_fen(fen).to_fen(), "rk5r/8/8/8/8/8/8/RK5R w HAha - 0 1");
+        }
+
+        // X-FEN uses the rook's file when castling with a rook that isn't the outermost one.
+        let fen = "4k3/8/8/8/8/8/8/1R1K2RR w Gq - 0 1";
+        assert_eq!(chess.from_fen(fen).to_fen(), "4k3/8/8/8/8/8/8/1R1K2RR w G - 0 1");
+        assert_eq!(chess.from_fen("4k3/8/8/8/8/8/8/1R1K2RR w KB - 0 1").to_fen(), "4k3/8/8/8/8/8/8/1R1K2RR w KQ - 0 1");
+
+        let mut board = chess.from_fen("4k3/8/8/8/8/8/8/R3K2R w KQ - 0 1");
+        assert!(ChessCastlingRights::XFen.decode(&mut board, "KK").is_err());
+        assert!(ChessCastlingRights::XFen.decode(&mut board, "KX").is_err());
+    }
+}
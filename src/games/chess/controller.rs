@@ -1,6 +1,11 @@
-use crate::{board::{game::{MoveController, get_theoretical_moves_bound}, Board, actions::{Action, TheoreticalAction, Move, TheoreticalMove, CounterUpdate, TurnUpdate}, BoardState}, bitboard::BitBoard};
+use crate::{board::{game::{Game, MoveController, get_theoretical_moves_bound}, Board, actions::{Action, TheoreticalAction, Move, TheoreticalMove, CounterUpdate, TurnUpdate}, BoardState}, bitboard::{BitBoard, Direction}};
 
-use super::{ATTACKS_MODE, pieces::CASTLING_MOVE};
+use super::{ATTACKS_MODE, pieces::{get_castling_squares, CASTLING_MOVE, KING_PIECE_TYPE, PAWN_PIECE_TYPE}};
+
+/// Finds a team that has had their king captured, for games where kings are captured instead of checkmated (eg. Duck chess.)
+pub fn get_kingless_team<const T: usize>(board: &Board<T>) -> Option<u16> {
+    (0..board.game.teams).find(|team| (board.state.pieces[KING_PIECE_TYPE as usize] & board.state.teams[*team as usize]).is_empty())
+}
 
 #[derive(Debug)]
 pub struct ChessMoveController<const T: usize>;
@@ -21,7 +26,7 @@ impl<const T: usize> MoveController<T> for ChessMoveController<T> {
         match action {
             Move::Action(action) => {
                 let to_board = BitBoard::from_lsb(action.to);
-                let kings = board.state.pieces[5];
+                let kings = board.state.pieces[KING_PIECE_TYPE as usize];
                 if (to_board & kings).is_set() {
                     return false;
                 }
@@ -29,9 +34,12 @@ impl<const T: usize> MoveController<T> for ChessMoveController<T> {
                 let current_team = board.state.moving_team;
 
                 let undo = board.make_move(&Move::Action(*action));
-                let kings = board.state.pieces[5];
+                let kings = board.state.pieces[KING_PIECE_TYPE as usize];
                 let king_board = board.state.teams[current_team as usize] & kings;
-                let in_check = board.can_move(board.state.moving_team, king_board, ATTACKS_MODE);
+                // Teams without a king (eg. the horde) can't be put in check.
+                // With more than two teams (eg. four-player chess), any of the other teams could be giving check.
+                let in_check = king_board.is_set() && (0..board.game.teams)
+                    .any(|team| team != current_team && board.can_move(team, king_board, ATTACKS_MODE));
                 board.undo_move(undo);
                 !in_check
             }
@@ -69,16 +77,19 @@ impl<const T: usize> MoveController<T> for ChessMoveController<T> {
         ];
 
         if let Move::Action(action) = action {
-            if action.piece_type == 5 && action.move_type == CASTLING_MOVE {
+            if action.piece_type == KING_PIECE_TYPE && action.move_type == CASTLING_MOVE {
                 if let Some(from) = action.from {
-                    let dir = ((action.to as i16) - (from as i16)).signum();
-                    let to = ((from as i16) + (2 * dir)) as u16;
-                    moves.push(format!(
-                        "{}{}{}",
-                        board.encode_position(from),
-                        board.encode_position(to),
-                        board.game.pieces[action.piece_type as usize].format_info(board, action.info)
-                    ))
+                    let dir = if from < action.to { Direction::RIGHT } else { Direction::LEFT };
+                    let (king_dest, _) = get_castling_squares(board, action.team, dir);
+                    // Castling can also be written as the king's move, as long as it can't be mistaken for a normal king move (eg. `e1g1`, or `f1i1` in Capablanca chess.)
+                    if from.abs_diff(king_dest) >= 2 {
+                        moves.push(format!(
+                            "{}{}{}",
+                            board.encode_position(from),
+                            board.encode_position(king_dest),
+                            board.game.pieces[action.piece_type as usize].format_info(board, action.info)
+                        ))
+                    }
                 }
             }
         }
@@ -86,13 +97,13 @@ impl<const T: usize> MoveController<T> for ChessMoveController<T> {
         moves
     }
 
-    fn update(&self, action: &Move, state: &BoardState<T>) -> TurnUpdate {
+    fn update(&self, action: &Move, state: &BoardState<T>, game: &Game<T>) -> TurnUpdate {
         TurnUpdate {
             turns: CounterUpdate::Next,
             sub_moves: match action {
                 Move::Action(action) => {
-                    let is_pawn_move = action.piece_type == 0;
-                    let is_capture = (BitBoard::<T>::from_lsb(action.to) & state.all_pieces).is_set() && !(action.piece_type == 5 && action.move_type == 1);
+                    let is_pawn_move = action.piece_type == PAWN_PIECE_TYPE;
+                    let is_capture = game.pieces[action.piece_type as usize].is_capture(state, action) && !(action.piece_type == KING_PIECE_TYPE && action.move_type == CASTLING_MOVE);
 
                     if is_pawn_move || is_capture {
                         CounterUpdate::To(0)
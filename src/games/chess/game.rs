@@ -17,9 +17,9 @@ use super::{pieces::{
     down, up, BishopPiece, KingPiece, KnightPiece, PawnPiece, QueenPiece, RookPiece,
 }, ChessMoveController, ChessPostProcess, ChessCastlingRights, ChessEnPassant, ChessResolution};
 
-pub const ATTACKS_MODE: u16 = 1;
+pub use crate::board::game::ATTACKS_MODE;
 
-const PAWN: &dyn Piece<1> = &PawnPiece;
+const PAWN: &dyn Piece<1> = &PawnPiece { king_promotion: false, first_move_steps: 2, promotion_pieces: None };
 const KNIGHT: &dyn Piece<1> = &KnightPiece;
 const BISHOP: &dyn Piece<1> = &BishopPiece;
 const ROOK: &dyn Piece<1> = &RookPiece;
@@ -32,11 +32,12 @@ impl Chess {
     pub fn create() -> Game<1> {
         Game {
             teams: 2,
-            turns: 1,
+            turns: vec![1, 1],
             rows: 8,
             cols: 8,
             squares: 64,
             saved_last_moves: 1,
+            regions: vec![],
             zobrist_controller: Box::new(DefaultZobristController),
             zobrist: ZobristHashTable::<1>::generate(64, 2, 6, 65, || fastrand::u64(0..u64::MAX)),
             name: String::from("Chess"),
@@ -44,13 +45,13 @@ impl Chess {
             controller: Box::new(ChessMoveController),
             resolution: Box::new(ChessResolution),
             fen_options: FenOptions {
-                state: FenState { first_moves: false, gaps: '-' },
+                state: FenState { first_moves: false, gaps: '-', reserves: false },
                 args: vec![
                     (
                         "team to move".to_string(),
                         Box::new(FenTeamArgument::Teams(vec!['w', 'b'])),
                     ),
-                    ("castling rights".to_string(), Box::new(ChessCastlingRights)),
+                    ("castling rights".to_string(), Box::new(ChessCastlingRights::XFen)),
                     ("en passant".to_string(), Box::new(ChessEnPassant)),
                     ("half moves".to_string(), Box::new(FenSubMoves)),
                     ("full moves".to_string(), Box::new(FenFullMoves)),
@@ -4,11 +4,14 @@ mod process;
 mod controller;
 mod resolution;
 mod zobrist;
+mod chess960;
 mod suite;
+mod suite960;
 pub mod pieces;
 
 pub use game::*;
 pub use args::*;
 pub use process::*;
 pub use controller::*;
-pub use resolution::*;
\ No newline at end of file
+pub use resolution::*;
+pub use chess960::*;
\ No newline at end of file
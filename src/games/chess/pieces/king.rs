@@ -4,60 +4,69 @@ use crate::{
         actions::{
             Action, HistoryMove, HistoryState, HistoryUpdate, IndexedPreviousBoard, PreviousBoard, Move, TurnInfo,
         },
-        edges::Edges,
-        pieces::{Piece, PieceSymbol},
-        AttackDirections, Board, Cols, PieceType,
+        pieces::{leap_by_offset, Offset, Piece, PieceSymbol},
+        AttackDirections, Board, PieceType,
     },
-    games::chess::game::ATTACKS_MODE,
 };
 
+use super::{get_back_rank, CASTLING_REGION, ROOK_PIECE_TYPE};
+
 const NORMAL_KING_MOVE: u16 = 0;
 pub const CASTLING_MOVE: u16 = 1;
 
-const ROOK_PIECE_TYPE: PieceType = 3;
-
 #[derive(Debug)] pub struct KingPiece<const T: usize>;
 
-fn right_one<const T: usize>(from: BitBoard<T>, edges: &Edges<T>) -> BitBoard<T> {
-    (from & !edges.right).right(1)
-}
-
-fn left_one<const T: usize>(from: BitBoard<T>, edges: &Edges<T>) -> BitBoard<T> {
-    (from & !edges.left).left(1)
-}
+pub const KING_OFFSETS: [Offset; 8] = [
+    (0, -1), (0, 1), (1, 0), (-1, 0),
+    (1, -1), (-1, -1), (1, 1), (-1, 1),
+];
+
+/// Gets the squares the king and rook of `team` end up on after castling towards `dir`, as `(king, rook)`.
+///
+/// FRC Castling brings us to the same positions that traditional chess castling would, which we define from the edges of the board:
+/// the king ends up on the third file from the edge it's castling towards, with the rook right beside it (on the king's other side.)
+/// On an 8-wide board this is `c1`/`d1` and `g1`/`f1`, and on a 10-wide board (eg. Capablanca chess) it's `c1`/`d1` and `i1`/`h1`, so the king moves three squares.
+///
+/// Games where the king castles elsewhere list its two destinations in their `CASTLING_REGION`.
+pub fn get_castling_squares<const T: usize>(board: &Board<T>, team: u16, dir: Direction) -> (u16, u16) {
+    if let Some(region) = board.game.get_region(CASTLING_REGION) {
+        let destinations = region.get(team);
+        return match dir {
+            Direction::LEFT => {
+                let king = destinations.bitscan_forward();
+                (king, king + 1)
+            }
+            Direction::RIGHT => {
+                let king = destinations.bitscan_reverse();
+                (king, king - 1)
+            }
+        };
+    }
 
-fn up_one<const T: usize>(from: BitBoard<T>, cols: Cols, edges: &Edges<T>) -> BitBoard<T> {
-    (from & !edges.top).up(1, cols)
-}
+    let cols = board.state.cols;
+    let back_rank = match team {
+        0 => (board.state.rows - 1) * cols,
+        _ => 0,
+    };
 
-fn down_one<const T: usize>(from: BitBoard<T>, cols: Cols, edges: &Edges<T>) -> BitBoard<T> {
-    (from & !edges.bottom).down(1, cols)
+    match dir {
+        Direction::LEFT => (back_rank + 2, back_rank + 3),
+        Direction::RIGHT => (back_rank + cols - 2, back_rank + cols - 3),
+    }
 }
 
 impl<const T: usize> KingPiece<T> {
     fn make_castling_move(&self, board: &mut Board<T>, action: &Action, from: BitBoard<T>, to: BitBoard<T>, turn_info: TurnInfo) -> Option<HistoryMove<T>> {
-        let cols = board.state.cols;
-        let mut left_center = BitBoard::from_lsb(if cols % 2 == 0 {
-            (cols / 2) - 1
-        } else {
-            cols / 2
-        });
-
-        if action.team == 0 {
-            left_center = left_center.down(7, cols);
-        }
-
-        let castle_left_king = left_center.left(1);
-        let castle_left_rook = left_center;
-        let castle_right_king = left_center.right(3);
-        let castle_right_rook = left_center.right(2);
-
         let dir = if from.bitscan_forward() < action.to {
             Direction::RIGHT
         } else {
             Direction::LEFT
         };
 
+        let (king_dest, rook_dest) = get_castling_squares(board, action.team, dir);
+        let king_dest = BitBoard::from_lsb(king_dest);
+        let rook_dest = BitBoard::from_lsb(rook_dest);
+
         let color: usize = action.team as usize;
         let piece_type = action.piece_type as usize;
 
@@ -91,28 +100,14 @@ impl<const T: usize> KingPiece<T> {
         board.state.all_pieces ^= from;
         board.state.all_pieces ^= to;
 
-        match dir {
-            Direction::LEFT => {
-                board.state.pieces[piece_type] |= castle_left_king;
-                board.state.pieces[ROOK_PIECE_TYPE as usize] |= castle_left_rook;
+        board.state.pieces[piece_type] |= king_dest;
+        board.state.pieces[ROOK_PIECE_TYPE as usize] |= rook_dest;
 
-                board.state.teams[color] |= castle_left_king;
-                board.state.teams[color] |= castle_left_rook;
+        board.state.teams[color] |= king_dest;
+        board.state.teams[color] |= rook_dest;
 
-                board.state.all_pieces |= castle_left_king;
-                board.state.all_pieces |= castle_left_rook;
-            }
-            Direction::RIGHT => {
-                board.state.pieces[piece_type] |= castle_right_king;
-                board.state.pieces[ROOK_PIECE_TYPE as usize] |= castle_right_rook;
-
-                board.state.teams[color] |= castle_right_king;
-                board.state.teams[color] |= castle_right_rook;
-
-                board.state.all_pieces |= castle_right_king;
-                board.state.all_pieces |= castle_right_rook;
-            }
-        }
+        board.state.all_pieces |= king_dest;
+        board.state.all_pieces |= rook_dest;
 
         board.state.first_move &= !from;
         board.state.first_move &= !to;
@@ -126,13 +121,11 @@ impl<const T: usize> Piece<T> for KingPiece<T> {
         PieceSymbol::Char('k')
     }
 
-    fn generate_lookup_moves(&self, board: &Board<T>, mut from: BitBoard<T>) -> AttackDirections<T> {
-        let cols = board.state.cols;
-        let edges = &board.state.edges[0];
-        let mut moves = right_one(from, edges) | left_one(from, edges);
-        from |= moves;
-        moves |= up_one(from, cols, edges);
-        moves |= down_one(from, cols, edges);
+    fn generate_lookup_moves(&self, board: &Board<T>, from: BitBoard<T>) -> AttackDirections<T> {
+        let mut moves = BitBoard::new();
+        for offset in KING_OFFSETS {
+            moves |= leap_by_offset(board, from, offset);
+        }
         vec![moves]
     }
 
@@ -184,11 +177,7 @@ impl<const T: usize> Piece<T> for KingPiece<T> {
 
         let color: usize = action.team as usize;
         let piece_type = piece_type as usize;
-        let captured_color: usize = if (to & board.state.teams[0]).is_set() {
-            0
-        } else {
-            1
-        };
+        let captured_color = board.state.get_team(to).expect("The captured piece should belong to a team.");
         let mut captured_piece_type: usize = 0;
         for i in 0..(board.game.pieces.len()) {
             if (board.state.pieces[i] & to).is_set() {
@@ -248,18 +237,12 @@ impl<const T: usize> Piece<T> for KingPiece<T> {
         team: u16,
         mode: u16,
     ) {
-        let rows = board.state.rows;
-        let cols = board.state.cols;
         let board_len = board.state.squares;
 
         let from_board = BitBoard::from_lsb(from);
         let bit_actions = self.get_moves(board, from_board, piece_type, team, mode)
             & !board.state.teams[team as usize] & !board.state.gaps;
 
-        if bit_actions.is_empty() {
-            return;
-        }
-
         for bit in bit_actions.iter_set_bits(board_len) {
             actions.push(Move::Action(Action {
                 from: Some(from),
@@ -279,11 +262,7 @@ impl<const T: usize> Piece<T> for KingPiece<T> {
             If necessary, I can remake this.
         */
 
-        let bottom_row = match team {
-            0 => board.state.edges[0].bottom,
-            1 => board.state.edges[0].top,
-            _ => board.state.edges[0].bottom,
-        };
+        let bottom_row = get_back_rank(board, team);
         let team_board = board.state.teams[team as usize];
         let first_move = board.state.first_move;
 
@@ -293,33 +272,6 @@ impl<const T: usize> Piece<T> for KingPiece<T> {
 
         let rooks = board.state.pieces[ROOK_PIECE_TYPE as usize] & team_board & first_move & bottom_row;
 
-        /*
-            FRC Castling brings us to the same positions that traditional chess castling would.
-            To extend for bigger board sizes, we'll have to create two castling spots for left and right.
-            We'll define these positions in terms of `left_center`.
-
-            `left_center` is the centermost point on the bottom row.
-            If there are two center points, `left_center` is the center point on the left.
-        */
-
-        let mut left_center = BitBoard::from_lsb(if board.state.cols % 2 == 0 {
-            (cols / 2) - 1
-        } else {
-            cols / 2
-        });
-
-        if team == 0 {
-            left_center = left_center.down(7, cols);
-        }
-
-        let castle_left_king = left_center.left(1);
-        let castle_left_rook = left_center;
-        let castle_right_king = left_center.right(3);
-        let castle_right_rook = left_center.right(2);
-
-        let castle_left = castle_left_king | castle_left_rook;
-        let castle_right = castle_right_king | castle_right_rook;
-
         for rook in rooks.iter_set_bits(board_len) {
             let rook_board = BitBoard::from_lsb(rook);
 
@@ -334,17 +286,16 @@ impl<const T: usize> Piece<T> for KingPiece<T> {
                 Direction::RIGHT
             };
 
-            let castling_spots = match dir {
-                Direction::LEFT => castle_left,
-                Direction::RIGHT => castle_right,
-            };
+            let (king_dest, rook_dest) = get_castling_squares(board, team, dir);
 
-            let in_between = match dir {
-                Direction::LEFT => BitBoard::starting_at_lsb(rook, from - rook + 1),
-                Direction::RIGHT => BitBoard::starting_at_lsb(from, rook - from + 1),
-            };
+            /*
+                In Fischer Random, the king and rook can start on either side of their destinations (or on them), so we can't assume which way they're travelling.
+                Every square either of them passes through has to be empty, other than the squares they start on.
+            */
+            let between_king_dest = BitBoard::starting_at_lsb(from.min(king_dest), from.abs_diff(king_dest) + 1);
+            let between_rook_dest = BitBoard::starting_at_lsb(rook.min(rook_dest), rook.abs_diff(rook_dest) + 1);
 
-            let all_spots = (castling_spots | in_between) & !(from_board | rook_board);
+            let all_spots = (between_king_dest | between_rook_dest) & !(from_board | rook_board);
 
             /*
                 We're not checking if the squares are attacked here, because if the squares aren't empty, we won't need to.
@@ -354,18 +305,7 @@ impl<const T: usize> Piece<T> for KingPiece<T> {
                 continue;
             }
 
-            let king_dest = match dir {
-                Direction::LEFT => castle_left_king,
-                Direction::RIGHT => castle_right_king,
-            }
-            .bitscan_forward();
-
-            let between_king_dest = match dir {
-                Direction::LEFT => BitBoard::starting_at_lsb(king_dest, from - king_dest + 1),
-                Direction::RIGHT => BitBoard::starting_at_lsb(from, king_dest - from + 1),
-            };
-
-            let attack_mask = board.get_move_mask(board.get_next_team(team), ATTACKS_MODE);
+            let attack_mask = board.game.controller.get_castling_attacks(board, team);
 
             if (between_king_dest & attack_mask).is_set() {
                 continue;
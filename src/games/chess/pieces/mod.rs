@@ -1,3 +1,5 @@
+use crate::{bitboard::BitBoard, board::{Board, PieceType}};
+
 mod king;
 mod knight;
 mod pawn;
@@ -7,3 +9,38 @@ pub use king::*;
 pub use knight::*;
 pub use pawn::*;
 pub use sliders::*;
+
+/// The piece types of chess's pieces, in the order of `Game::pieces`.
+/// Variants with extra pieces (eg. Capablanca's archbishop and chancellor) add them after the king, so these stay the same.
+pub const PAWN_PIECE_TYPE: PieceType = 0;
+pub const KNIGHT_PIECE_TYPE: PieceType = 1;
+pub const BISHOP_PIECE_TYPE: PieceType = 2;
+pub const ROOK_PIECE_TYPE: PieceType = 3;
+pub const QUEEN_PIECE_TYPE: PieceType = 4;
+pub const KING_PIECE_TYPE: PieceType = 5;
+
+/// The region with each team's back rank, for boards where it isn't the edge of the board (eg. Omega chess, where the board is surrounded by walls.)
+pub const BACK_RANK_REGION: &str = "back rank";
+/// The region with the two squares each team's king castles to, for games where they aren't the usual ones (see `get_castling_squares`.)
+pub const CASTLING_REGION: &str = "castling";
+/// The region with the squares each team's pawns promote on, for games where that isn't the other team's back rank (eg. four-player chess.)
+pub const PROMOTION_REGION: &str = "promotion";
+
+/// Gets the rank `team`'s pieces start on, where their king castles and where the other team's pawns promote.
+pub fn get_back_rank<const T: usize>(board: &Board<T>, team: u16) -> BitBoard<T> {
+    match board.game.get_region(BACK_RANK_REGION) {
+        Some(region) => region.get(team),
+        None => match team {
+            0 => board.state.edges[0].bottom,
+            _ => board.state.edges[0].top,
+        },
+    }
+}
+
+/// Gets the squares `team`'s pawns promote on.
+pub fn get_promotion_squares<const T: usize>(board: &Board<T>, team: u16) -> BitBoard<T> {
+    match board.game.get_region(PROMOTION_REGION) {
+        Some(region) => region.get(team),
+        None => get_back_rank(board, 0) | get_back_rank(board, 1),
+    }
+}
@@ -5,34 +5,88 @@ use crate::{
             Action, HistoryMove, HistoryState, HistoryUpdate, IndexedPreviousBoard, PreviousBoard, Move, ActionInfo, TurnInfo,
         },
         edges::Edges,
-        pieces::{Piece, PieceSymbol},
-        AttackDirections, Board, Cols, PieceType,
+        pieces::{leap_by_offset, orient_offset, Piece, PieceSymbol},
+        AttackDirections, Board, PieceType,
     },
     games::chess::game::ATTACKS_MODE,
 };
 
+use super::{get_back_rank, get_promotion_squares, KING_PIECE_TYPE, PAWN_PIECE_TYPE};
+
 const NORMAL_PAWN_MOVE: u16 = 0;
-const EN_PASSANT_MOVE: u16 = 1;
-fn promotion_move(piece_type: PieceType) -> u16 {
+pub const EN_PASSANT_MOVE: u16 = 1;
+
+pub fn promotion_move(piece_type: PieceType) -> u16 {
     piece_type + 1
 }
 
-#[derive(Debug)] pub struct PawnPiece<const T: usize>;
+#[derive(Debug)] pub struct PawnPiece<const T: usize> {
+    /// Whether or not pawns can promote to kings (eg. in Antichess, where the king is an ordinary piece.)
+    pub king_promotion: bool,
+    /// How many squares pawns can step forwards on their first move (2 in chess, or 3 in Omega chess.)
+    pub first_move_steps: u16,
+    /// The only piece types pawns can promote to (eg. the ferz in Shatranj), or `None` to allow any piece besides a pawn (or a king.)
+    pub promotion_pieces: Option<&'static [PieceType]>
+}
+
+/// Moves a bitboard `shift` squares forwards from `team`'s perspective (the way its pawns move), dropping any squares that would leave the board.
+/// With four teams (eg. four-player chess), two of the teams move sideways across the board; see `orient_offset`.
+pub fn up<const T: usize>(board: &Board<T>, bitboard: &BitBoard<T>, shift: u16, team: u16) -> BitBoard<T> {
+    leap_by_offset(board, *bitboard, orient_offset((shift as i16, 0), team, board.game.teams))
+}
 
-pub fn up<const T: usize>(bitboard: &BitBoard<T>, shift: u16, cols: Cols, team: u16) -> BitBoard<T> {
-    match team {
-        0 => bitboard.up(shift, cols),
-        1 => bitboard.down(shift, cols),
-        _ => bitboard.up(shift, cols),
+/// Moves a bitboard `shift` squares backwards from `team`'s perspective, dropping any squares that would leave the board.
+pub fn down<const T: usize>(board: &Board<T>, bitboard: &BitBoard<T>, shift: u16, team: u16) -> BitBoard<T> {
+    leap_by_offset(board, *bitboard, orient_offset((-(shift as i16), 0), team, board.game.teams))
+}
+
+/// Whether or not `action` was a pawn's double (or triple) step that can be captured en passant.
+/// Pawns double stepping from their first rank (eg. in Horde) can't be captured en passant.
+/// The pawn also has to still be where it stepped to, since teams making several turns (eg. in Monster chess) could have moved it again.
+pub fn is_en_passant_step<const T: usize>(board: &Board<T>, action: &Action) -> bool {
+    match action.from {
+        Some(from) => {
+            let back_rank = get_back_rank(board, action.team);
+
+            action.piece_type == PAWN_PIECE_TYPE
+                && get_skipped_squares(board, action).is_set()
+                && (BitBoard::<T>::from_lsb(from) & back_rank).is_empty()
+                && (BitBoard::<T>::from_lsb(action.to) & board.state.get_piece_team_board(PAWN_PIECE_TYPE as usize, action.team as usize)).is_set()
+        }
+        None => false,
     }
 }
 
-pub fn down<const T: usize>(bitboard: &BitBoard<T>, shift: u16, cols: Cols, team: u16) -> BitBoard<T> {
-    match team {
-        0 => bitboard.down(shift, cols),
-        1 => bitboard.up(shift, cols),
-        _ => bitboard.down(shift, cols),
+/// Gets the squares a pawn stepped over with `action`, which can all be captured en passant after a double (or triple) step.
+/// Pawns step along their file, or along their rank for teams that move sideways across the board (eg. in four-player chess.)
+pub fn get_skipped_squares<const T: usize>(board: &Board<T>, action: &Action) -> BitBoard<T> {
+    let mut skipped = BitBoard::new();
+    if let Some(from) = action.from {
+        let cols = board.state.cols;
+        let (start, end) = (from.min(action.to), from.max(action.to));
+        let step = if start / cols == end / cols {
+            1
+        } else if start % cols == end % cols {
+            cols
+        } else {
+            // Captures move diagonally, so they don't skip any squares.
+            return skipped;
+        };
+
+        for square in ((start + step)..end).step_by(step as usize) {
+            skipped |= BitBoard::from_lsb(square);
+        }
     }
+    skipped
+}
+
+/// Finds the double steps the previous team made on their last sub-move that can be captured en passant.
+/// Teams making several turns per sub-move (eg. in Monster chess) can make more than one of them.
+pub fn get_en_passant_steps<'a, const T: usize>(board: &'a Board<T>) -> impl Iterator<Item = &'a Action> {
+    board.get_last_sub_move().filter_map(move |last_move| match last_move {
+        Move::Action(last_action) if is_en_passant_step(board, last_action) => Some(last_action),
+        _ => None,
+    })
 }
 
 impl<const T: usize> PawnPiece<T> {
@@ -45,20 +99,16 @@ impl<const T: usize> PawnPiece<T> {
         to: BitBoard<T>,
         turn_info: TurnInfo
     ) -> Option<HistoryMove<T>> {
-        let cols = board.state.cols;
-
         let color: usize = action.team as usize;
         let piece_type = piece_type as usize;
-        let en_passant_target = down(&to, 1, cols, color as u16);
+        let en_passant_target = get_en_passant_steps(board)
+            .find(|step| (get_skipped_squares(board, step) & to).is_set())
+            .map(|step| BitBoard::from_lsb(step.to))
+            .unwrap_or_else(|| down(board, &to, 1, color as u16));
 
-        let en_passant_target_color: usize = if (en_passant_target & board.state.teams[0]).is_set()
-        {
-            0
-        } else {
-            1
-        };
+        let en_passant_target_color = board.state.get_team(en_passant_target).expect("The pawn captured en passant should belong to a team.");
 
-        let history_move = HistoryMove {
+        let mut history_move = HistoryMove {
             action: Move::Action(*action),
             first_history_move: board.retrieve_first_history_move(Move::Action(*action)),
             turn_info,
@@ -79,13 +129,25 @@ impl<const T: usize> PawnPiece<T> {
             },
         };
 
+        // Pawns moving sideways (eg. in four-player chess) can step over another team's promotion squares, so en passant can promote too.
+        let mut promotion_piece_type = piece_type;
+        if action.info >= 1 {
+            promotion_piece_type = (action.info - 1) as usize;
+            if let HistoryState::Any { updates, .. } = &mut history_move.state {
+                updates.push(HistoryUpdate::Piece(IndexedPreviousBoard(
+                    promotion_piece_type,
+                    board.state.pieces[promotion_piece_type],
+                )));
+            }
+        }
+
         board.state.teams[color] ^= from;
         board.state.teams[color] |= to;
         board.state.teams[en_passant_target_color] ^= en_passant_target;
 
         board.state.pieces[piece_type] ^= from;
         board.state.pieces[piece_type] ^= en_passant_target;
-        board.state.pieces[piece_type] |= to;
+        board.state.pieces[promotion_piece_type] |= to;
 
         board.state.all_pieces ^= from;
         board.state.all_pieces ^= en_passant_target;
@@ -103,18 +165,13 @@ impl<const T: usize> Piece<T> for PawnPiece<T> {
         true
     }
 
-    fn generate_lookup_moves(&self, board: &Board<T>, mut from: BitBoard<T>) -> AttackDirections<T> {
+    fn generate_lookup_moves(&self, board: &Board<T>, from: BitBoard<T>) -> AttackDirections<T> {
         let mut attack_dirs: AttackDirections<T> = vec![];
-        let edges = board.state.edges[0];
         for team in 0..board.game.teams {
-            let from = match team {
-                0 => from & !edges.top,
-                1 => from & !edges.bottom,
-                _ => from & !edges.top,
-            };
-            let up_one = up(&from, 1, board.state.cols, team);
-            let mut captures = (up_one & !edges.right).right(1);
-            captures |= (up_one & !edges.left).left(1);
+            let mut captures = BitBoard::new();
+            for offset in [(1, -1), (1, 1)] {
+                captures |= leap_by_offset(board, from, orient_offset(offset, team, board.game.teams));
+            }
             attack_dirs.push(captures);
         }
         attack_dirs
@@ -142,6 +199,7 @@ impl<const T: usize> Piece<T> for PawnPiece<T> {
                 .position(|piece_trait| match piece_trait.get_piece_symbol() {
                     PieceSymbol::Char(piece_symbol) => char == piece_symbol,
                     PieceSymbol::TeamSymbol(chars) => chars.contains(&char),
+                    PieceSymbol::Prefixed(_, _) => false,
                 })
                 .expect(&format!(
                     "Could not find a promotion piece type from '{info}'"
@@ -184,9 +242,6 @@ impl<const T: usize> Piece<T> for PawnPiece<T> {
         team: u16,
         mode: u16,
     ) -> BitBoard<T> {
-        let cols = board.state.cols;
-        let edges = &board.state.edges[0];
-
         if mode == ATTACKS_MODE {
             return self.get_attack_lookup(board, piece_type).expect("Could not find pawn attack lookup")
                 [from.bitscan_forward() as usize][team as usize];
@@ -198,32 +253,21 @@ impl<const T: usize> Piece<T> for PawnPiece<T> {
         let mut captures = self.get_attack_lookup(board, piece_type).expect("Could not find pawn attack lookup")
             [from.bitscan_forward() as usize][team as usize];
 
-        let single_moves = up(&from, 1, cols, team) & !board.state.all_pieces;
+        let single_moves = up(board, &from, 1, team) & !board.state.all_pieces;
         let first_move = (from & board.state.first_move).is_set();
 
         moves |= single_moves;
 
         if first_move {
-            let double_moves = up(&single_moves, 1, cols, team) & !board.state.all_pieces;
-            moves |= double_moves;
+            let mut steps = single_moves;
+            for _ in 1..self.first_move_steps {
+                steps = up(board, &steps, 1, team) & !board.state.all_pieces;
+                moves |= steps;
+            }
         }
 
-        if let Some(last_move) = board.history.iter().last() {
-            if let Move::Action(last_action) = last_move {
-                if let Some(from) = last_action.from {
-                    let conditions = last_action.piece_type == 0
-                        && (last_action.to.abs_diff(from) == (2 * (cols)));
-
-                    if conditions {
-                        capture_requirements |= up(
-                            &BitBoard::from_lsb(from),
-                            1,
-                            cols,
-                            board.get_next_team(team),
-                        );
-                    }
-                }
-            }
+        for last_action in get_en_passant_steps(board) {
+            capture_requirements |= get_skipped_squares(board, last_action);
         }
 
         captures &= capture_requirements;
@@ -244,11 +288,7 @@ impl<const T: usize> Piece<T> for PawnPiece<T> {
     ) -> Option<HistoryMove<T>> {
         let color: usize = action.team as usize;
         let piece_type = piece_type as usize;
-        let captured_color: usize = if (to & board.state.teams[0]).is_set() {
-            0
-        } else {
-            1
-        };
+        let captured_color = board.state.get_team(to).expect("The captured piece should belong to a team.");
         let mut captured_piece_type: usize = 0;
         for i in 0..(board.game.pieces.len()) {
             if (board.state.pieces[i] & to).is_set() {
@@ -388,7 +428,7 @@ impl<const T: usize> Piece<T> for PawnPiece<T> {
         team: u16,
         mode: u16,
     ) {
-        let promotion_rows = board.state.edges[0].bottom | board.state.edges[0].top;
+        let promotion_rows = get_promotion_squares(board, team);
 
         let from_board = BitBoard::from_lsb(from);
         let bit_actions = self.get_moves(board, from_board, piece_type, team, mode)
@@ -398,61 +438,82 @@ impl<const T: usize> Piece<T> for PawnPiece<T> {
             return;
         }
 
-        let cols = board.state.cols;
-
-        let piece_types = board.game.pieces.len();
+        let piece_types = board.game.pieces.len() as PieceType;
 
         let captures = self.get_attack_lookup(board, piece_type).expect("Could not find pawn attack lookup")
             [from as usize][team as usize];
 
         for bit in bit_actions.iter_set_bits(board.state.squares) {
-            if (BitBoard::from_lsb(bit) & promotion_rows).is_set() {
+            let bit_board = BitBoard::from_lsb(bit);
+
+            let mut en_passant = false;
+            if (captures & bit_board & !board.state.all_pieces).is_set() {
+                en_passant = get_en_passant_steps(board)
+                    .any(|last_action| (get_skipped_squares(board, last_action) & bit_board).is_set());
+            }
+            let move_type = if en_passant {
+                EN_PASSANT_MOVE
+            } else {
+                NORMAL_PAWN_MOVE
+            };
+
+            if (bit_board & promotion_rows).is_set() {
                 for promotion_piece_type in 0..piece_types {
-                    if promotion_piece_type == 0 {
+                    if promotion_piece_type == PAWN_PIECE_TYPE {
                         continue;
                     }
-                    if promotion_piece_type == 5 {
+                    if promotion_piece_type == KING_PIECE_TYPE && !self.king_promotion {
                         continue;
                     }
+                    if let Some(promotion_pieces) = self.promotion_pieces {
+                        if !promotion_pieces.contains(&promotion_piece_type) {
+                            continue;
+                        }
+                    }
                     actions.push(Move::Action(Action {
                         from: Some(from),
                         to: bit,
                         team,
-                        info: promotion_move(promotion_piece_type as u16),
-                        move_type: NORMAL_PAWN_MOVE,
+                        info: promotion_move(promotion_piece_type),
+                        move_type,
                         piece_type,
                     }));
                 }
             } else {
-                let mut en_passant = false;
-                if let Some(last_move) = board.history.iter().last() {
-                    if let Move::Action(last_action) = last_move {
-                        if let Some(from) = last_action.from {
-                            let conditions = last_action.piece_type == 0
-                                && (last_action.to.abs_diff(from) == (2 * (cols)))
-                                && (last_action.to.abs_diff(bit) == (cols))
-                                && (captures & BitBoard::from_lsb(bit)).is_set();
-
-                            if conditions {
-                                en_passant = true;
-                            }
-                        }
-                    }
-                }
-
                 actions.push(Move::Action(Action {
                     from: Some(from),
                     to: bit,
                     team,
                     info: NORMAL_PAWN_MOVE,
-                    move_type: if en_passant {
-                        EN_PASSANT_MOVE
-                    } else {
-                        NORMAL_PAWN_MOVE
-                    },
+                    move_type,
                     piece_type,
                 }));
             }
         }
     }
+
+    /// Pawns can't be dropped onto the first or last rank.
+    #[allow(unused_variables)]
+    fn add_drop_actions(
+        &self,
+        actions: &mut Vec<Move>,
+        board: &Board<T>,
+        piece_type: PieceType,
+        team: u16,
+        mode: u16,
+    ) {
+        let edges = &board.state.edges[0];
+        let empty_squares = !board.state.all_pieces & !board.state.gaps & !edges.top & !edges.bottom;
+
+        for bit in empty_squares.iter_set_bits(board.state.squares) {
+            actions.push(Move::Action(Action {
+                from: None,
+                to: bit,
+                team,
+                info: NORMAL_PAWN_MOVE,
+                move_type: NORMAL_PAWN_MOVE,
+                piece_type,
+            }));
+        }
+    }
 }
@@ -1,9 +1,11 @@
 use crate::{board::{
-    pieces::{Piece, PieceSymbol},
+    pieces::{Piece, PieceSymbol, Offset},
     AttackDirections, Board, PieceType,
 }, bitboard::BitBoard};
 
-use super::{get_moves_ray, get_ray_attacks};
+use super::{generate_ray_lookups, get_ray_move_mask, get_rays_attacks};
+
+pub const BISHOP_DIRECTIONS: [Offset; 4] = [(1, -1), (-1, -1), (1, 1), (-1, 1)];
 
 #[derive(Debug)] pub struct BishopPiece;
 
@@ -13,42 +15,14 @@ impl<const T: usize> Piece<T> for BishopPiece {
     }
 
     fn generate_lookup_moves(&self, board: &Board<T>, from: BitBoard<T>) -> AttackDirections<T> {
-        let edges = &board.state.edges[0];
-        let cols = board.state.cols;
-        let mut lookups = vec![
-            get_moves_ray(
-                from,
-                |b| b.left(1).up(1, cols),
-                |b| b.is_empty() || (b & (edges.left | edges.top)).is_set(),
-            ),
-            get_moves_ray(
-                from,
-                |b| b.left(1).down(1, cols),
-                |b| b.is_empty() || (b & (edges.left | edges.bottom)).is_set(),
-            ),
-            get_moves_ray(
-                from,
-                |b| b.right(1).up(1, cols),
-                |b| b.is_empty() || (b & (edges.right | edges.top)).is_set(),
-            ),
-            get_moves_ray(
-                from,
-                |b| b.right(1).down(1, cols),
-                |b| b.is_empty() || (b & (edges.right | edges.bottom)).is_set(),
-            ),
-        ];
-        let mut combined_lookup = BitBoard::new();
-        for lookup in &lookups {
-            combined_lookup |= *lookup;
-        }
-        lookups.push(combined_lookup);
-        lookups
+        generate_ray_lookups(board, from, &BISHOP_DIRECTIONS)
     }
 
     fn can_lookup(&self) -> bool {
         true
     }
 
+    #[allow(unused_variables)]
     fn can_move_mask(
         &self,
         board: &Board<T>,
@@ -61,21 +35,9 @@ impl<const T: usize> Piece<T> for BishopPiece {
     ) -> BitBoard<T> {
         let lookup = self
             .get_attack_lookup(board, piece_type)
-            .expect("Could not find the queen attack lookup.");
-
-        let from_bit = from_bit as usize;
-        if (lookup[from_bit][4] & to).is_empty() {
-            return BitBoard::new();
-        }
+            .expect("Could not find the bishop attack lookup.");
 
-        for dir in 0..4 {
-            let ray = get_ray_attacks(board, from, from_bit, dir, &lookup);
-            if (ray & to).is_set() {
-                return ray;
-            }
-        }
-
-        return BitBoard::new();
+        get_ray_move_mask(board, from, from_bit as usize, 0..4, 4, lookup, to)
     }
 
     #[allow(unused_variables)]
@@ -89,14 +51,8 @@ impl<const T: usize> Piece<T> for BishopPiece {
     ) -> BitBoard<T> {
         let lookup = self
             .get_attack_lookup(board, piece_type)
-            .expect("Could not find the queen attack lookup.");
-        let mut attacks = BitBoard::new();
-
-        let from_bit = from.bitscan_forward() as usize;
-        for dir in 0..4 {
-            attacks |= get_ray_attacks(board, from, from_bit, dir, &lookup);
-        }
+            .expect("Could not find the bishop attack lookup.");
 
-        attacks
+        get_rays_attacks(board, from, from.bitscan_forward() as usize, 0..4, lookup)
     }
 }
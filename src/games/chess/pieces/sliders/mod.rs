@@ -1,9 +1,8 @@
 mod bishop;
 mod queen;
 mod rook;
-mod util;
 
 pub use bishop::*;
 pub use queen::*;
 pub use rook::*;
-pub use util::*;
+pub use crate::board::pieces::{generate_ray_lookups, get_moves_ray, get_ray_attacks, get_ray_move_mask, get_rays_attacks};
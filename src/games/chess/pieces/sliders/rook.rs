@@ -1,9 +1,11 @@
 use crate::{board::{
-    pieces::{Piece, PieceSymbol},
+    pieces::{Piece, PieceSymbol, Offset},
     AttackDirections, Board, PieceType,
 }, bitboard::BitBoard};
 
-use super::{get_moves_ray, get_ray_attacks};
+use super::{generate_ray_lookups, get_ray_move_mask, get_rays_attacks};
+
+pub const ROOK_DIRECTIONS: [Offset; 4] = [(0, -1), (0, 1), (1, 0), (-1, 0)];
 
 #[derive(Debug)] pub struct RookPiece;
 
@@ -13,38 +15,14 @@ impl<const T: usize> Piece<T> for RookPiece {
     }
 
     fn generate_lookup_moves(&self, board: &Board<T>, from: BitBoard<T>) -> AttackDirections<T> {
-        let edges = &board.state.edges[0];
-        let cols = board.state.cols;
-        let mut lookups = vec![
-            get_moves_ray(
-                from,
-                |b| b.left(1),
-                |b| b.is_empty() || (b & edges.left).is_set(),
-            ),
-            get_moves_ray(
-                from,
-                |b| b.right(1),
-                |b| b.is_empty() || (b & edges.right).is_set(),
-            ),
-            get_moves_ray(
-                from,
-                |b| b.up(1, cols),
-                |b| b.is_empty() || (b & edges.top).is_set(),
-            ),
-            get_moves_ray(
-                from,
-                |b| b.down(1, cols),
-                |b| b.is_empty() || (b & edges.bottom).is_set(),
-            ),
-        ];
-        let mut combined_lookup = BitBoard::new();
-        for lookup in &lookups {
-            combined_lookup |= *lookup;
-        }
-        lookups.push(combined_lookup);
-        lookups
+        generate_ray_lookups(board, from, &ROOK_DIRECTIONS)
     }
 
+    fn can_lookup(&self) -> bool {
+        true
+    }
+
+    #[allow(unused_variables)]
     fn can_move_mask(
         &self,
         board: &Board<T>,
@@ -57,25 +35,9 @@ impl<const T: usize> Piece<T> for RookPiece {
     ) -> BitBoard<T> {
         let lookup = self
             .get_attack_lookup(board, piece_type)
-            .expect("Could not find the queen attack lookup.");
-
-        let from_bit = from_bit as usize;
-        if (lookup[from_bit][4] & to).is_empty() {
-            return BitBoard::new();
-        }
+            .expect("Could not find the rook attack lookup.");
 
-        for dir in 0..4 {
-            let ray = get_ray_attacks(board, from, from_bit, dir, &lookup);
-            if (ray & to).is_set() {
-                return ray;
-            }
-        }
-
-        return BitBoard::new();
-    }
-
-    fn can_lookup(&self) -> bool {
-        true
+        get_ray_move_mask(board, from, from_bit as usize, 0..4, 4, lookup, to)
     }
 
     #[allow(unused_variables)]
@@ -89,14 +51,8 @@ impl<const T: usize> Piece<T> for RookPiece {
     ) -> BitBoard<T> {
         let lookup = self
             .get_attack_lookup(board, piece_type)
-            .expect("Could not find the queen attack lookup.");
-        let mut attacks = BitBoard::new();
-
-        let from_bit = from.bitscan_forward() as usize;
-        for dir in 0..4 {
-            attacks |= get_ray_attacks(board, from, from_bit, dir, &lookup);
-        }
+            .expect("Could not find the rook attack lookup.");
 
-        attacks
+        get_rays_attacks(board, from, from.bitscan_forward() as usize, 0..4, lookup)
     }
 }
@@ -1,20 +1,19 @@
 use crate::{board::{fen::PostProcess, Board, actions::Action}, bitboard::BitBoard};
 
+use super::pieces::{get_back_rank, up, PAWN_PIECE_TYPE};
+
 #[derive(Debug)]
 pub struct ChessPostProcess;
 impl<const T: usize> PostProcess<T> for ChessPostProcess {
     fn apply(&self, board: &mut Board<T>) {
-        let cols = board.state.cols;
-        let edges = &board.state.edges[0];
-        let mut bottom = edges.bottom;
-        let mut top = edges.top;
-
-        bottom |= bottom.up(1, cols);
-        top |= top.down(1, cols);
-
-        let first_move = (board.state.pieces[0] & board.state.teams[0] & bottom)
-            | (board.state.pieces[0] & board.state.teams[1] & top)
-            | (board.state.all_pieces ^ board.state.pieces[0]);
+        // Pawns can only make their first move from their starting rank, right in front of their team's back rank.
+        let pawns = board.state.pieces[PAWN_PIECE_TYPE as usize];
+        let mut first_move = board.state.all_pieces ^ pawns;
+        for team in 0..board.game.teams {
+            let back_rank = get_back_rank(board, team);
+            let starting_ranks = back_rank | up(board, &back_rank, 1, team);
+            first_move |= pawns & board.state.teams[team as usize] & starting_ranks;
+        }
         board.state.first_move &= first_move;
     }
-}
\ No newline at end of file
+}
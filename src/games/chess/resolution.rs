@@ -2,7 +2,7 @@ use std::cmp::Ordering;
 
 use crate::board::{game::{Resolution, GameResults}, Board, actions::{Action, Move}};
 
-use super::ATTACKS_MODE;
+use super::{ATTACKS_MODE, pieces::KING_PIECE_TYPE};
 
 #[derive(Debug)]
 pub struct ChessResolution<const T: usize>;
@@ -10,11 +10,12 @@ pub struct ChessResolution<const T: usize>;
 impl<const T: usize> Resolution<T> for ChessResolution<T> {
     fn resolve(&self, board: &mut Board<T>, legal_moves: &Vec<Move>) -> GameResults {
         if legal_moves.len() == 0 {
-            let kings = board.state.pieces[5];
+            let kings = board.state.pieces[KING_PIECE_TYPE as usize];
             let king_board = board.state.teams[board.state.moving_team as usize] & kings;
 
             let next_team = board.state.team_lookup[board.state.moving_team as usize];
-            let in_check = board.can_move(next_team, king_board, ATTACKS_MODE);
+            // Teams without a king (eg. the horde) can't be checkmated, only stalemated.
+            let in_check = king_board.is_set() && board.can_move(next_team, king_board, ATTACKS_MODE);
 
             if in_check {
                 GameResults::Win(next_team)
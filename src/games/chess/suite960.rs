@@ -0,0 +1,18 @@
+use crate::board::{tests::run_tests};
+use super::Chess960;
+
+const TEST_STR: &str = r#"rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w HAha - 0 1 ;D1 20 ;D2 400 ;D3 8902 ;D4 197281 ;D5 4865609
+bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w HFhf - 2 9 ;D1 21 ;D2 528 ;D3 12189 ;D4 326672 ;D5 8146062
+2nnrbkr/p1qppppp/8/1ppb4/6PP/3PP3/PPP2P2/BQNNRBKR w HEhe - 1 9 ;D1 21 ;D2 807 ;D3 18002 ;D4 667366 ;D5 16253601
+b1q1rrkb/pppppppp/3nn3/8/P7/1PPP4/4PPPP/BQNNRKRB w GE - 1 9 ;D1 20 ;D2 479 ;D3 10471 ;D4 273318 ;D5 6417013
+qbbnnrkr/2pp2pp/p7/1p2pp2/8/P3PP2/1PPP1KPP/QBBNNR1R w hf - 0 9 ;D1 22 ;D2 593 ;D3 13440 ;D4 382958 ;D5 9183776
+1nbbnrkr/p1p1ppp1/3p4/1p3P1p/3Pq2P/8/PPP1P1P1/QNBBNRKR w HFhf - 0 9 ;D1 28 ;D2 1120 ;D3 31058 ;D4 1171749 ;D5 34030312
+qnbnr1kr/ppp1b1pp/4p3/3p1p2/8/2NPP3/PPP1BPPP/QNB1R1KR w HEhe - 1 9 ;D1 29 ;D2 899 ;D3 26578 ;D4 824055 ;D5 24851983
+rk5r/8/8/8/8/8/8/RK5R w HAha - 0 1 ;D1 24 ;D2 479 ;D3 11099 ;D4 242723 ;D5 5689344
+r5kr/8/8/8/8/8/8/R5KR b HAha - 0 1 ;D1 24 ;D2 479 ;D3 11069 ;D4 242097 ;D5 5651372
+1rk2r2/8/8/8/8/8/8/1RK2R2 w FBfb - 0 1 ;D1 23 ;D2 442 ;D3 9859 ;D4 210648 ;D5 4839049"#;
+
+#[test]
+fn chess960_perft_suite() {
+    run_tests("Chess960", Chess960::create(), TEST_STR);
+}
@@ -1,10 +1,12 @@
 use crate::board::{game::ZobristController, Board, zobrist::ZobristHashTable, actions::Move};
 
+use super::pieces::PAWN_PIECE_TYPE;
+
 #[derive(Debug)]
 pub struct ChessZobrist<const T: usize>;
 
 impl<const T: usize> ZobristController<T> for ChessZobrist<T> {
-    fn apply(&self, hash: &mut u64, zobrist: &mut ZobristHashTable<T>, board: &mut Board<T>) {
+    fn apply(&self, hash: &mut u64, zobrist: &ZobristHashTable<T>, board: &Board<T>) {
         let last_move = (&board.history).iter().last();
         if let None = last_move {
             *hash ^= zobrist.table[zobrist.base_len];
@@ -16,7 +18,7 @@ impl<const T: usize> ZobristController<T> for ChessZobrist<T> {
 
         match last_move {
             Move::Action(last_action) => {
-                if last_action.piece_type != 0 {
+                if last_action.piece_type != PAWN_PIECE_TYPE {
                     *hash ^= zobrist.table[zobrist.base_len];
                     return;
                 }
@@ -0,0 +1,139 @@
+use crate::{
+    bitboard::BitBoard,
+    board::{
+        actions::{
+            Action, HistoryMove, HistoryState, HistoryUpdate, Move, PreviousBoard, PreviousReserve,
+            TheoreticalMove, TurnUpdate,
+        },
+        game::{Game, MoveController},
+        pieces::PieceSymbol,
+        Board, BoardState,
+    },
+    games::chess::{pieces::{up, PAWN_PIECE_TYPE}, ChessMoveController},
+};
+
+/// Crazyhouse is played with the rules of chess, except captured pieces go to the capturer's reserves, where they can be dropped back onto the board.
+#[derive(Debug)]
+pub struct CrazyhouseMoveController;
+
+impl CrazyhouseMoveController {
+    /// Finds what type of piece used to be on `square` before the given move was made.
+    fn get_previous_piece_type<const T: usize>(history_move: &HistoryMove<T>, square: BitBoard<T>) -> Option<usize> {
+        match &history_move.state {
+            HistoryState::Any { updates, .. } => updates.iter().find_map(|update| match update {
+                HistoryUpdate::Piece(piece) if (piece.1 & square).is_set() => Some(piece.0),
+                _ => None,
+            }),
+            HistoryState::Single { piece, .. } if (piece.1 & square).is_set() => Some(piece.0),
+            _ => None,
+        }
+    }
+}
+
+impl<const T: usize> MoveController<T> for CrazyhouseMoveController {
+    fn transform_moves(&self, board: &mut Board<T>, mode: u16, actions: Vec<Move>) -> Vec<Move> {
+        ChessMoveController::<T>.transform_moves(board, mode, actions)
+    }
+
+    fn is_legal(&self, board: &mut Board<T>, action: &Move) -> bool {
+        ChessMoveController::<T>.is_legal(board, action)
+    }
+
+    fn use_pseudolegal(&self) -> bool {
+        true
+    }
+
+    fn make_move(&self, board: &mut Board<T>, action: &Action) -> Option<HistoryMove<T>> {
+        let team = action.team as usize;
+        let opponent = board.get_next_team(action.team) as usize;
+
+        let from = BitBoard::from_lsb(action.from.expect("Moves of pieces on the board must have a `from` square."));
+        let to = BitBoard::from_lsb(action.to);
+
+        let promoted = board.state.promoted;
+        let opponent_pieces = board.state.teams[opponent];
+
+        let mut history_move = board.game.pieces[action.piece_type as usize].make_move(board, action)?;
+        let captured = opponent_pieces & !board.state.teams[opponent];
+
+        let mut updates = vec![];
+
+        // Promoted pieces keep track of being promoted as they move, so they can be demoted once they're captured.
+        let is_promotion = action.piece_type == PAWN_PIECE_TYPE && action.info > 0;
+        let mut new_promoted = promoted & !captured;
+        if is_promotion || (promoted & from).is_set() {
+            new_promoted = (new_promoted & !from) | to;
+        }
+
+        if new_promoted != promoted {
+            updates.push(HistoryUpdate::Promoted(PreviousBoard(promoted)));
+            board.state.promoted = new_promoted;
+        }
+
+        for square in captured.iter_set_bits(board.state.squares) {
+            let square = BitBoard::from_lsb(square);
+            let piece_type = if (promoted & square).is_set() {
+                PAWN_PIECE_TYPE as usize
+            } else {
+                CrazyhouseMoveController::get_previous_piece_type(&history_move, square)
+                    .expect("Could not find the type of the captured piece.")
+            };
+
+            updates.push(HistoryUpdate::Reserve(PreviousReserve(
+                team,
+                piece_type,
+                board.state.reserves[team][piece_type],
+            )));
+            board.state.reserves[team][piece_type] += 1;
+        }
+
+        if !updates.is_empty() {
+            history_move.add_updates(updates);
+        }
+
+        Some(history_move)
+    }
+
+    fn make_drop_move(&self, board: &mut Board<T>, action: &Action) -> Option<HistoryMove<T>> {
+        let history_move = board.game.pieces[action.piece_type as usize].make_drop_move(board, action)?;
+
+        // Pawns dropped onto their second rank can still move two squares forward.
+        if action.piece_type == PAWN_PIECE_TYPE {
+            let back_rank = match action.team {
+                0 => board.state.edges[0].bottom,
+                _ => board.state.edges[0].top,
+            };
+            let second_rank = up(board, &back_rank, 1, action.team);
+            board.state.first_move |= BitBoard::from_lsb(action.to) & second_rank;
+        }
+
+        Some(history_move)
+    }
+
+    fn encode_action(&self, board: &Board<T>, action: &Move) -> Vec<String> {
+        match action {
+            Move::Action(Action { from: None, to, piece_type, .. }) => {
+                let symbol = match board.game.pieces[*piece_type as usize].get_piece_symbol() {
+                    PieceSymbol::Char(char) | PieceSymbol::Prefixed(_, char) => char,
+                    PieceSymbol::TeamSymbol(chars) => chars[0],
+                };
+
+                vec![format!("{}@{}", symbol.to_ascii_uppercase(), board.encode_position(*to))]
+            }
+            _ => ChessMoveController::<T>.encode_action(board, action),
+        }
+    }
+
+    fn update(&self, action: &Move, state: &BoardState<T>, game: &Game<T>) -> TurnUpdate {
+        ChessMoveController::<T>.update(action, state, game)
+    }
+
+    fn get_theoretical_moves(&self, board: &Board<T>) -> Vec<TheoreticalMove> {
+        ChessMoveController::<T>.get_theoretical_moves(board)
+    }
+
+    fn get_max_available_moves(&self) -> u32 {
+        // Drops can add up to five moves for each empty square.
+        500
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{board::game::Game, games::chess::Chess};
+
+use super::CrazyhouseMoveController;
+
+pub struct Crazyhouse;
+
+impl Crazyhouse {
+    pub fn create() -> Game<1> {
+        let mut game = Chess::create();
+        game.name = String::from("Crazyhouse");
+        game.controller = Box::new(CrazyhouseMoveController);
+        game.fen_options.state.reserves = true;
+        game.fen_options.default_fen = String::from("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR[] w KQkq - 0 1");
+        game
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::game::NORMAL_MODE;
+
+    use super::Crazyhouse;
+
+    #[test]
+    fn holdings_fen() {
+        let crazyhouse = Crazyhouse::create();
+        let fen = "r1b1kb1r/ppp2ppp/2n5/3Q~4/8/8/PPPP1PPP/RNB1KBNR[NPn] b KQkq - 0 1";
+        let board = crazyhouse.from_fen(fen);
+
+        assert_eq!(board.state.reserves[0], vec![1, 1, 0, 0, 0, 0]);
+        assert_eq!(board.state.reserves[1], vec![0, 1, 0, 0, 0, 0]);
+        assert_eq!(board.to_fen(), "r1b1kb1r/ppp2ppp/2n5/3Q~4/8/8/PPPP1PPP/RNB1KBNR[PNn] b KQkq - 0 1");
+
+        assert_eq!(crazyhouse.default().to_fen(), "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR[] w KQkq - 0 1");
+    }
+
+    #[test]
+    fn drop_notation() {
+        let crazyhouse = Crazyhouse::create();
+        let mut board = crazyhouse.from_fen("4k3/8/8/8/8/8/8/4K3[P] w - - 0 1");
+
+        let action = board.decode_action("P@e4", NORMAL_MODE).expect("P@e4 should be a legal drop.");
+        assert_eq!(board.encode_action(&action), "P@e4");
+        assert!(board.decode_action("P@e8", NORMAL_MODE).is_none());
+        assert!(board.decode_action("P@e1", NORMAL_MODE).is_none());
+
+        board.make_move(&action);
+        assert_eq!(board.to_fen(), "4k3/8/8/8/4P3/8/8/4K3[] b - - 0 1");
+    }
+
+    #[test]
+    fn captures_go_to_hand() {
+        let crazyhouse = Crazyhouse::create();
+
+        // The promoted queen on d7 goes back to the hand as a pawn.
+        let mut board = crazyhouse.from_fen("4k3/3Q~4/8/8/8/8/8/4K3[] b - - 0 1");
+        let state = board.state.clone();
+
+        let action = board.decode_action("e8d7", NORMAL_MODE).expect("The king should be able to capture on d7.");
+        let undo = board.make_move(&action);
+        assert_eq!(board.to_fen(), "8/3k4/8/8/8/8/8/4K3[p] w - - 0 2");
+
+        board.undo_move(undo);
+        assert_eq!(board.state, state);
+
+        // Pieces that promote are marked as promoted.
+        let mut board = crazyhouse.from_fen("1r2k3/P7/8/8/8/8/8/4K3[] w - - 0 1");
+        let action = board.decode_action("a7b8q", NORMAL_MODE).expect("The pawn should be able to promote on b8.");
+        board.make_move(&action);
+        assert_eq!(board.to_fen(), "1Q~2k3/8/8/8/8/8/8/4K3[R] b - - 0 1");
+    }
+}
@@ -0,0 +1,6 @@
+mod game;
+mod controller;
+mod suite;
+
+pub use game::*;
+pub use controller::*;
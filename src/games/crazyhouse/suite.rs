@@ -0,0 +1,16 @@
+use crate::board::{tests::run_tests};
+use super::Crazyhouse;
+
+const TEST_STR: &str = r#"rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR[] w KQkq - 0 1 ;D1 20 ;D2 400 ;D3 8902 ;D4 197281 ;D5 4888832
+2k5/8/8/8/8/8/8/4K3[QRBNPqrbnp] w - - 0 1 ;D1 301 ;D2 75353
+2k5/8/8/8/8/8/8/4K3[Qn] w - - 0 1 ;D1 67 ;D2 3083 ;D3 88634 ;D4 932554
+r1bqk2r/pppp1ppp/2n1p3/4P3/1b1Pn3/2NB1N2/PPP2PPP/R1BQK2R[] b KQkq - 0 1 ;D1 42 ;D2 1347 ;D3 58057 ;D4 2083382
+4k3/3Q~4/8/8/8/8/8/4K3[] b - - 0 1 ;D1 2 ;D2 33 ;D3 347 ;D4 3778
+r3k3/1P6/8/8/8/8/8/4K3[] w q - 0 1 ;D1 13 ;D2 124 ;D3 2410 ;D4 23766
+4k3/8/8/8/8/8/8/4K3[P] w - - 0 1 ;D1 53 ;D2 255 ;D3 2830 ;D4 19002
+rnb1kbnr/ppp2ppp/8/3q4/8/2N5/PPPP1PPP/R1BQKBNR[Pp] b KQkq - 1 4 ;D1 85 ;D2 5194 ;D3 338140"#;
+
+#[test]
+fn crazyhouse_perft_suite() {
+    run_tests("Crazyhouse", Crazyhouse::create(), TEST_STR);
+}
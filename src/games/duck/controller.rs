@@ -0,0 +1,118 @@
+use crate::{
+    bitboard::BitBoard,
+    board::{
+        actions::{Action, CounterUpdate, Move, TheoreticalMove, TurnUpdate},
+        game::{Game, MoveController},
+        Board, BoardState, PieceType,
+    },
+    games::chess::{get_kingless_team, pieces::{promotion_move, PAWN_PIECE_TYPE}, ChessMoveController},
+};
+
+pub const DUCK: PieceType = 6;
+
+/// The turn each team moves the duck on, after making their chess move.
+pub const DUCK_TURN: u16 = 1;
+
+/// Duck chess is played with the rules of chess, except each team also moves the duck to an empty square after their move.
+/// The duck can't be captured or moved through. There's no check, so the game is won by capturing the opponent's king.
+#[derive(Debug)]
+pub struct DuckMoveController;
+
+impl<const T: usize> MoveController<T> for DuckMoveController {
+    #[allow(unused_variables)]
+    fn transform_moves(&self, board: &mut Board<T>, mode: u16, actions: Vec<Move>) -> Vec<Move> {
+        // Once a king has been captured, the game is over.
+        if get_kingless_team(board).is_some() {
+            return vec![];
+        }
+
+        let mut legal_moves = Vec::with_capacity(actions.len());
+        for action in actions {
+            if self.is_legal(board, &action) {
+                legal_moves.push(action);
+            }
+        }
+        legal_moves
+    }
+
+    fn is_legal(&self, board: &mut Board<T>, action: &Move) -> bool {
+        match action {
+            // Pawns can't promote into the duck, since it doesn't belong to either team.
+            Move::Action(action) if action.piece_type == PAWN_PIECE_TYPE && action.info == promotion_move(DUCK) => false,
+            // The duck has to be moved on the duck's turn, and only then.
+            Move::Action(action) => (action.piece_type == DUCK) == (board.state.current_turn == DUCK_TURN),
+            Move::Pass => false,
+        }
+    }
+
+    fn use_pseudolegal(&self) -> bool {
+        true
+    }
+
+    fn add_moves(&self, board: &Board<T>, actions: &mut Vec<Move>) {
+        if board.state.current_turn != DUCK_TURN {
+            return;
+        }
+
+        let duck = board.state.gaps;
+        let from = if duck.is_set() {
+            Some(duck.bitscan_forward())
+        } else {
+            None
+        };
+
+        let empty_squares = !(board.state.all_pieces | board.state.gaps);
+        for to in empty_squares.iter_set_bits(board.state.squares) {
+            actions.push(Move::Action(Action {
+                from,
+                to,
+                team: board.state.moving_team,
+                piece_type: DUCK,
+                info: 0,
+                move_type: 0,
+            }));
+        }
+    }
+
+    /// There's no check, so kings can castle out of and through attacked squares.
+    #[allow(unused_variables)]
+    fn get_castling_attacks(&self, board: &Board<T>, team: u16) -> BitBoard<T> {
+        BitBoard::new()
+    }
+
+    fn encode_action(&self, board: &Board<T>, action: &Move) -> Vec<String> {
+        match action {
+            Move::Action(action) if action.piece_type == DUCK => {
+                vec![format!("@{}", board.encode_position(action.to))]
+            }
+            _ => ChessMoveController::<T>.encode_action(board, action),
+        }
+    }
+
+    /// The half move clock is updated by each team's chess move, so their duck move leaves it as is.
+    fn update(&self, action: &Move, state: &BoardState<T>, game: &Game<T>) -> TurnUpdate {
+        let is_duck_move = matches!(action, Move::Action(action) if action.piece_type == DUCK);
+        let sub_moves = if is_duck_move {
+            CounterUpdate::To(state.sub_moves)
+        } else {
+            match ChessMoveController::<T>.update(action, state, game).sub_moves {
+                CounterUpdate::To(sub_moves) => CounterUpdate::To(sub_moves),
+                CounterUpdate::Next => CounterUpdate::To(state.sub_moves + 1),
+            }
+        };
+
+        TurnUpdate {
+            turns: CounterUpdate::Next,
+            sub_moves,
+            full_moves: CounterUpdate::Next,
+        }
+    }
+
+    fn get_theoretical_moves(&self, board: &Board<T>) -> Vec<TheoreticalMove> {
+        ChessMoveController::<T>.get_theoretical_moves(board)
+    }
+
+    fn get_max_available_moves(&self) -> u32 {
+        ChessMoveController::<T>.get_max_available_moves()
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{
+    board::{
+        fen::FenCurrentTurn,
+        game::{Game, ZobristController},
+        pieces::Piece,
+        zobrist::ZobristHashTable,
+    },
+    games::chess::Chess,
+};
+
+use super::{pieces::DuckPiece, DuckMoveController, DuckPostProcess, DuckResolution, DuckZobrist};
+
+const DUCK: &dyn Piece<1> = &DuckPiece;
+
+pub struct Duck;
+
+impl Duck {
+    /// Each team makes two turns per sub-move: their chess move, and then their duck move.
+    /// The duck is written as `*` in FENs, and the current turn is added after the full move number.
+    pub fn create() -> Game<1> {
+        let mut game = Chess::create();
+        game.name = String::from("Duck Chess");
+        game.turns = vec![2, 2];
+        // En passant has to look past the duck move made after a pawn's double step.
+        game.saved_last_moves = 2;
+        game.pieces.push(DUCK);
+        game.controller = Box::new(DuckMoveController);
+        game.resolution = Box::new(DuckResolution);
+
+        let extra_hashes = ZobristController::<1>::get_extra_hashes(&DuckZobrist);
+        game.zobrist_controller = Box::new(DuckZobrist);
+        game.zobrist = ZobristHashTable::<1>::generate(64, 2, 7, extra_hashes, || fastrand::u64(0..u64::MAX));
+
+        game.fen_options.state.gaps = '*';
+        game.fen_options.args.push(("current turn".to_string(), Box::new(FenCurrentTurn)));
+        game.fen_options.post_process = Box::new(DuckPostProcess);
+        game.fen_options.default_fen = String::from("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1 0");
+        game
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::{actions::Move, game::{GameResults, NORMAL_MODE}};
+
+    use super::{Duck, super::DUCK};
+
+    #[test]
+    fn duck_moves() {
+        let duck = Duck::create();
+        let mut board = duck.from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1 0");
+
+        let state = board.state.clone();
+        let hash = duck.zobrist.compute(&board);
+
+        let action = board.decode_action("g1f3", NORMAL_MODE).expect("Nf3 should be legal.");
+        let knight_undo = board.make_move(&action);
+        assert_eq!(board.to_fen(), "rnbqkbnr/pppppppp/8/8/8/5N2/PPPPPPPP/RNBQKB1R w KQkq - 1 1 1");
+
+        // The duck has to be moved to one of the empty squares after a team's chess move.
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert_eq!(moves.len(), 32);
+        assert!(moves.iter().all(|action| matches!(action, Move::Action(action) if action.piece_type == DUCK)));
+        let action = board.decode_action("@e6", NORMAL_MODE).expect("The duck should be able to move to e6.");
+        let duck_undo = board.make_move(&action);
+        assert_eq!(board.to_fen(), "rnbqkbnr/pppppppp/4*3/8/8/5N2/PPPPPPPP/RNBQKB1R b KQkq - 1 1 0");
+        assert_eq!(duck.from_fen(&board.to_fen()).to_fen(), board.to_fen());
+
+        // The duck can't be captured, and blocks the pawn's double step.
+        assert!(board.decode_action("e7e6", NORMAL_MODE).is_none());
+        assert!(board.decode_action("e7e5", NORMAL_MODE).is_none());
+
+        board.undo_move(duck_undo);
+        board.undo_move(knight_undo);
+        assert_eq!(board.state, state);
+        assert_eq!(duck.zobrist.compute(&board), hash);
+    }
+
+    #[test]
+    fn duck_blocks_sliders() {
+        let duck = Duck::create();
+        let mut board = duck.from_fen("4k3/8/8/8/R2*3r/8/8/4K3 w - - 0 1 0");
+
+        assert!(board.decode_action("a4c4", NORMAL_MODE).is_some());
+        assert!(board.decode_action("a4e4", NORMAL_MODE).is_none());
+        assert!(board.decode_action("a4h4", NORMAL_MODE).is_none());
+    }
+
+    #[test]
+    fn capturing_the_king_wins() {
+        let duck = Duck::create();
+        let mut board = duck.from_fen("4k3/8/8/8/8/8/8/3QK3 w - - 0 1 0");
+
+        // There's no check, so kings can be left attacked, and then captured.
+        let action = board.decode_action("d1d8", NORMAL_MODE).expect("Qd8 should be legal.");
+        board.make_move(&action);
+        let action = board.decode_action("@a1", NORMAL_MODE).expect("The duck should be able to move to a1.");
+        board.make_move(&action);
+        assert_eq!(board.generate_legal_moves(NORMAL_MODE).len(), 5);
+
+        let mut board = duck.from_fen("3k4/8/8/8/8/8/8/3QK3 w - - 0 1 0");
+        let action = board.decode_action("d1d8", NORMAL_MODE).expect("Qxd8 should be legal.");
+        board.make_move(&action);
+
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert!(moves.is_empty());
+        assert!(matches!(duck.resolution.resolve(&mut board, &moves), GameResults::Win(0)));
+    }
+
+    #[test]
+    fn stalemate_wins() {
+        let duck = Duck::create();
+        let mut board = duck.from_fen("6bk/5p*p/5P1P/8/8/8/8/4K3 b - - 0 1 0");
+
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert!(moves.is_empty());
+        assert!(matches!(duck.resolution.resolve(&mut board, &moves), GameResults::Win(1)));
+    }
+}
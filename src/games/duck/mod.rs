@@ -0,0 +1,13 @@
+mod game;
+mod controller;
+mod resolution;
+mod process;
+mod zobrist;
+mod suite;
+pub mod pieces;
+
+pub use game::*;
+pub use controller::*;
+pub use resolution::*;
+pub use process::*;
+pub use zobrist::*;
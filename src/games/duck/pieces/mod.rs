@@ -0,0 +1,66 @@
+use crate::{
+    bitboard::BitBoard,
+    board::{
+        actions::{Action, HistoryMove, HistoryState, HistoryUpdate, Move, PreviousBoard},
+        pieces::{Piece, PieceSymbol},
+        update_turns, Board, PieceType,
+    },
+};
+
+/// The duck belongs to neither team, so it's stored in `BoardState::gaps` (and `all_pieces`, so it blocks sliding pieces) instead of a team's pieces.
+/// It can move to any empty square, or be placed there if it isn't on the board yet.
+#[derive(Debug)] pub struct DuckPiece;
+
+impl<const T: usize> Piece<T> for DuckPiece {
+    fn get_piece_symbol(&self) -> PieceSymbol {
+        PieceSymbol::Char('*')
+    }
+
+    fn can_lookup(&self) -> bool {
+        false
+    }
+
+    #[allow(unused_variables)]
+    fn get_moves(
+        &self,
+        board: &Board<T>,
+        from: BitBoard<T>,
+        piece_type: PieceType,
+        team: u16,
+        mode: u16,
+    ) -> BitBoard<T> {
+        !(board.state.all_pieces | board.state.gaps)
+    }
+
+    fn make_move(&self, board: &mut Board<T>, action: &Action) -> Option<HistoryMove<T>> {
+        let from = match action.from {
+            Some(from) => BitBoard::from_lsb(from),
+            None => BitBoard::new(),
+        };
+        let to = BitBoard::from_lsb(action.to);
+
+        let turn_info = board.get_turn_info();
+        update_turns(&mut board.state, board.game, &Move::Action(*action));
+
+        let history_move = HistoryMove {
+            action: Move::Action(*action),
+            first_history_move: board.retrieve_first_history_move(Move::Action(*action)),
+            turn_info,
+            state: HistoryState::Any {
+                all_pieces: PreviousBoard(board.state.all_pieces),
+                first_move: PreviousBoard(board.state.first_move),
+                updates: vec![HistoryUpdate::Gaps(PreviousBoard(board.state.gaps))],
+            },
+        };
+
+        board.state.gaps = (board.state.gaps & !from) | to;
+        board.state.all_pieces = (board.state.all_pieces & !from) | to;
+
+        Some(history_move)
+    }
+
+    /// The duck is placed onto the board for the first time the same way it's moved.
+    fn make_drop_move(&self, board: &mut Board<T>, action: &Action) -> Option<HistoryMove<T>> {
+        self.make_move(board, action)
+    }
+}
@@ -0,0 +1,12 @@
+use crate::{board::{fen::PostProcess, Board}, games::chess::ChessPostProcess};
+
+#[derive(Debug)]
+pub struct DuckPostProcess;
+impl<const T: usize> PostProcess<T> for DuckPostProcess {
+    fn apply(&self, board: &mut Board<T>) {
+        ChessPostProcess.apply(board);
+
+        // The duck is written as a gap, but it still has to block sliding pieces.
+        board.state.all_pieces |= board.state.gaps;
+    }
+}
@@ -0,0 +1,22 @@
+use crate::{
+    board::{game::{Resolution, GameResults}, Board, actions::Move},
+    games::chess::{get_kingless_team, ChessResolution},
+};
+
+#[derive(Debug)]
+pub struct DuckResolution;
+
+impl<const T: usize> Resolution<T> for DuckResolution {
+    fn resolve(&self, board: &mut Board<T>, legal_moves: &Vec<Move>) -> GameResults {
+        if let Some(team) = get_kingless_team(board) {
+            return GameResults::Win(board.get_next_team(team));
+        }
+
+        // A team with no moves has been stalemated, which wins in Duck chess.
+        if legal_moves.is_empty() {
+            return GameResults::Win(board.state.moving_team);
+        }
+
+        ChessResolution::<T>.resolve(board, legal_moves)
+    }
+}
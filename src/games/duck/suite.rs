@@ -0,0 +1,15 @@
+use crate::board::{tests::run_tests};
+use super::Duck;
+
+const TEST_STR: &str = r#"rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1 0 ;D1 20 ;D2 640 ;D3 12240 ;D4 379440
+r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1 0 ;D1 48 ;D2 1544 ;D3 63189
+r3k2r/8/8/8/3*4/8/8/R3K2R w KQkq - 0 1 1 ;D1 57 ;D2 1409 ;D3 80415 ;D4 1816264
+rnbqkbnr/ppp1pppp/8/8/3pP3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 3 0 ;D1 30 ;D2 961 ;D3 26279
+rnbqkbnr/ppp1pppp/8/8/3pP3/4*3/PPPP1PPP/RNBQKBNR b KQkq e3 0 3 0 ;D1 29 ;D2 899 ;D3 24472 ;D4 758987
+4k3/1P6/8/8/8/8/6p1/4K2R w K - 0 1 0 ;D1 19 ;D2 1121 ;D3 12286 ;D4 701496
+8/8/8/3k4/3K4/8/8/8 w - - 0 1 0 ;D1 8 ;D2 434 ;D3 3420 ;D4 193492"#;
+
+#[test]
+fn duck_perft_suite() {
+    run_tests("Duck Chess", Duck::create(), TEST_STR);
+}
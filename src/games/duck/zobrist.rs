@@ -0,0 +1,19 @@
+use crate::board::{game::ZobristController, zobrist::ZobristHashTable, Board};
+
+use super::DUCK_TURN;
+
+/// The same position can come up before or after the duck is moved, so the duck's turn has its own hash.
+#[derive(Debug)]
+pub struct DuckZobrist;
+
+impl<const T: usize> ZobristController<T> for DuckZobrist {
+    fn apply(&self, hash: &mut u64, zobrist: &ZobristHashTable<T>, board: &Board<T>) {
+        if board.state.current_turn == DUCK_TURN {
+            *hash ^= zobrist.table[zobrist.base_len];
+        }
+    }
+
+    fn get_extra_hashes(&self) -> usize {
+        1
+    }
+}
@@ -0,0 +1,57 @@
+use crate::{
+    bitboard::BitBoard,
+    board::{
+        actions::{Move, TheoreticalMove, TurnUpdate},
+        game::{Game, MoveController},
+        Board, BoardState,
+    },
+    games::chess::{get_kingless_team, ChessMoveController},
+};
+
+/// Fog-of-war (or dark chess) is played with the rules of chess, except there's no check.
+/// Teams can't see where their opponent's pieces are, so they're free to leave their king attacked, and it can be captured to win.
+#[derive(Debug)]
+pub struct FogOfWarMoveController;
+
+impl<const T: usize> MoveController<T> for FogOfWarMoveController {
+    #[allow(unused_variables)]
+    fn transform_moves(&self, board: &mut Board<T>, mode: u16, actions: Vec<Move>) -> Vec<Move> {
+        // Without checkmate, the game only ends once a king is captured.
+        if get_kingless_team(board).is_some() {
+            return vec![];
+        }
+
+        actions
+    }
+
+    #[allow(unused_variables)]
+    fn is_legal(&self, board: &mut Board<T>, action: &Move) -> bool {
+        !matches!(action, Move::Pass)
+    }
+
+    fn use_pseudolegal(&self) -> bool {
+        true
+    }
+
+    /// Kings can castle out of, through and into attacked squares, since they can't be put in check.
+    #[allow(unused_variables)]
+    fn get_castling_attacks(&self, board: &Board<T>, team: u16) -> BitBoard<T> {
+        BitBoard::new()
+    }
+
+    fn encode_action(&self, board: &Board<T>, action: &Move) -> Vec<String> {
+        ChessMoveController::<T>.encode_action(board, action)
+    }
+
+    fn update(&self, action: &Move, state: &BoardState<T>, game: &Game<T>) -> TurnUpdate {
+        ChessMoveController::<T>.update(action, state, game)
+    }
+
+    fn get_theoretical_moves(&self, board: &Board<T>) -> Vec<TheoreticalMove> {
+        ChessMoveController::<T>.get_theoretical_moves(board)
+    }
+
+    fn get_max_available_moves(&self) -> u32 {
+        ChessMoveController::<T>.get_max_available_moves()
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{
+    board::game::Game,
+    games::chess::Chess,
+};
+
+use super::{FogOfWarMoveController, FogOfWarResolution};
+
+pub struct FogOfWar;
+
+impl FogOfWar {
+    /// Fog-of-war keeps the position of chess, but each team should only be shown what `Board::observe` lets them see.
+    pub fn create() -> Game<1> {
+        let mut game = Chess::create();
+        game.name = String::from("Fog of War");
+        game.controller = Box::new(FogOfWarMoveController);
+        game.resolution = Box::new(FogOfWarResolution);
+        game
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::game::{GameResults, NORMAL_MODE};
+
+    use super::FogOfWar;
+
+    #[test]
+    fn observe_hides_pieces() {
+        let fog_of_war = FogOfWar::create();
+        let board = fog_of_war.default();
+
+        assert_eq!(board.observe(0).to_fen(), "8/8/8/8/8/8/PPPPPPPP/RNBQKBNR w KQ - 0 1");
+        assert_eq!(board.observe(1).to_fen(), "rnbqkbnr/pppppppp/8/8/8/8/8/8 w kq - 0 1");
+
+        // Pawns can see the pieces they're able to capture.
+        let board = fog_of_war.from_fen("rnbqkbnr/ppp1pppp/8/3p4/4P3/8/PPPP1PPP/RNBQKBNR w KQkq d6 0 2");
+        assert_eq!(board.observe(0).to_fen(), "8/8/8/3p4/4P3/8/PPPP1PPP/RNBQKBNR w KQ d6 0 2");
+    }
+
+    #[test]
+    fn observe_hides_unseen_moves() {
+        let fog_of_war = FogOfWar::create();
+        let mut board = fog_of_war.from_fen("4k3/3p4/8/4P3/8/8/8/4K3 b - - 0 1");
+
+        let action = board.decode_action("d7d5", NORMAL_MODE).expect("d7d5 should be legal.");
+        board.make_move(&action);
+        assert_eq!(board.to_fen(), "4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 2");
+
+        // White can't see the pawn that stepped past it, so it can't know it's able to capture it en passant.
+        assert_eq!(board.observe(0).to_fen(), "8/8/8/4P3/8/8/8/4K3 w - - 0 2");
+        assert_eq!(board.observe(1).to_fen(), "4k3/8/8/3p4/8/8/8/8 w - d6 0 2");
+    }
+
+    #[test]
+    fn king_capture_wins() {
+        let fog_of_war = FogOfWar::create();
+        let mut board = fog_of_war.from_fen("4k3/p7/8/8/8/8/8/4R1K1 b - - 0 1");
+
+        // The king can stay in check, since there's no check.
+        let action = board.decode_action("e8e7", NORMAL_MODE).expect("e8e7 should be legal.");
+        assert!(fog_of_war.controller.is_legal(&mut board, &action));
+        board.make_move(&action);
+
+        let action = board.decode_action("e1e7", NORMAL_MODE).expect("e1xe7 should be legal.");
+        board.make_move(&action);
+
+        // Black still has a pawn, but the game is over once its king is captured.
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert!(moves.is_empty());
+        assert!(matches!(fog_of_war.resolution.resolve(&mut board, &moves), GameResults::Win(0)));
+    }
+}
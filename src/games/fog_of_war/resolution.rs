@@ -0,0 +1,23 @@
+use crate::{
+    board::{game::{Resolution, GameResults}, Board, actions::Move},
+    games::chess::get_kingless_team,
+};
+
+#[derive(Debug)]
+pub struct FogOfWarResolution;
+
+impl<const T: usize> Resolution<T> for FogOfWarResolution {
+    fn resolve(&self, board: &mut Board<T>, legal_moves: &Vec<Move>) -> GameResults {
+        // Whoever captures the other team's king wins.
+        if let Some(team) = get_kingless_team(board) {
+            return GameResults::Win(board.get_next_team(team));
+        }
+
+        // There's no checkmate, so a team can only run out of moves by being stalemated.
+        if legal_moves.is_empty() || board.state.sub_moves == 100 {
+            GameResults::Draw
+        } else {
+            GameResults::Ongoing
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use crate::board::{tests::run_tests};
+use super::FogOfWar;
+
+const TEST_STR: &str = r#"rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1 ;D1 20 ;D2 400 ;D3 8902 ;D4 197742
+r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1 ;D1 48 ;D2 2049 ;D3 98903
+r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1 ;D1 26 ;D2 613 ;D3 15950
+4k3/8/8/8/8/8/8/4R1K1 b - - 0 1 ;D1 5 ;D2 84 ;D3 562 ;D4 9893
+4k3/p7/8/8/8/8/8/4R1K1 b - - 0 1 ;D1 7 ;D2 118 ;D3 920 ;D4 16212"#;
+
+#[test]
+fn fog_of_war_perft_suite() {
+    run_tests("Fog of War", FogOfWar::create(), TEST_STR);
+}
@@ -0,0 +1,78 @@
+use crate::board::{fen::{FenArgument, FenDecodeError}, Board};
+
+use super::ELIMINATED_COUNTER;
+
+/// The letter of each team in FENs: red, blue, yellow and green.
+pub const TEAM_CHARS: [char; 4] = ['r', 'b', 'y', 'g'];
+
+/// The teams that have been eliminated, such as `by`, or `-` if every team is still playing.
+#[derive(Debug)]
+pub struct FourPlayerEliminated;
+
+impl<const T: usize> FenArgument<T> for FourPlayerEliminated {
+    fn encode(&self, board: &Board<T>) -> String {
+        let eliminated = board.state.counters[ELIMINATED_COUNTER];
+        let teams = TEAM_CHARS
+            .iter()
+            .enumerate()
+            .filter(|(team, _)| eliminated & (1 << team) != 0)
+            .map(|(_, char)| *char)
+            .collect::<String>();
+
+        if teams.is_empty() {
+            "-".to_string()
+        } else {
+            teams
+        }
+    }
+
+    fn decode(&self, board: &mut Board<T>, arg: &str) -> Result<(), FenDecodeError> {
+        let mut eliminated = 0;
+        if arg != "-" {
+            for char in arg.chars() {
+                let team = TEAM_CHARS.iter().position(|team| *team == char).ok_or_else(|| {
+                    FenDecodeError::InvalidArgument(format!("'{char}' is not a valid team to be eliminated."))
+                })?;
+                eliminated |= 1 << team;
+            }
+        }
+
+        board.state.counters[ELIMINATED_COUNTER] = eliminated;
+        Ok(())
+    }
+}
+
+/// The points each team has scored, such as `3,0,9,0`.
+#[derive(Debug)]
+pub struct FourPlayerPoints;
+
+impl<const T: usize> FenArgument<T> for FourPlayerPoints {
+    fn encode(&self, board: &Board<T>) -> String {
+        board.state.counters[0..ELIMINATED_COUNTER]
+            .iter()
+            .map(|points| points.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    fn decode(&self, board: &mut Board<T>, arg: &str) -> Result<(), FenDecodeError> {
+        let points = arg
+            .split(',')
+            .map(|points| points.parse::<u16>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| {
+                FenDecodeError::InvalidArgument(format!(
+                    "'{arg}' is not a valid amount of points, as they aren't all positive integers."
+                ))
+            })?;
+
+        if points.len() != ELIMINATED_COUNTER {
+            return Err(FenDecodeError::InvalidArgument(format!(
+                "'{arg}' should have the amount of points for each of the {ELIMINATED_COUNTER} teams."
+            )));
+        }
+
+        board.state.counters[0..ELIMINATED_COUNTER].copy_from_slice(&points);
+        Ok(())
+    }
+}
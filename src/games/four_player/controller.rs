@@ -0,0 +1,279 @@
+use crate::{
+    bitboard::BitBoard,
+    board::{
+        actions::{
+            Action, CounterUpdate, HistoryMove, HistoryState, HistoryUpdate, IndexedPreviousBoard, Move,
+            PreviousBoard, PreviousCounter, TheoreticalMove, TurnUpdate,
+        },
+        game::{Game, get_theoretical_moves_bound, MoveController, ATTACKS_MODE, NORMAL_MODE},
+        update_turns, Board, BoardState, PieceType,
+    },
+    games::chess::{
+        pieces::{EN_PASSANT_MOVE, KING_PIECE_TYPE, PAWN_PIECE_TYPE},
+        ChessMoveController,
+    },
+};
+
+/// Whether the four teams play for themselves, or red and yellow play against blue and green.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FourPlayerMode {
+    /// Every team plays for itself, scoring points for captures and checkmates. Checkmated teams are eliminated, and the game goes on until one team is left.
+    FreeForAll,
+    /// Teams sitting across from each other (red and yellow, blue and green) play together, and the game ends as soon as a team is checkmated.
+    Teams,
+}
+
+impl FourPlayerMode {
+    pub fn are_opponents(&self, team: u16, other_team: u16) -> bool {
+        match self {
+            FourPlayerMode::FreeForAll => team != other_team,
+            FourPlayerMode::Teams => team % 2 != other_team % 2,
+        }
+    }
+}
+
+/// `BoardState::counters` holds each team's points, followed by the teams that have been eliminated (as bits, starting from the first team.)
+pub const ELIMINATED_COUNTER: usize = 4;
+
+/// A team with no legal moves is eliminated by a "move" of its king onto its own square, with this move type.
+/// Castling and en passant use `1`, so this can't be mistaken for either.
+pub const ELIMINATION_MOVE: u16 = 2;
+
+/// The points a team scores for checkmating (or being stalemated) in free-for-all games.
+pub const ELIMINATION_POINTS: u16 = 20;
+
+/// The points a team scores for capturing each chess piece in free-for-all games, from pawns to kings.
+pub const PIECE_POINTS: [u16; 6] = [1, 3, 5, 5, 9, 0];
+
+pub fn is_eliminated<const T: usize>(board: &Board<T>, team: u16) -> bool {
+    board.state.counters[ELIMINATED_COUNTER] & (1 << team) != 0
+}
+
+/// Gets the teams that haven't been eliminated.
+pub fn get_active_teams<const T: usize>(board: &Board<T>) -> Vec<u16> {
+    (0..board.game.teams).filter(|team| !is_eliminated(board, *team)).collect()
+}
+
+#[derive(Debug)]
+pub struct FourPlayerMoveController {
+    pub mode: FourPlayerMode,
+}
+
+impl FourPlayerMoveController {
+    pub fn is_game_over<const T: usize>(&self, board: &Board<T>) -> bool {
+        self.mode == FourPlayerMode::FreeForAll && get_active_teams(board).len() <= 1
+    }
+
+    /// Finds the opponent giving check to `team`'s king, favoring whoever moved most recently.
+    pub fn get_checking_team<const T: usize>(&self, board: &Board<T>, team: u16) -> Option<u16> {
+        let king_board = board.state.pieces[KING_PIECE_TYPE as usize] & board.state.teams[team as usize];
+        if king_board.is_empty() {
+            return None;
+        }
+
+        let mut attacker = board.get_previous_team(team);
+        while attacker != team {
+            if self.mode.are_opponents(team, attacker) && board.can_move(attacker, king_board, ATTACKS_MODE) {
+                return Some(attacker);
+            }
+            attacker = board.get_previous_team(attacker);
+        }
+        None
+    }
+
+    fn get_elimination_move<const T: usize>(board: &Board<T>, team: u16) -> Move {
+        let king_board = board.state.pieces[KING_PIECE_TYPE as usize] & board.state.teams[team as usize];
+        let king = king_board.bitscan_forward();
+        Move::Action(Action {
+            from: Some(king),
+            to: king,
+            team,
+            piece_type: KING_PIECE_TYPE,
+            info: 0,
+            move_type: ELIMINATION_MOVE,
+        })
+    }
+
+    fn get_captured_piece<const T: usize>(board: &Board<T>, action: &Action) -> Option<PieceType> {
+        if action.piece_type == PAWN_PIECE_TYPE && action.move_type == EN_PASSANT_MOVE {
+            return Some(PAWN_PIECE_TYPE);
+        }
+
+        let to = BitBoard::from_lsb(action.to);
+        if (board.state.all_pieces & !board.state.teams[action.team as usize] & to).is_empty() {
+            return None;
+        }
+
+        (0..board.game.pieces.len())
+            .find(|piece_type| (board.state.pieces[*piece_type] & to).is_set())
+            .map(|piece_type| piece_type as PieceType)
+    }
+
+    /// Eliminates the team, taking all of its pieces off of the board.
+    /// Whoever checkmated the team scores for it, and a stalemated team scores for itself.
+    fn make_elimination_move<const T: usize>(&self, board: &mut Board<T>, action: &Action) -> Option<HistoryMove<T>> {
+        let team = action.team as usize;
+        let scorer = self.get_checking_team(board, action.team).unwrap_or(action.team) as usize;
+
+        let turn_info = board.get_turn_info();
+        update_turns(&mut board.state, board.game, &Move::Action(*action));
+
+        let mut updates = vec![HistoryUpdate::Team(IndexedPreviousBoard(team, board.state.teams[team]))];
+        for piece_type in 0..board.game.pieces.len() {
+            updates.push(HistoryUpdate::Piece(IndexedPreviousBoard(piece_type, board.state.pieces[piece_type])));
+        }
+        updates.push(HistoryUpdate::Counter(PreviousCounter(ELIMINATED_COUNTER, board.state.counters[ELIMINATED_COUNTER])));
+        updates.push(HistoryUpdate::Counter(PreviousCounter(scorer, board.state.counters[scorer])));
+
+        let history_move = HistoryMove {
+            action: Move::Action(*action),
+            first_history_move: board.retrieve_first_history_move(Move::Action(*action)),
+            turn_info,
+            state: HistoryState::Any {
+                all_pieces: PreviousBoard(board.state.all_pieces),
+                first_move: PreviousBoard(board.state.first_move),
+                updates,
+            },
+        };
+
+        let team_board = board.state.teams[team];
+        for piece_type in 0..board.game.pieces.len() {
+            board.state.pieces[piece_type] &= !team_board;
+        }
+        board.state.all_pieces &= !team_board;
+        board.state.first_move &= !team_board;
+        board.state.teams[team] = BitBoard::new();
+
+        board.state.counters[ELIMINATED_COUNTER] |= 1 << team;
+        board.state.counters[scorer] += ELIMINATION_POINTS;
+
+        Some(history_move)
+    }
+}
+
+impl<const T: usize> MoveController<T> for FourPlayerMoveController {
+    fn transform_moves(&self, board: &mut Board<T>, _mode: u16, actions: Vec<Move>) -> Vec<Move> {
+        if self.is_game_over(board) {
+            return vec![];
+        }
+
+        // Eliminated teams keep their turns, but can only pass them.
+        let team = board.state.moving_team;
+        if is_eliminated(board, team) {
+            return vec![Move::Pass];
+        }
+
+        let mut legal_moves = Vec::with_capacity(actions.len());
+        for action in actions {
+            if self.is_legal(board, &action) {
+                legal_moves.push(action);
+            }
+        }
+
+        // In free-for-all games, a team that is checkmated or stalemated is eliminated instead of ending the game.
+        if legal_moves.is_empty() && self.mode == FourPlayerMode::FreeForAll {
+            legal_moves.push(FourPlayerMoveController::get_elimination_move(board, team));
+        }
+
+        legal_moves
+    }
+
+    fn is_legal(&self, board: &mut Board<T>, action: &Move) -> bool {
+        let current_team = board.state.moving_team;
+        match action {
+            Move::Action(action) => {
+                if action.piece_type == KING_PIECE_TYPE && action.move_type == ELIMINATION_MOVE {
+                    let moves = board.generate_moves(NORMAL_MODE);
+                    return !moves.iter().any(|action| self.is_legal(board, action));
+                }
+
+                let to_board = BitBoard::from_lsb(action.to);
+                if (to_board & board.state.pieces[KING_PIECE_TYPE as usize]).is_set() {
+                    return false;
+                }
+
+                // Teammates can't capture each other's pieces.
+                if let Some(team) = board.state.get_team(to_board) {
+                    if !self.mode.are_opponents(current_team, team as u16) {
+                        return false;
+                    }
+                }
+
+                let undo = board.make_move(&Move::Action(*action));
+                let in_check = self.get_checking_team(board, current_team).is_some();
+                board.undo_move(undo);
+                !in_check
+            }
+            Move::Pass => is_eliminated(board, current_team),
+        }
+    }
+
+    fn use_pseudolegal(&self) -> bool {
+        true
+    }
+
+    fn get_counters(&self) -> usize {
+        // Each team's points, and the eliminated teams.
+        5
+    }
+
+    fn make_move(&self, board: &mut Board<T>, action: &Action) -> Option<HistoryMove<T>> {
+        if action.piece_type == KING_PIECE_TYPE && action.move_type == ELIMINATION_MOVE {
+            return self.make_elimination_move(board, action);
+        }
+
+        let captured = match self.mode {
+            FourPlayerMode::FreeForAll => FourPlayerMoveController::get_captured_piece(board, action),
+            FourPlayerMode::Teams => None,
+        };
+
+        let mut history_move = board.game.pieces[action.piece_type as usize].make_move(board, action)?;
+
+        if let Some(points) = captured.and_then(|piece_type| PIECE_POINTS.get(piece_type as usize)) {
+            let team = action.team as usize;
+            history_move.add_updates(vec![HistoryUpdate::Counter(PreviousCounter(team, board.state.counters[team]))]);
+            board.state.counters[team] += points;
+        }
+
+        Some(history_move)
+    }
+
+    fn encode_action(&self, board: &Board<T>, action: &Move) -> Vec<String> {
+        if let Move::Action(action) = action {
+            if action.piece_type == KING_PIECE_TYPE && action.move_type == ELIMINATION_MOVE {
+                return vec!["#".to_string()];
+            }
+        }
+
+        ChessMoveController::<T>.encode_action(board, action)
+    }
+
+    fn decode_action(&self, board: &mut Board<T>, action: &str, mode: u16) -> Option<Move> {
+        // Passing and being eliminated are only added to the legal moves, so they have to be decoded from those.
+        board.generate_legal_moves(mode)
+            .into_iter()
+            .find(|el| self.encode_action(board, el).contains(&action.to_string()))
+    }
+
+    fn update(&self, action: &Move, state: &BoardState<T>, game: &Game<T>) -> TurnUpdate {
+        if let Move::Action(action) = action {
+            if action.piece_type == KING_PIECE_TYPE && action.move_type == ELIMINATION_MOVE {
+                return TurnUpdate {
+                    turns: CounterUpdate::Next,
+                    sub_moves: CounterUpdate::Next,
+                    full_moves: CounterUpdate::Next,
+                };
+            }
+        }
+
+        ChessMoveController::<T>.update(action, state, game)
+    }
+
+    fn get_theoretical_moves(&self, board: &Board<T>) -> Vec<TheoreticalMove> {
+        get_theoretical_moves_bound(board, 4, true)
+    }
+
+    fn get_max_available_moves(&self) -> u32 {
+        400
+    }
+}
@@ -0,0 +1,195 @@
+use crate::{
+    board::{
+        fen::{FenFullMoves, FenOptions, FenState, FenSubMoves, FenTeamArgument},
+        game::{Game, ZobristController},
+        pieces::Piece,
+        zobrist::ZobristHashTable,
+    },
+    games::chess::{
+        pieces::{BishopPiece, KingPiece, KnightPiece, PawnPiece, QueenPiece, RookPiece},
+        ChessEnPassant,
+    },
+};
+
+use super::{
+    get_four_player_regions, FourPlayerEliminated, FourPlayerMode, FourPlayerMoveController, FourPlayerPoints,
+    FourPlayerPostProcess, FourPlayerResolution, FourPlayerZobrist, TEAM_CHARS,
+};
+
+const PAWN: &dyn Piece<2> = &PawnPiece { king_promotion: false, first_move_steps: 2, promotion_pieces: None };
+const KNIGHT: &dyn Piece<2> = &KnightPiece;
+const BISHOP: &dyn Piece<2> = &BishopPiece;
+const ROOK: &dyn Piece<2> = &RookPiece;
+const QUEEN: &dyn Piece<2> = &QueenPiece;
+const KING: &dyn Piece<2> = &KingPiece;
+
+pub struct FourPlayer;
+
+impl FourPlayer {
+    /// Four-player chess is played on a 14x14 board with a 3x3 square cut out of each corner (walls, `*` in FENs), so it needs `Game<2>`.
+    /// Red, blue, yellow and green sit clockwise around the board starting from the bottom, and each team's pawns move away from its own side.
+    /// Pieces are written with their team after them in braces, starting from `{1}` for red.
+    ///
+    /// In free-for-all games, teams score points for captures and checkmates, and checkmated (or stalemated) teams are eliminated.
+    /// In team games, red and yellow play against blue and green, and the first checkmate ends the game.
+    ///
+    /// Castling isn't supported.
+    pub fn create(mode: FourPlayerMode) -> Game<2> {
+        let extra_hashes = ZobristController::<2>::get_extra_hashes(&FourPlayerZobrist);
+
+        Game {
+            teams: 4,
+            turns: vec![1, 1, 1, 1],
+            rows: 14,
+            cols: 14,
+            squares: 196,
+            saved_last_moves: 1,
+            regions: get_four_player_regions(mode),
+            zobrist_controller: Box::new(FourPlayerZobrist),
+            zobrist: ZobristHashTable::<2>::generate(196, 4, 6, extra_hashes, || fastrand::u64(0..u64::MAX)),
+            name: String::from(match mode {
+                FourPlayerMode::FreeForAll => "Four-player chess",
+                FourPlayerMode::Teams => "Four-player teams chess",
+            }),
+            pieces: vec![PAWN, KNIGHT, BISHOP, ROOK, QUEEN, KING],
+            controller: Box::new(FourPlayerMoveController { mode }),
+            resolution: Box::new(FourPlayerResolution { mode }),
+            fen_options: FenOptions {
+                state: FenState { first_moves: false, gaps: '*', reserves: false },
+                args: vec![
+                    (
+                        "team to move".to_string(),
+                        Box::new(FenTeamArgument::Teams(TEAM_CHARS.to_vec())),
+                    ),
+                    ("eliminated teams".to_string(), Box::new(FourPlayerEliminated)),
+                    ("points".to_string(), Box::new(FourPlayerPoints)),
+                    ("en passant".to_string(), Box::new(ChessEnPassant)),
+                    ("half moves".to_string(), Box::new(FenSubMoves)),
+                    ("full moves".to_string(), Box::new(FenFullMoves)),
+                ],
+                post_process: Box::new(FourPlayerPostProcess),
+                default_fen: String::from(DEFAULT_FEN)
+            }
+        }
+    }
+}
+
+const DEFAULT_FEN: &str = "***r{3}n{3}b{3}k{3}q{3}b{3}n{3}r{3}***/***p{3}p{3}p{3}p{3}p{3}p{3}p{3}p{3}***/***8***/r{2}p{2}10p{4}r{4}/n{2}p{2}10p{4}n{4}/b{2}p{2}10p{4}b{4}/q{2}p{2}10p{4}k{4}/k{2}p{2}10p{4}q{4}/b{2}p{2}10p{4}b{4}/n{2}p{2}10p{4}n{4}/r{2}p{2}10p{4}r{4}/***8***/***p{1}p{1}p{1}p{1}p{1}p{1}p{1}p{1}***/***r{1}n{1}b{1}q{1}k{1}b{1}n{1}r{1}*** r - 0,0,0,0 - 0 1";
+
+#[cfg(test)]
+mod tests {
+    use crate::board::game::{GameResults, NORMAL_MODE};
+
+    use super::{FourPlayer, FourPlayerMode, DEFAULT_FEN};
+
+    fn encode_moves(mode: FourPlayerMode, fen: &str) -> Vec<String> {
+        let four_player = FourPlayer::create(mode);
+        let mut board = four_player.from_fen(fen);
+        board
+            .generate_legal_moves(NORMAL_MODE)
+            .iter()
+            .map(|action| board.encode_action(action))
+            .collect()
+    }
+
+    #[test]
+    fn fen() {
+        let four_player = FourPlayer::create(FourPlayerMode::FreeForAll);
+        assert_eq!(four_player.default().to_fen(), DEFAULT_FEN);
+
+        let fen = "***3k{3}4***/***8***/***8***/14/9q{3}4/14/11r{4}2/13k{4}/14/12p{4}1/5n{1}8/***8***/***5p{1}2***/***4k{1}3*** y b 3,20,0,5 - 0 1";
+        assert_eq!(four_player.from_fen(fen).to_fen(), fen);
+    }
+
+    #[test]
+    fn pawn_directions() {
+        // Each team's pawns move away from its own side of the board, and can step twice from their starting rank (or file.)
+        let fen = "***3k{3}4***/***1p{3}6***/***8***/14/14/14/k{2}12k{4}/14/14/1p{2}10p{4}1/14/***8***/***1p{1}6***/***4k{1}3***";
+        for (team, pawn_moves) in [
+            ("r", ["e2e3", "e2e4"]),
+            ("b", ["b5c5", "b5d5"]),
+            ("y", ["e13e12", "e13e11"]),
+            ("g", ["m5l5", "m5k5"]),
+        ] {
+            let moves = encode_moves(FourPlayerMode::FreeForAll, &format!("{fen} {team} - 0,0,0,0 - 0 1"));
+            for pawn_move in pawn_moves {
+                assert!(moves.contains(&pawn_move.to_string()), "{pawn_move} should be legal.");
+            }
+        }
+    }
+
+    #[test]
+    fn en_passant_promotion() {
+        // In team games, red promotes on the eleventh rank, which green's pawns step over from their starting file.
+        let four_player = FourPlayer::create(FourPlayerMode::Teams);
+        let mut board = four_player.from_fen("***3k{3}4***/***8***/***8***/12p{4}1/10p{1}3/5r{2}8/13k{4}/k{2}13/14/14/14/***8***/***8***/***4k{1}3*** g - 0,0,0,0 - 0 1");
+
+        let action = board.decode_action("m11k11", NORMAL_MODE).expect("A double pawn step should be legal.");
+        board.make_move(&action);
+        assert_eq!(board.to_fen(), "***3k{3}4***/***8***/***8***/10p{4}3/10p{1}3/5r{2}8/13k{4}/k{2}13/14/14/14/***8***/***8***/***4k{1}3*** r - 0,0,0,0 l11 0 2");
+
+        let action = board.decode_action("k10l11q", NORMAL_MODE).expect("Capturing en passant onto the promotion rank should be legal.");
+        board.make_move(&action);
+        assert_eq!(board.to_fen(), "***3k{3}4***/***8***/***8***/11q{1}2/14/5r{2}8/13k{4}/k{2}13/14/14/14/***8***/***8***/***4k{1}3*** b - 0,0,0,0 - 0 2");
+    }
+
+    #[test]
+    fn elimination() {
+        let four_player = FourPlayer::create(FourPlayerMode::FreeForAll);
+        let fen = "***r{3}2k{3}4***/***8***/***4p{3}3***/14/4r{4}9/11p{4}2/k{2}13/13k{4}/14/2p{2}2q{2}8/14/***8***/***7p{1}***/***k{1}7*** r - 0,0,0,0 - 0 1";
+        let mut board = four_player.from_fen(fen);
+        let state = board.state.clone();
+        let hash = four_player.zobrist.compute(&board);
+
+        // Red is checkmated by yellow's rook, so its only move is to be eliminated, which scores yellow twenty points.
+        assert_eq!(encode_moves(FourPlayerMode::FreeForAll, fen), vec!["#"]);
+        let action = board.decode_action("#", NORMAL_MODE).expect("Red should be eliminated.");
+        let undo = board.make_move(&action);
+        assert_eq!(board.to_fen(), "***r{3}2k{3}4***/***8***/***4p{3}3***/14/4r{4}9/11p{4}2/k{2}13/13k{4}/14/2p{2}2q{2}8/14/***8***/***8***/***8*** b r 0,0,20,0 - 1 1");
+        assert_ne!(four_player.zobrist.compute(&board), hash);
+
+        board.undo_move(undo);
+        assert_eq!(board.state, state);
+        assert_eq!(four_player.zobrist.compute(&board), hash);
+
+        // Eliminated teams can only pass their turns.
+        let moves = encode_moves(FourPlayerMode::FreeForAll, "***3k{3}4***/***8***/***8***/14/14/14/k{2}12k{4}/14/14/14/14/***8***/***8***/***8*** r r 0,0,20,0 - 0 1");
+        assert_eq!(moves, vec!["0000"]);
+    }
+
+    #[test]
+    fn free_for_all_points() {
+        let four_player = FourPlayer::create(FourPlayerMode::FreeForAll);
+        let mut board = four_player.from_fen("***3k{3}4***/***8***/***8***/14/14/14/k{2}12k{4}/14/14/14/14/***8***/***8***/***4k{1}3*** r - 0,0,0,0 - 0 1");
+        let action = board.decode_action("h1h2", NORMAL_MODE).expect("The king should be able to move.");
+        board.make_move(&action);
+        assert_eq!(board.to_fen(), "***3k{3}4***/***8***/***8***/14/14/14/k{2}12k{4}/14/14/14/14/***8***/***4k{1}3***/***8*** b - 0,0,0,0 - 1 1");
+
+        // Captures score points for the capturing team.
+        let mut board = four_player.from_fen("***3k{3}4***/***8***/***8***/14/14/14/k{2}12k{4}/14/14/7q{2}6/14/***8***/***4r{1}3***/***4k{1}3*** r - 0,0,0,0 - 0 1");
+        let action = board.decode_action("h2h5", NORMAL_MODE).expect("The rook should be able to capture the queen.");
+        board.make_move(&action);
+        assert_eq!(board.to_fen(), "***3k{3}4***/***8***/***8***/14/14/14/k{2}12k{4}/14/14/7r{1}6/14/***8***/***8***/***4k{1}3*** b - 9,0,0,0 - 0 1");
+
+        // Once only one team is left, the team with the most points wins, even if it was eliminated.
+        let mut board = four_player.from_fen("***8***/***8***/***8***/14/14/14/13k{4}/14/14/14/14/***8***/***8***/***8*** g rby 10,20,5,15 - 0 1");
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert!(moves.is_empty());
+        assert!(matches!(four_player.resolution.resolve(&mut board, &moves), GameResults::Win(1)));
+    }
+
+    #[test]
+    fn teams() {
+        // Teammates can't capture each other's pieces.
+        let moves = encode_moves(FourPlayerMode::Teams, "***3k{3}4***/***8***/***8***/14/14/7n{3}6/k{2}12k{4}/7q{1}6/14/14/14/***8***/***8***/***4k{1}3*** r - 0,0,0,0 - 0 1");
+        assert!(!moves.contains(&"h7h9".to_string()));
+        assert!(moves.contains(&"h7h8".to_string()));
+
+        // The first checkmate ends the game, and the checkmating team (along with its partner) wins.
+        let four_player = FourPlayer::create(FourPlayerMode::Teams);
+        let mut board = four_player.from_fen("***3k{3}4***/***8***/***4p{3}3***/14/3r{4}r{2}9/11p{4}2/k{2}13/13k{4}/14/5q{2}8/14/***8***/***7p{1}***/***k{1}7*** r - 0,0,0,0 - 0 1");
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert!(moves.is_empty());
+        assert!(matches!(four_player.resolution.resolve(&mut board, &moves), GameResults::Win(3)));
+    }
+}
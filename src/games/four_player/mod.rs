@@ -0,0 +1,16 @@
+mod game;
+mod args;
+mod controller;
+mod process;
+mod regions;
+mod resolution;
+mod zobrist;
+mod suite;
+
+pub use game::*;
+pub use args::*;
+pub use controller::*;
+pub use process::*;
+pub use regions::*;
+pub use resolution::*;
+pub use zobrist::*;
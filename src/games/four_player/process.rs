@@ -0,0 +1,15 @@
+use crate::{board::{fen::PostProcess, Board}, games::chess::{pieces::PAWN_PIECE_TYPE, ChessPostProcess}};
+
+#[derive(Debug)]
+pub struct FourPlayerPostProcess;
+impl<const T: usize> PostProcess<T> for FourPlayerPostProcess {
+    fn apply(&self, board: &mut Board<T>) {
+        PostProcess::<T>::apply(&ChessPostProcess, board);
+
+        // Castling isn't supported, so only pawns keep track of their first move.
+        board.state.first_move &= board.state.pieces[PAWN_PIECE_TYPE as usize];
+
+        // The cut-out corners block sliding pieces, just like pieces would.
+        board.state.all_pieces |= board.state.gaps;
+    }
+}
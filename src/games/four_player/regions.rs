@@ -0,0 +1,36 @@
+use crate::{
+    board::{regions::{get_rectangle, Region}, Cols, Rows},
+    games::chess::pieces::{BACK_RANK_REGION, PROMOTION_REGION},
+};
+
+use super::FourPlayerMode;
+
+const ROWS: Rows = 14;
+const COLS: Cols = 14;
+
+/// Gets the regions of the four-player chess board, in team order (red, blue, yellow and then green.)
+/// Each team's back rank is on its own side of the board, so blue and green's back ranks are files.
+///
+/// Pawns promote on the eighth rank from their team's side in free-for-all games (the middle of the board),
+/// and on the eleventh rank in team games, so that they have to reach the opponents' side first.
+pub fn get_four_player_regions<const T: usize>(mode: FourPlayerMode) -> Vec<Region<T>> {
+    let promotion = match mode {
+        FourPlayerMode::FreeForAll => 7,
+        FourPlayerMode::Teams => 10,
+    };
+
+    vec![
+        Region::new(BACK_RANK_REGION, vec![
+            get_rectangle(ROWS - 1..ROWS, 3..COLS - 3, COLS),
+            get_rectangle(3..ROWS - 3, 0..1, COLS),
+            get_rectangle(0..1, 3..COLS - 3, COLS),
+            get_rectangle(3..ROWS - 3, COLS - 1..COLS, COLS),
+        ]),
+        Region::new(PROMOTION_REGION, vec![
+            get_rectangle(ROWS - 1 - promotion..ROWS - promotion, 0..COLS, COLS),
+            get_rectangle(0..ROWS, promotion..promotion + 1, COLS),
+            get_rectangle(promotion..promotion + 1, 0..COLS, COLS),
+            get_rectangle(0..ROWS, COLS - 1 - promotion..COLS - promotion, COLS),
+        ]),
+    ]
+}
@@ -0,0 +1,44 @@
+use crate::board::{game::{Resolution, GameResults}, Board, actions::Move};
+
+use super::{FourPlayerMode, FourPlayerMoveController, ELIMINATED_COUNTER};
+
+/// Games are drawn after fifty moves from each team without a capture or a pawn move.
+pub const DRAW_SUB_MOVES: u16 = 200;
+
+#[derive(Debug)]
+pub struct FourPlayerResolution {
+    pub mode: FourPlayerMode,
+}
+
+impl<const T: usize> Resolution<T> for FourPlayerResolution {
+    fn resolve(&self, board: &mut Board<T>, legal_moves: &Vec<Move>) -> GameResults {
+        if legal_moves.is_empty() {
+            return match self.mode {
+                FourPlayerMode::FreeForAll => {
+                    // Once one team is left, the team with the most points wins (which isn't always the last team standing.)
+                    let points = &board.state.counters[0..ELIMINATED_COUNTER];
+                    let best = points.iter().max().copied().unwrap_or(0);
+                    let mut winners = (0..points.len()).filter(|team| points[*team] == best);
+                    match (winners.next(), winners.next()) {
+                        (Some(team), None) => GameResults::Win(team as u16),
+                        _ => GameResults::Draw,
+                    }
+                }
+                FourPlayerMode::Teams => {
+                    // The checkmating team's partner (two teams along) wins alongside it.
+                    let controller = FourPlayerMoveController { mode: self.mode };
+                    match controller.get_checking_team(board, board.state.moving_team) {
+                        Some(team) => GameResults::Win(team),
+                        None => GameResults::Draw,
+                    }
+                }
+            };
+        }
+
+        if board.state.sub_moves >= DRAW_SUB_MOVES {
+            GameResults::Draw
+        } else {
+            GameResults::Ongoing
+        }
+    }
+}
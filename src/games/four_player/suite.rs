@@ -0,0 +1,21 @@
+use crate::board::{tests::run_tests};
+use super::{FourPlayer, FourPlayerMode};
+
+const TEST_STR: &str = r#"***r{3}n{3}b{3}k{3}q{3}b{3}n{3}r{3}***/***p{3}p{3}p{3}p{3}p{3}p{3}p{3}p{3}***/***8***/r{2}p{2}10p{4}r{4}/n{2}p{2}10p{4}n{4}/b{2}p{2}10p{4}b{4}/q{2}p{2}10p{4}k{4}/k{2}p{2}10p{4}q{4}/b{2}p{2}10p{4}b{4}/n{2}p{2}10p{4}n{4}/r{2}p{2}10p{4}r{4}/***8***/***p{1}p{1}p{1}p{1}p{1}p{1}p{1}p{1}***/***r{1}n{1}b{1}q{1}k{1}b{1}n{1}r{1}*** r - 0,0,0,0 - 0 1 ;D1 20 ;D2 395 ;D3 7800 ;D4 152050
+***3k{3}4***/***2p{3}3p{3}1***/***6b{3}1***/2r{2}7p{4}3/7p{4}6/12p{4}1/k{2}3p{3}9/3p{1}9k{4}/6p{2}4n{4}2/1p{2}12/3p{2}6q{1}3/***8***/***1p{1}6***/***4k{1}3*** r - 0,0,0,0 - 0 1 ;D1 46 ;D2 1186 ;D3 21010
+***r{3}2k{3}4***/***8***/***4p{3}3***/14/4r{4}9/11p{4}2/k{2}13/13k{4}/14/2p{2}2q{2}8/14/***8***/***7p{1}***/***k{1}7*** r - 0,0,0,0 - 0 1 ;D1 1 ;D2 46 ;D3 790 ;D4 20043
+***3k{3}4***/***1p{3}6***/***8***/14/9q{3}4/14/11r{4}2/13k{4}/14/12p{4}1/5n{1}8/***8***/***5p{1}2***/***4k{1}3*** y b 3,20,0,5 - 0 1 ;D1 46 ;D2 1056 ;D3 13168"#;
+
+const TEAMS_TEST_STR: &str = r#"***r{3}n{3}b{3}k{3}q{3}b{3}n{3}r{3}***/***p{3}p{3}p{3}p{3}p{3}p{3}p{3}p{3}***/***8***/r{2}p{2}10p{4}r{4}/n{2}p{2}10p{4}n{4}/b{2}p{2}10p{4}b{4}/q{2}p{2}10p{4}k{4}/k{2}p{2}10p{4}q{4}/b{2}p{2}10p{4}b{4}/n{2}p{2}10p{4}n{4}/r{2}p{2}10p{4}r{4}/***8***/***p{1}p{1}p{1}p{1}p{1}p{1}p{1}p{1}***/***r{1}n{1}b{1}q{1}k{1}b{1}n{1}r{1}*** r - 0,0,0,0 - 0 1 ;D1 20 ;D2 395 ;D3 7800
+***3k{3}4***/***5p{3}2***/***8***/2r{2}6p{4}4/5p{1}8/4p{4}2n{3}6/12p{4}k{4}/k{2}2p{3}3q{1}2p{1}3/1p{2}7p{2}4/6p{3}7/3p{2}7b{4}2/***8***/***1p{1}6***/***4k{1}3*** r - 0,0,0,0 - 0 1 ;D1 37 ;D2 1072 ;D3 19872
+***3k{3}4***/***8***/***8***/12p{4}1/10p{1}3/5r{2}8/13k{4}/k{2}13/14/14/14/***8***/***8***/***4k{1}3*** g - 0,0,0,0 - 0 1 ;D1 7 ;D2 67 ;D3 2050 ;D4 7570"#;
+
+#[test]
+fn four_player_perft_suite() {
+    run_tests("Four-player chess", FourPlayer::create(FourPlayerMode::FreeForAll), TEST_STR);
+}
+
+#[test]
+fn four_player_teams_perft_suite() {
+    run_tests("Four-player teams chess", FourPlayer::create(FourPlayerMode::Teams), TEAMS_TEST_STR);
+}
@@ -0,0 +1,23 @@
+use crate::board::{game::ZobristController, zobrist::ZobristHashTable, Board};
+
+use super::ELIMINATED_COUNTER;
+
+/// Each team has a hash for being eliminated.
+/// Points don't change which moves can be made, so they aren't hashed.
+#[derive(Debug)]
+pub struct FourPlayerZobrist;
+
+impl<const T: usize> ZobristController<T> for FourPlayerZobrist {
+    fn apply(&self, hash: &mut u64, zobrist: &ZobristHashTable<T>, board: &Board<T>) {
+        let eliminated = board.state.counters[ELIMINATED_COUNTER];
+        for team in 0..board.game.teams as usize {
+            if eliminated & (1 << team) != 0 {
+                *hash ^= zobrist.table[zobrist.base_len + team];
+            }
+        }
+    }
+
+    fn get_extra_hashes(&self) -> usize {
+        4
+    }
+}
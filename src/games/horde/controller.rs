@@ -0,0 +1,62 @@
+use crate::{
+    bitboard::BitBoard,
+    board::{
+        actions::{Action, HistoryMove, Move, TheoreticalMove, TurnUpdate},
+        game::{Game, MoveController},
+        Board, BoardState,
+    },
+    games::chess::{pieces::up, ChessMoveController},
+};
+
+const PAWN: u16 = 0;
+
+/// Horde is played with the rules of chess, but the horde's pawns can start on their first rank.
+/// Pawns on the first and second rank can always double step, even if they've already moved there from the first rank.
+#[derive(Debug)]
+pub struct HordeMoveController;
+
+impl<const T: usize> MoveController<T> for HordeMoveController {
+    fn transform_moves(&self, board: &mut Board<T>, mode: u16, actions: Vec<Move>) -> Vec<Move> {
+        ChessMoveController::<T>.transform_moves(board, mode, actions)
+    }
+
+    fn is_legal(&self, board: &mut Board<T>, action: &Move) -> bool {
+        ChessMoveController::<T>.is_legal(board, action)
+    }
+
+    fn use_pseudolegal(&self) -> bool {
+        true
+    }
+
+    fn make_move(&self, board: &mut Board<T>, action: &Action) -> Option<HistoryMove<T>> {
+        let history_move = board.game.pieces[action.piece_type as usize].make_move(board, action)?;
+
+        // `first_move` is already recorded by the move, so this is reverted when it's undone.
+        if action.piece_type == PAWN {
+            let back_rank = match action.team {
+                0 => board.state.edges[0].bottom,
+                _ => board.state.edges[0].top,
+            };
+            let second_rank = up(board, &back_rank, 1, action.team);
+            board.state.first_move |= BitBoard::from_lsb(action.to) & second_rank;
+        }
+
+        Some(history_move)
+    }
+
+    fn encode_action(&self, board: &Board<T>, action: &Move) -> Vec<String> {
+        ChessMoveController::<T>.encode_action(board, action)
+    }
+
+    fn update(&self, action: &Move, state: &BoardState<T>, game: &Game<T>) -> TurnUpdate {
+        ChessMoveController::<T>.update(action, state, game)
+    }
+
+    fn get_theoretical_moves(&self, board: &Board<T>) -> Vec<TheoreticalMove> {
+        ChessMoveController::<T>.get_theoretical_moves(board)
+    }
+
+    fn get_max_available_moves(&self) -> u32 {
+        ChessMoveController::<T>.get_max_available_moves()
+    }
+}
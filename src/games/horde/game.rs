@@ -0,0 +1,60 @@
+use crate::{board::game::Game, games::chess::Chess};
+
+use super::{HordeMoveController, HordeResolution};
+
+pub struct Horde;
+
+impl Horde {
+    /// In Horde, white is a horde of 36 pawns with no king.
+    pub fn create() -> Game<1> {
+        let mut game = Chess::create();
+        game.name = String::from("Horde");
+        game.controller = Box::new(HordeMoveController);
+        game.resolution = Box::new(HordeResolution);
+        game.fen_options.default_fen = String::from("rnbqkbnr/pppppppp/8/1PP2PP1/PPPPPPPP/PPPPPPPP/PPPPPPPP/PPPPPPPP w kq - 0 1");
+        game
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::game::{GameResults, NORMAL_MODE};
+
+    use super::Horde;
+
+    #[test]
+    fn first_rank_double_steps() {
+        let horde = Horde::create();
+        let mut board = horde.from_fen("4k3/8/8/8/8/1p6/8/P7 w - - 0 1");
+
+        let action = board.decode_action("a1a3", NORMAL_MODE).expect("a1a3 should be legal.");
+        board.make_move(&action);
+
+        // Pawns that double step from the first rank can't be captured en passant.
+        assert_eq!(board.to_fen(), "4k3/8/8/8/8/Pp6/8/8 b - - 0 1");
+        assert!(board.decode_action("b3a2", NORMAL_MODE).is_none());
+    }
+
+    #[test]
+    fn horde_extinction() {
+        let horde = Horde::create();
+        let mut board = horde.from_fen("4k3/8/8/8/8/8/1p6/P7 b - - 0 1");
+
+        let action = board.decode_action("b2a1q", NORMAL_MODE).expect("b2xa1=Q should be legal.");
+        board.make_move(&action);
+
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert!(moves.is_empty());
+        assert!(matches!(horde.resolution.resolve(&mut board, &moves), GameResults::Win(1)));
+    }
+
+    #[test]
+    fn horde_stalemate() {
+        let horde = Horde::create();
+        let mut board = horde.from_fen("4k3/8/8/8/8/p7/P7/8 w - - 0 1");
+
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert!(moves.is_empty());
+        assert!(matches!(horde.resolution.resolve(&mut board, &moves), GameResults::Draw));
+    }
+}
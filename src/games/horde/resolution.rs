@@ -0,0 +1,19 @@
+use crate::{
+    board::{game::{Resolution, GameResults}, Board, actions::Move},
+    games::chess::ChessResolution,
+};
+
+#[derive(Debug)]
+pub struct HordeResolution;
+
+impl<const T: usize> Resolution<T> for HordeResolution {
+    fn resolve(&self, board: &mut Board<T>, legal_moves: &Vec<Move>) -> GameResults {
+        // The horde has no king, so it only loses once every one of its pieces has been captured.
+        let team = board.state.moving_team;
+        if board.state.teams[team as usize].is_empty() {
+            return GameResults::Win(board.get_next_team(team));
+        }
+
+        ChessResolution::<T>.resolve(board, legal_moves)
+    }
+}
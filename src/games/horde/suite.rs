@@ -0,0 +1,17 @@
+use crate::board::{tests::run_tests};
+use super::Horde;
+
+const TEST_STR: &str = r#"rnbqkbnr/pppppppp/8/1PP2PP1/PPPPPPPP/PPPPPPPP/PPPPPPPP/PPPPPPPP w kq - 0 1 ;D1 8 ;D2 128 ;D3 1274 ;D4 23310 ;D5 265223
+4k3/pp4q1/3P2p1/8/P3PP2/PPP2r2/PPP5/PPPP4 b - - 0 1 ;D1 30 ;D2 241 ;D3 6633 ;D4 56539 ;D5 1573347
+k7/5p2/4p2P/3p2P1/2p2P2/1p2P2P/p2P2P1/2P2P2 w - - 0 1 ;D1 13 ;D2 172 ;D3 2205 ;D4 33781 ;D5 426584
+rnbqkbnr/6p1/2p1Pp1P/P1PPPP2/Pp4PP/1p2PPPP/1PPPPPPP/PPPPPPPP b kq a3 0 18 ;D1 31 ;D2 547 ;D3 16603 ;D4 297941
+rnbqkbnr/pppppppp/8/8/8/8/1P6/P7 w kq - 0 1 ;D1 4 ;D2 80 ;D3 261 ;D4 5784 ;D5 15866
+4k3/8/8/8/8/8/1p6/P7 w - - 0 1 ;D1 3 ;D2 23 ;D3 37 ;D4 425 ;D5 430
+4k3/8/8/8/8/1p6/8/P7 w - - 0 1 ;D1 2 ;D2 13 ;D3 23 ;D4 178"#;
+
+#[test]
+fn horde_perft_suite() {
+    run_tests("Horde", Horde::create(), TEST_STR);
+}
+
+
@@ -0,0 +1,74 @@
+use crate::{
+    bitboard::BitBoard,
+    board::{
+        actions::{Move, TheoreticalMove, TurnUpdate},
+        game::{Game, MoveController},
+        Board, BoardState,
+    },
+    games::chess::{pieces::KING_PIECE_TYPE, ChessMoveController},
+};
+
+/// King of the Hill is played with the rules of chess, but a team also wins by moving their king onto the hill, the center four squares of the board.
+#[derive(Debug)]
+pub struct KingOfTheHillMoveController;
+
+impl KingOfTheHillMoveController {
+    /// Finds the center four squares of the board.
+    pub fn get_hill<const T: usize>(board: &Board<T>) -> BitBoard<T> {
+        let rows = board.state.rows;
+        let cols = board.state.cols;
+
+        let mut hill = BitBoard::new();
+        for row in [(rows - 1) / 2, rows / 2] {
+            for col in [(cols - 1) / 2, cols / 2] {
+                hill |= BitBoard::from_lsb((row * cols) + col);
+            }
+        }
+        hill
+    }
+
+    /// Finds the team whose king has reached the hill, if there is one.
+    pub fn get_king_of_the_hill<const T: usize>(board: &Board<T>) -> Option<u16> {
+        let kings = board.state.pieces[KING_PIECE_TYPE as usize] & KingOfTheHillMoveController::get_hill(board);
+        if kings.is_empty() {
+            return None;
+        }
+
+        (0..board.game.teams).find(|team| (kings & board.state.teams[*team as usize]).is_set())
+    }
+}
+
+impl<const T: usize> MoveController<T> for KingOfTheHillMoveController {
+    fn transform_moves(&self, board: &mut Board<T>, mode: u16, actions: Vec<Move>) -> Vec<Move> {
+        // Once a king has reached the hill, the game is over.
+        if KingOfTheHillMoveController::get_king_of_the_hill(board).is_some() {
+            return vec![];
+        }
+
+        ChessMoveController::<T>.transform_moves(board, mode, actions)
+    }
+
+    fn is_legal(&self, board: &mut Board<T>, action: &Move) -> bool {
+        ChessMoveController::<T>.is_legal(board, action)
+    }
+
+    fn use_pseudolegal(&self) -> bool {
+        true
+    }
+
+    fn encode_action(&self, board: &Board<T>, action: &Move) -> Vec<String> {
+        ChessMoveController::<T>.encode_action(board, action)
+    }
+
+    fn update(&self, action: &Move, state: &BoardState<T>, game: &Game<T>) -> TurnUpdate {
+        ChessMoveController::<T>.update(action, state, game)
+    }
+
+    fn get_theoretical_moves(&self, board: &Board<T>) -> Vec<TheoreticalMove> {
+        ChessMoveController::<T>.get_theoretical_moves(board)
+    }
+
+    fn get_max_available_moves(&self) -> u32 {
+        ChessMoveController::<T>.get_max_available_moves()
+    }
+}
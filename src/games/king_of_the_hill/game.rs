@@ -0,0 +1,35 @@
+use crate::{board::game::Game, games::chess::Chess};
+
+use super::{KingOfTheHillMoveController, KingOfTheHillResolution};
+
+pub struct KingOfTheHill;
+
+impl KingOfTheHill {
+    pub fn create() -> Game<1> {
+        let mut game = Chess::create();
+        game.name = String::from("King of the Hill");
+        game.controller = Box::new(KingOfTheHillMoveController);
+        game.resolution = Box::new(KingOfTheHillResolution);
+        game
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::game::{GameResults, NORMAL_MODE};
+
+    use super::KingOfTheHill;
+
+    #[test]
+    fn king_reaches_hill() {
+        let koth = KingOfTheHill::create();
+        let mut board = koth.from_fen("r1bqkbnr/pppp1ppp/2n5/4p3/3P4/4K3/PPP2PPP/RNBQ1BNR w kq - 2 4");
+
+        let action = board.decode_action("e3e4", NORMAL_MODE).expect("Ke4 should be legal.");
+        board.make_move(&action);
+
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert!(moves.is_empty());
+        assert!(matches!(koth.resolution.resolve(&mut board, &moves), GameResults::Win(0)));
+    }
+}
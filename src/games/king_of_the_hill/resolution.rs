@@ -0,0 +1,19 @@
+use crate::{
+    board::{game::{Resolution, GameResults}, Board, actions::Move},
+    games::chess::ChessResolution,
+};
+
+use super::KingOfTheHillMoveController;
+
+#[derive(Debug)]
+pub struct KingOfTheHillResolution;
+
+impl<const T: usize> Resolution<T> for KingOfTheHillResolution {
+    fn resolve(&self, board: &mut Board<T>, legal_moves: &Vec<Move>) -> GameResults {
+        if let Some(team) = KingOfTheHillMoveController::get_king_of_the_hill(board) {
+            return GameResults::Win(team);
+        }
+
+        ChessResolution::<T>.resolve(board, legal_moves)
+    }
+}
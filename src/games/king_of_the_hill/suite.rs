@@ -0,0 +1,13 @@
+use crate::board::{tests::run_tests};
+use super::KingOfTheHill;
+
+const TEST_STR: &str = r#"rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1 ;D1 20 ;D2 400 ;D3 8902 ;D4 197281 ;D5 4865609
+r1bqkbnr/pppp1ppp/2n5/4p3/3PK3/8/PPP2PPP/RNBQ1BNR b kq - 0 4 ;D1 0 ;D2 0
+rnbq1bnr/ppp1pppp/8/3pk3/4P3/8/PPPP1PPP/RNBQKBNR w KQ - 2 3 ;D1 0 ;D2 0
+8/8/8/8/2k5/8/4K3/8 w - - 0 1 ;D1 7 ;D2 52 ;D3 236 ;D4 1691 ;D5 10495
+4k3/8/8/8/8/8/8/R3K2R w KQ - 0 1 ;D1 26 ;D2 112 ;D3 3189 ;D4 17945 ;D5 532933"#;
+
+#[test]
+fn king_of_the_hill_perft_suite() {
+    run_tests("King of the Hill", KingOfTheHill::create(), TEST_STR);
+}
@@ -0,0 +1,75 @@
+use crate::{
+    bitboard::BitBoard,
+    board::{
+        actions::{Move, TheoreticalMove, TurnUpdate},
+        game::{Game, MoveController, ATTACKS_MODE},
+        Board, BoardState,
+    },
+    games::chess::{
+        pieces::{promotion_move, KING_PIECE_TYPE, PAWN_PIECE_TYPE},
+        ChessMoveController,
+    },
+};
+
+use super::pieces::MAHARAJAH_PIECE_TYPE;
+
+/// Gets `team`'s royal pieces: its king, or the maharajah.
+pub fn get_royal_pieces<const T: usize>(board: &Board<T>, team: u16) -> BitBoard<T> {
+    let royals = board.state.pieces[KING_PIECE_TYPE as usize] | board.state.pieces[MAHARAJAH_PIECE_TYPE as usize];
+    royals & board.state.teams[team as usize]
+}
+
+/// Maharajah and the Sepoys is played with the rules of chess, but the maharajah is royal, just like a king.
+/// It can't be captured or left in check, and the sepoys' pawns can't promote to a maharajah.
+#[derive(Debug)]
+pub struct MaharajahMoveController;
+
+impl<const T: usize> MoveController<T> for MaharajahMoveController {
+    fn transform_moves(&self, board: &mut Board<T>, _mode: u16, actions: Vec<Move>) -> Vec<Move> {
+        let mut legal_moves = Vec::with_capacity(actions.len());
+        for action in actions {
+            if self.is_legal(board, &action) {
+                legal_moves.push(action);
+            }
+        }
+        legal_moves
+    }
+
+    fn is_legal(&self, board: &mut Board<T>, action: &Move) -> bool {
+        match action {
+            Move::Action(action) => {
+                let opponent = board.get_next_team(action.team);
+                if action.piece_type == PAWN_PIECE_TYPE && action.info == promotion_move(MAHARAJAH_PIECE_TYPE) {
+                    return false;
+                }
+
+                let undo = board.make_move(&Move::Action(*action));
+                let royals = get_royal_pieces(board, action.team);
+                let in_check = board.can_move(opponent, royals, ATTACKS_MODE);
+                board.undo_move(undo);
+                !in_check
+            }
+            Move::Pass => false,
+        }
+    }
+
+    fn use_pseudolegal(&self) -> bool {
+        true
+    }
+
+    fn encode_action(&self, board: &Board<T>, action: &Move) -> Vec<String> {
+        ChessMoveController::<T>.encode_action(board, action)
+    }
+
+    fn update(&self, action: &Move, state: &BoardState<T>, game: &Game<T>) -> TurnUpdate {
+        ChessMoveController::<T>.update(action, state, game)
+    }
+
+    fn get_theoretical_moves(&self, board: &Board<T>) -> Vec<TheoreticalMove> {
+        ChessMoveController::<T>.get_theoretical_moves(board)
+    }
+
+    fn get_max_available_moves(&self) -> u32 {
+        ChessMoveController::<T>.get_max_available_moves()
+    }
+}
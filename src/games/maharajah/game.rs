@@ -0,0 +1,93 @@
+use crate::{
+    board::{game::Game, zobrist::ZobristHashTable},
+    games::chess::Chess,
+};
+
+use super::{pieces::get_maharajah_piece, MaharajahMoveController, MaharajahResolution};
+
+pub struct Maharajah;
+
+impl Maharajah {
+    /// In Maharajah and the Sepoys, white is a lone maharajah (`M`), which moves as a queen or a knight, against black's full chess army.
+    /// The maharajah is royal, so white loses once it's checkmated. White has no pawns or rooks, so it can't castle or promote.
+    pub fn create() -> Game<1> {
+        let mut game = Chess::create();
+        game.name = String::from("Maharajah and the Sepoys");
+        game.pieces.push(get_maharajah_piece());
+        game.controller = Box::new(MaharajahMoveController);
+        game.resolution = Box::new(MaharajahResolution);
+        game.zobrist = ZobristHashTable::<1>::generate(64, 2, 7, 65, || fastrand::u64(0..u64::MAX));
+        game.fen_options.default_fen = String::from("rnbqkbnr/pppppppp/8/8/8/8/8/4M3 w kq - 0 1");
+        game
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::game::{GameResults, NORMAL_MODE};
+
+    use super::Maharajah;
+
+    fn encode_moves(fen: &str) -> Vec<String> {
+        let maharajah = Maharajah::create();
+        let mut board = maharajah.from_fen(fen);
+        board
+            .generate_legal_moves(NORMAL_MODE)
+            .iter()
+            .map(|action| board.encode_action(action))
+            .collect()
+    }
+
+    #[test]
+    fn fen() {
+        let maharajah = Maharajah::create();
+        let board = maharajah.default();
+        assert_eq!(board.to_fen(), "rnbqkbnr/pppppppp/8/8/8/8/8/4M3 w kq - 0 1");
+    }
+
+    #[test]
+    fn maharajah_moves() {
+        // The maharajah moves as a queen or a knight, but can't move into check.
+        let mut moves = encode_moves("4k3/8/8/8/8/8/8/M7 w - - 0 1");
+        moves.sort();
+        assert_eq!(moves.len(), 23);
+        assert!(moves.contains(&"a1b3".to_string()));
+        assert!(moves.contains(&"a1h8".to_string()));
+
+        let moves = encode_moves("4k3/8/8/8/8/8/3p4/M7 w - - 0 1");
+        assert!(!moves.contains(&"a1c1".to_string()));
+        assert!(!moves.contains(&"a1e1".to_string()));
+
+        // Being royal, it can't move next to the king either.
+        let moves = encode_moves("8/8/8/4k3/8/8/8/3M4 w - - 0 1");
+        assert!(moves.contains(&"d1d3".to_string()));
+        assert!(!moves.contains(&"d1d4".to_string()));
+        assert!(!moves.contains(&"d1f4".to_string()));
+    }
+
+    #[test]
+    fn no_maharajah_promotion() {
+        let moves = encode_moves("4k3/8/8/8/8/8/1p6/7M b - - 0 1");
+        for promotion in ["b2b1n", "b2b1b", "b2b1r", "b2b1q"] {
+            assert!(moves.contains(&promotion.to_string()));
+        }
+        assert!(!moves.contains(&"b2b1m".to_string()));
+    }
+
+    #[test]
+    fn maharajah_checkmate() {
+        let maharajah = Maharajah::create();
+
+        // The maharajah is checkmated just like a king.
+        let mut board = maharajah.from_fen("4k3/8/8/8/3b4/8/rq3n2/M6r w - - 0 1");
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert!(moves.is_empty());
+        assert!(matches!(maharajah.resolution.resolve(&mut board, &moves), GameResults::Win(1)));
+
+        // The maharajah can checkmate the king on its own.
+        let mut board = maharajah.from_fen("k7/2M5/8/8/8/8/8/8 b - - 0 1");
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert!(moves.is_empty());
+        assert!(matches!(maharajah.resolution.resolve(&mut board, &moves), GameResults::Win(0)));
+    }
+}
@@ -0,0 +1,9 @@
+mod game;
+mod controller;
+mod resolution;
+mod suite;
+pub mod pieces;
+
+pub use game::*;
+pub use controller::*;
+pub use resolution::*;
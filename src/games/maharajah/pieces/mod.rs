@@ -0,0 +1,12 @@
+use std::sync::OnceLock;
+
+use crate::board::{pieces::BetzaPiece, PieceType};
+
+/// Maharajah and the Sepoys adds the maharajah after chess's pieces, so the chess piece types (eg. `KING_PIECE_TYPE`) stay the same.
+pub const MAHARAJAH_PIECE_TYPE: PieceType = 6;
+
+/// Gets the maharajah, a royal piece which moves as a queen or a knight.
+pub fn get_maharajah_piece() -> &'static BetzaPiece {
+    static PIECE: OnceLock<BetzaPiece> = OnceLock::new();
+    PIECE.get_or_init(|| BetzaPiece::parse('m', "QN").expect("The maharajah should have valid Betza notation."))
+}
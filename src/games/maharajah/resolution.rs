@@ -0,0 +1,27 @@
+use crate::board::{game::{Resolution, GameResults, ATTACKS_MODE}, Board, actions::Move};
+
+use super::get_royal_pieces;
+
+#[derive(Debug)]
+pub struct MaharajahResolution;
+
+impl<const T: usize> Resolution<T> for MaharajahResolution {
+    fn resolve(&self, board: &mut Board<T>, legal_moves: &Vec<Move>) -> GameResults {
+        if legal_moves.is_empty() {
+            // The maharajah is checkmated just like a king would be.
+            let team = board.state.moving_team;
+            let opponent = board.get_next_team(team);
+            let royals = get_royal_pieces(board, team);
+
+            if board.can_move(opponent, royals, ATTACKS_MODE) {
+                GameResults::Win(opponent)
+            } else {
+                GameResults::Draw
+            }
+        } else if board.state.sub_moves == 100 {
+            GameResults::Draw
+        } else {
+            GameResults::Ongoing
+        }
+    }
+}
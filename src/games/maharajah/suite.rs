@@ -0,0 +1,13 @@
+use crate::board::{tests::run_tests};
+use super::Maharajah;
+
+const TEST_STR: &str = r#"rnbqkbnr/pppppppp/8/8/8/8/8/4M3 w kq - 0 1 ;D1 22 ;D2 438 ;D3 9097 ;D4 197635
+r1bqk2r/ppp2ppp/2n2n2/3pp3/1b6/3M4/8/8 b kq - 0 5 ;D1 43 ;D2 573 ;D3 23934 ;D4 316567
+4k3/1p6/8/8/8/8/p5p1/4M3 b - - 0 1 ;D1 4 ;D2 78 ;D3 932 ;D4 20476
+r3k2r/8/8/8/8/8/8/3M4 b kq - 0 1 ;D1 23 ;D2 398 ;D3 8446 ;D4 162155
+r3k2r/p6p/8/8/8/8/8/6M1 w kq - 0 1 ;D1 20 ;D2 236 ;D3 5321 ;D4 70862"#;
+
+#[test]
+fn maharajah_perft_suite() {
+    run_tests("Maharajah and the Sepoys", Maharajah::create(), TEST_STR);
+}
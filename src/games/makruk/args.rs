@@ -0,0 +1,48 @@
+use crate::board::{fen::{FenArgument, FenDecodeError}, Board};
+
+use super::{get_counting_team, COUNT, COUNTING_LIMIT, COUNTING_TEAM};
+
+const TEAMS: [char; 2] = ['w', 'b'];
+
+/// The team that's counting, its count and its counting limit, such as `b:5/16`, or `-` if no team is counting yet.
+#[derive(Debug)]
+pub struct MakrukCounting;
+
+impl<const T: usize> FenArgument<T> for MakrukCounting {
+    fn encode(&self, board: &Board<T>) -> String {
+        match get_counting_team(board) {
+            Some(team) => format!(
+                "{}:{}/{}",
+                TEAMS[team as usize],
+                board.state.counters[COUNT],
+                board.state.counters[COUNTING_LIMIT]
+            ),
+            None => "-".to_string(),
+        }
+    }
+
+    fn decode(&self, board: &mut Board<T>, arg: &str) -> Result<(), FenDecodeError> {
+        if arg == "-" {
+            board.state.counters = vec![0; 3];
+            return Ok(());
+        }
+
+        let invalid = || FenDecodeError::InvalidArgument(format!(
+            "'{arg}' is not a valid count, as it should look like 'b:5/16' or '-'."
+        ));
+
+        let (team, count) = arg.split_once(':').ok_or_else(invalid)?;
+        let team = TEAMS
+            .iter()
+            .position(|symbol| team == symbol.to_string())
+            .ok_or_else(invalid)?;
+        let (count, limit) = count.split_once('/').ok_or_else(invalid)?;
+        let count = count.parse::<u16>().map_err(|_| invalid())?;
+        let limit = limit.parse::<u16>().map_err(|_| invalid())?;
+
+        board.state.counters[COUNTING_TEAM] = team as u16 + 1;
+        board.state.counters[COUNT] = count;
+        board.state.counters[COUNTING_LIMIT] = limit;
+        Ok(())
+    }
+}
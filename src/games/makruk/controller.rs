@@ -0,0 +1,173 @@
+use crate::{
+    board::{
+        actions::{Action, HistoryMove, HistoryUpdate, Move, PreviousCounter, TheoreticalMove, TurnUpdate},
+        game::{Game, MoveController},
+        Board, BoardState,
+    },
+    games::chess::{
+        pieces::{KNIGHT_PIECE_TYPE, PAWN_PIECE_TYPE, ROOK_PIECE_TYPE},
+        ChessMoveController,
+    },
+};
+
+use super::pieces::KHON_PIECE_TYPE;
+
+/// The counter with the team that's counting (plus one), or zero if no team is counting yet.
+pub const COUNTING_TEAM: usize = 0;
+/// The counter with the count so far.
+pub const COUNT: usize = 1;
+/// The counter with the count that draws the game once it's reached.
+pub const COUNTING_LIMIT: usize = 2;
+
+/// The limit of the board's honor count, which is the same no matter which pieces are left.
+pub const BOARD_HONOR_LIMIT: u16 = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CountingRule {
+    /// Once there are no pawns left, the team with fewer pieces counts its moves from 1 to 64.
+    BoardHonor,
+    /// Once a team only has its king left, it counts its moves from the amount of pieces on the board,
+    /// up to a limit depending on the pieces the other team has.
+    PiecesHonor,
+}
+
+/// Gets the team that's counting, if any.
+pub fn get_counting_team<const T: usize>(board: &Board<T>) -> Option<u16> {
+    match board.state.counters[COUNTING_TEAM] {
+        0 => None,
+        team => Some(team - 1),
+    }
+}
+
+/// Gets which team should be counting and under which rule, given the team that's already counting (if any.)
+/// When both teams have as many pieces, the team that's already counting keeps counting, or else `next_team` starts to.
+pub fn get_counting<const T: usize>(board: &Board<T>, counting_team: Option<u16>, next_team: u16) -> Option<(u16, CountingRule)> {
+    if board.state.pieces[PAWN_PIECE_TYPE as usize].is_set() {
+        return None;
+    }
+
+    let pieces = [board.state.teams[0].count_ones(), board.state.teams[1].count_ones()];
+    match (pieces[0] == 1, pieces[1] == 1) {
+        // Two bare kings are a draw, so there's nothing left to count.
+        (true, true) => None,
+        (true, false) => Some((0, CountingRule::PiecesHonor)),
+        (false, true) => Some((1, CountingRule::PiecesHonor)),
+        (false, false) => {
+            let team = if pieces[0] < pieces[1] {
+                0
+            } else if pieces[1] < pieces[0] {
+                1
+            } else {
+                counting_team.unwrap_or(next_team)
+            };
+            Some((team, CountingRule::BoardHonor))
+        }
+    }
+}
+
+/// Gets the count `team` starts counting from and the limit it counts up to.
+pub fn get_count_bounds<const T: usize>(board: &Board<T>, team: u16, rule: CountingRule) -> (u16, u16) {
+    match rule {
+        CountingRule::BoardHonor => (0, BOARD_HONOR_LIMIT),
+        CountingRule::PiecesHonor => {
+            let opponent = board.state.teams[board.get_next_team(team) as usize];
+            let count = |piece_type: u16| (board.state.pieces[piece_type as usize] & opponent).count_ones();
+
+            let limit = if count(ROOK_PIECE_TYPE) >= 2 {
+                8
+            } else if count(ROOK_PIECE_TYPE) == 1 {
+                16
+            } else if count(KHON_PIECE_TYPE) >= 2 {
+                22
+            } else if count(KNIGHT_PIECE_TYPE) >= 2 {
+                32
+            } else if count(KHON_PIECE_TYPE) == 1 {
+                44
+            } else {
+                64
+            };
+            (board.state.all_pieces.count_ones() as u16, limit)
+        }
+    }
+}
+
+fn set_counter<const T: usize>(board: &mut Board<T>, updates: &mut Vec<HistoryUpdate<T>>, counter: usize, value: u16) {
+    if board.state.counters[counter] != value {
+        updates.push(HistoryUpdate::Counter(PreviousCounter(counter, board.state.counters[counter])));
+        board.state.counters[counter] = value;
+    }
+}
+
+/// Makruk is played with chess's move rules, but keeps track of the board's honor and pieces' honor counts in `BoardState::counters`.
+/// Counting starts once there are no pawns left, restarts whenever the counting team or rule changes, and goes up with each of the counting team's moves.
+#[derive(Debug)]
+pub struct MakrukMoveController;
+
+impl<const T: usize> MoveController<T> for MakrukMoveController {
+    fn transform_moves(&self, board: &mut Board<T>, mode: u16, actions: Vec<Move>) -> Vec<Move> {
+        ChessMoveController::<T>.transform_moves(board, mode, actions)
+    }
+
+    fn is_legal(&self, board: &mut Board<T>, action: &Move) -> bool {
+        ChessMoveController::<T>.is_legal(board, action)
+    }
+
+    fn use_pseudolegal(&self) -> bool {
+        true
+    }
+
+    fn get_counters(&self) -> usize {
+        // The counting team, the count, and the counting limit.
+        3
+    }
+
+    fn make_move(&self, board: &mut Board<T>, action: &Action) -> Option<HistoryMove<T>> {
+        let counting_team = get_counting_team(board);
+        let next_team = board.get_next_team(action.team);
+        let previous_counting = get_counting(board, counting_team, next_team);
+
+        let mut history_move = board.game.pieces[action.piece_type as usize].make_move(board, action)?;
+
+        let mut updates = vec![];
+        match get_counting(board, counting_team, next_team) {
+            None => {
+                for counter in [COUNTING_TEAM, COUNT, COUNTING_LIMIT] {
+                    set_counter(board, &mut updates, counter, 0);
+                }
+            }
+            Some((team, rule)) => {
+                if counting_team != Some(team) || previous_counting != Some((team, rule)) {
+                    let (count, limit) = get_count_bounds(board, team, rule);
+                    set_counter(board, &mut updates, COUNTING_TEAM, team + 1);
+                    set_counter(board, &mut updates, COUNT, count);
+                    set_counter(board, &mut updates, COUNTING_LIMIT, limit);
+                } else if action.team == team {
+                    let count = board.state.counters[COUNT] + 1;
+                    set_counter(board, &mut updates, COUNT, count);
+                }
+            }
+        }
+
+        if !updates.is_empty() {
+            history_move.add_updates(updates);
+        }
+
+        Some(history_move)
+    }
+
+    fn encode_action(&self, board: &Board<T>, action: &Move) -> Vec<String> {
+        ChessMoveController::<T>.encode_action(board, action)
+    }
+
+    fn update(&self, action: &Move, state: &BoardState<T>, game: &Game<T>) -> TurnUpdate {
+        ChessMoveController::<T>.update(action, state, game)
+    }
+
+    fn get_theoretical_moves(&self, board: &Board<T>) -> Vec<TheoreticalMove> {
+        ChessMoveController::<T>.get_theoretical_moves(board)
+    }
+
+    fn get_max_available_moves(&self) -> u32 {
+        ChessMoveController::<T>.get_max_available_moves()
+    }
+}
@@ -0,0 +1,142 @@
+use crate::{
+    board::{fen::FenUnused, game::Game, regions::{get_rectangle, Region}},
+    games::chess::{pieces::{KING_PIECE_TYPE, PAWN_PIECE_TYPE, PROMOTION_REGION}, Chess},
+};
+
+use super::{pieces::{get_makruk_pieces, KHON_PIECE_TYPE, MAKRUK_PAWN, MET_PIECE_TYPE}, MakrukCounting, MakrukMoveController, MakrukResolution};
+
+pub struct Makruk;
+
+impl Makruk {
+    /// Makruk (Thai chess) has a khon (`S`) instead of a bishop and a met (`M`) instead of a queen, and its pawns start on the third rank.
+    /// Pawns only step one square at a time and promote to a met on the sixth rank, and there's no castling.
+    /// Once there are no pawns left, the weaker team starts counting, and the game is drawn if it's not checkmated in time (see `MakrukMoveController`.)
+    pub fn create() -> Game<1> {
+        let mut game = Chess::create();
+        game.name = String::from("Makruk");
+
+        let pieces = get_makruk_pieces();
+        game.pieces[PAWN_PIECE_TYPE as usize] = MAKRUK_PAWN;
+        game.pieces[KHON_PIECE_TYPE as usize] = &pieces.khon;
+        game.pieces[MET_PIECE_TYPE as usize] = &pieces.met;
+        game.pieces[KING_PIECE_TYPE as usize] = &pieces.king;
+        game.regions = vec![
+            Region::new(PROMOTION_REGION, vec![
+                get_rectangle(2..3, 0..8, 8),
+                get_rectangle(5..6, 0..8, 8),
+            ]),
+        ];
+        game.controller = Box::new(MakrukMoveController);
+        game.resolution = Box::new(MakrukResolution);
+
+        // There's no castling, but its FEN field is kept as a placeholder.
+        game.fen_options.args[1] = ("castling rights".to_string(), Box::new(FenUnused));
+        game.fen_options.args.push(("counting".to_string(), Box::new(MakrukCounting)));
+        game.fen_options.default_fen = String::from("rnsmksnr/8/pppppppp/8/8/PPPPPPPP/8/RNSKMSNR w - - 0 1 -");
+        game
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::game::{GameResults, NORMAL_MODE};
+
+    use super::Makruk;
+
+    fn encode_moves(fen: &str) -> Vec<String> {
+        let makruk = Makruk::create();
+        let mut board = makruk.from_fen(fen);
+        board
+            .generate_legal_moves(NORMAL_MODE)
+            .iter()
+            .map(|action| board.encode_action(action))
+            .collect()
+    }
+
+    #[test]
+    fn fen() {
+        let makruk = Makruk::create();
+        for fen in [
+            "rnsmksnr/8/pppppppp/8/8/PPPPPPPP/8/RNSKMSNR w - - 0 1 -",
+            "8/8/3k4/8/8/4K3/8/R6R b - - 0 40 b:5/8",
+        ] {
+            assert_eq!(makruk.from_fen(fen).to_fen(), fen);
+        }
+    }
+
+    #[test]
+    fn pieces() {
+        // The khon steps diagonally or forwards, but not sideways or backwards.
+        let mut moves = encode_moves("4k3/8/8/8/3S4/8/8/4K3 w - - 0 1 -");
+        moves.retain(|action| action.starts_with("d4"));
+        moves.sort();
+        assert_eq!(moves, vec!["d4c3", "d4c5", "d4d5", "d4e3", "d4e5"]);
+
+        // Pawns promote to a met on the sixth rank.
+        let moves = encode_moves("4k3/8/8/1P6/8/8/8/4K3 w - - 0 1 -");
+        assert!(moves.contains(&"b5b6m".to_string()));
+        assert!(!moves.contains(&"b5b6".to_string()));
+        assert!(!moves.contains(&"b5b6s".to_string()));
+
+        let moves = encode_moves("4k3/8/8/8/8/1p6/8/4K3 b - - 0 1 -");
+        assert!(moves.contains(&"b3b2".to_string()));
+        assert!(!moves.contains(&"b3b2m".to_string()));
+
+        let moves = encode_moves("4k3/8/8/8/1p6/8/8/4K3 b - - 0 1 -");
+        assert!(moves.contains(&"b4b3m".to_string()));
+    }
+
+    #[test]
+    fn counting() {
+        let makruk = Makruk::create();
+
+        // Once the last pawn is captured, black only has its king left, so it counts with the pieces' honor rule.
+        // It starts from the three pieces on the board, and white has a rook, so it only has until sixteen to checkmate.
+        let mut board = makruk.from_fen("4k3/8/1p6/8/8/4K3/8/1R6 w - - 0 1 -");
+        let state = board.state.clone();
+
+        let first_action = board.decode_action("b1b6", NORMAL_MODE).expect("Rxb6 should be legal.");
+        let first_undo = board.make_move(&first_action);
+        assert_eq!(board.to_fen(), "4k3/8/1R6/8/8/4K3/8/8 b - - 0 1 b:3/16");
+
+        // Only black's moves are counted.
+        let action = board.decode_action("e8d7", NORMAL_MODE).expect("Kd7 should be legal.");
+        let undo = board.make_move(&action);
+        assert_eq!(board.to_fen(), "8/3k4/1R6/8/8/4K3/8/8 w - - 1 2 b:4/16");
+
+        board.undo_move(undo);
+        board.undo_move(first_undo);
+        assert_eq!(board.state, state);
+
+        // With as many pieces on each side, the team that would move next counts with the board's honor rule.
+        let mut board = makruk.from_fen("4k3/2s5/1p6/8/8/4K3/8/1R6 w - - 0 1 -");
+        let action = board.decode_action("b1b6", NORMAL_MODE).expect("Rxb6 should be legal.");
+        board.make_move(&action);
+        assert_eq!(board.to_fen(), "4k3/2s5/1R6/8/8/4K3/8/8 b - - 0 1 b:0/64");
+
+        // Capturing the khon leaves black with a bare king, so it starts counting again under the pieces' honor rule.
+        let action = board.decode_action("e8d8", NORMAL_MODE).expect("Kd8 should be legal.");
+        board.make_move(&action);
+        assert_eq!(board.to_fen(), "3k4/2s5/1R6/8/8/4K3/8/8 w - - 1 2 b:1/64");
+        let action = board.decode_action("b6c6", NORMAL_MODE).expect("Rc6 should be legal.");
+        board.make_move(&action);
+        let action = board.decode_action("c7d6", NORMAL_MODE).expect("Sd6 should be legal.");
+        board.make_move(&action);
+        let action = board.decode_action("c6d6", NORMAL_MODE).expect("Rxd6+ should be legal.");
+        board.make_move(&action);
+        assert_eq!(board.to_fen(), "3k4/8/3R4/8/8/4K3/8/8 b - - 0 3 b:3/16");
+    }
+
+    #[test]
+    fn counting_limit() {
+        let makruk = Makruk::create();
+        let mut board = makruk.from_fen("8/8/3k4/8/8/4K3/8/R6R b - - 0 40 b:7/8");
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert!(matches!(makruk.resolution.resolve(&mut board, &moves), GameResults::Ongoing));
+
+        let action = board.decode_action("d6d7", NORMAL_MODE).expect("Kd7 should be legal.");
+        board.make_move(&action);
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert!(matches!(makruk.resolution.resolve(&mut board, &moves), GameResults::Draw));
+    }
+}
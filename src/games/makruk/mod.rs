@@ -0,0 +1,11 @@
+mod game;
+mod args;
+mod controller;
+mod resolution;
+mod suite;
+pub mod pieces;
+
+pub use game::*;
+pub use args::*;
+pub use controller::*;
+pub use resolution::*;
@@ -0,0 +1,36 @@
+use std::sync::OnceLock;
+
+use crate::{
+    board::{pieces::{BetzaPiece, Piece}, PieceType},
+    games::chess::pieces::{PawnPiece, BISHOP_PIECE_TYPE, QUEEN_PIECE_TYPE},
+};
+
+/// Makruk's pieces take the place of chess's, so the khon and met use the piece types of the bishop and queen they line up with.
+pub const KHON_PIECE_TYPE: PieceType = BISHOP_PIECE_TYPE;
+pub const MET_PIECE_TYPE: PieceType = QUEEN_PIECE_TYPE;
+
+/// Pawns (bia) can't double step, and can only promote to a met once they reach the sixth rank.
+pub const MAKRUK_PAWN: &dyn Piece<1> = &PawnPiece {
+    king_promotion: false,
+    first_move_steps: 1,
+    promotion_pieces: Some(&[MET_PIECE_TYPE]),
+};
+
+pub struct MakrukPieces {
+    /// The king steps one square in any direction, and can't castle.
+    pub king: BetzaPiece,
+    /// The khon steps one square diagonally or one square forwards.
+    pub khon: BetzaPiece,
+    /// The met steps one square diagonally.
+    pub met: BetzaPiece,
+}
+
+/// Gets the pieces Makruk uses instead of chess's bishop, queen and king.
+pub fn get_makruk_pieces() -> &'static MakrukPieces {
+    static PIECES: OnceLock<MakrukPieces> = OnceLock::new();
+    PIECES.get_or_init(|| MakrukPieces {
+        king: BetzaPiece::parse('k', "K").expect("The king should have valid Betza notation."),
+        khon: BetzaPiece::parse('s', "FfW").expect("The khon should have valid Betza notation."),
+        met: BetzaPiece::parse('m', "F").expect("The met should have valid Betza notation."),
+    })
+}
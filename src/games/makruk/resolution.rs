@@ -0,0 +1,29 @@
+use crate::{
+    board::{game::{Resolution, GameResults}, Board, actions::Move},
+    games::chess::ChessResolution,
+};
+
+use super::{get_counting_team, COUNT, COUNTING_LIMIT};
+
+/// In Makruk, checkmate wins and stalemate draws, just like in chess.
+/// There's no fifty-move rule, but the game is drawn once the counting team reaches its counting limit, or once both kings are bare.
+#[derive(Debug)]
+pub struct MakrukResolution;
+
+impl<const T: usize> Resolution<T> for MakrukResolution {
+    fn resolve(&self, board: &mut Board<T>, legal_moves: &Vec<Move>) -> GameResults {
+        if legal_moves.is_empty() {
+            return ChessResolution::<T>.resolve(board, legal_moves);
+        }
+
+        let counting_limit_reached = get_counting_team(board).is_some()
+            && board.state.counters[COUNT] >= board.state.counters[COUNTING_LIMIT];
+        let bare_kings = board.state.teams.iter().all(|team| team.count_ones() == 1);
+
+        if counting_limit_reached || bare_kings {
+            GameResults::Draw
+        } else {
+            GameResults::Ongoing
+        }
+    }
+}
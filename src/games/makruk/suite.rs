@@ -0,0 +1,12 @@
+use crate::board::{tests::run_tests};
+use super::Makruk;
+
+const TEST_STR: &str = r#"rnsmksnr/8/pppppppp/8/8/PPPPPPPP/8/RNSKMSNR w - - 0 1 - ;D1 23 ;D2 529 ;D3 12012 ;D4 273026
+rnsmksnr/8/1pp1pppp/p2p4/3P4/PPP1PPPP/8/RNSKMSNR w - - 0 1 - ;D1 22 ;D2 530 ;D3 11708 ;D4 284000
+8/2k5/8/1P6/3p4/5s2/8/3K4 w - - 0 1 - ;D1 5 ;D2 60 ;D3 368 ;D4 4387
+4k3/8/8/8/3M4/8/8/R3K2S w - - 0 1 - ;D1 21 ;D2 100 ;D3 2275 ;D4 13867"#;
+
+#[test]
+fn makruk_perft_suite() {
+    run_tests("Makruk", Makruk::create(), TEST_STR);
+}
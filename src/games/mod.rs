@@ -1,2 +1,23 @@
 pub mod chess;
-pub mod ataxx;
\ No newline at end of file
+pub mod ataxx;
+pub mod shogi;
+pub mod crazyhouse;
+pub mod three_check;
+pub mod atomic;
+pub mod antichess;
+pub mod horde;
+pub mod king_of_the_hill;
+pub mod racing_kings;
+pub mod duck;
+pub mod monster;
+pub mod xiangqi;
+pub mod capablanca;
+pub mod omega;
+pub mod four_player;
+pub mod maharajah;
+pub mod shatranj;
+pub mod makruk;
+pub mod seirawan;
+pub mod bughouse;
+pub mod fog_of_war;
+pub mod alice;
@@ -0,0 +1,81 @@
+use crate::{
+    bitboard::BitBoard,
+    board::{
+        actions::{Move, TheoreticalMove, TurnUpdate},
+        game::{Game, MoveController, NORMAL_MODE},
+        Board, BoardState,
+    },
+    games::chess::{get_kingless_team, pieces::KING_PIECE_TYPE, ChessMoveController, ATTACKS_MODE},
+};
+
+/// White is the team that makes two turns per sub-move.
+pub const MONSTER_TEAM: u16 = 0;
+
+/// Monster chess is played with the rules of chess, except white makes two moves for each of black's.
+/// White's king can be left in check after their first move, as long as their second move gets it out of check.
+/// If white's first move checks black's king, white's second move can capture it to win.
+#[derive(Debug)]
+pub struct MonsterMoveController;
+
+impl<const T: usize> MoveController<T> for MonsterMoveController {
+    #[allow(unused_variables)]
+    fn transform_moves(&self, board: &mut Board<T>, mode: u16, actions: Vec<Move>) -> Vec<Move> {
+        // White's second move can capture black's king, which ends the game.
+        if get_kingless_team(board).is_some() {
+            return vec![];
+        }
+
+        let mut legal_moves = Vec::with_capacity(actions.len());
+        for action in actions {
+            if self.is_legal(board, &action) {
+                legal_moves.push(action);
+            }
+        }
+        legal_moves
+    }
+
+    fn is_legal(&self, board: &mut Board<T>, action: &Move) -> bool {
+        match action {
+            Move::Action(action) if action.team == MONSTER_TEAM => {
+                let opponent = board.get_next_team(MONSTER_TEAM);
+                let opponent_king = board.state.pieces[KING_PIECE_TYPE as usize] & board.state.teams[opponent as usize];
+                if (BitBoard::from_lsb(action.to) & opponent_king).is_set() {
+                    return true;
+                }
+
+                let undo = board.make_move(&Move::Action(*action));
+                let is_legal = if board.state.current_turn == 1 {
+                    // White's king is only checked after both of their moves, so their first move needs a second move that leaves it safe.
+                    let replies = board.generate_moves(NORMAL_MODE);
+                    replies.iter().any(|reply| self.is_legal(board, reply))
+                } else {
+                    let king_board = board.state.pieces[KING_PIECE_TYPE as usize] & board.state.teams[MONSTER_TEAM as usize];
+                    !(king_board.is_set() && board.can_move(opponent, king_board, ATTACKS_MODE))
+                };
+                board.undo_move(undo);
+                is_legal
+            }
+            _ => ChessMoveController::<T>.is_legal(board, action),
+        }
+    }
+
+    fn use_pseudolegal(&self) -> bool {
+        true
+    }
+
+    fn encode_action(&self, board: &Board<T>, action: &Move) -> Vec<String> {
+        ChessMoveController::<T>.encode_action(board, action)
+    }
+
+    fn update(&self, action: &Move, state: &BoardState<T>, game: &Game<T>) -> TurnUpdate {
+        ChessMoveController::<T>.update(action, state, game)
+    }
+
+    fn get_theoretical_moves(&self, board: &Board<T>) -> Vec<TheoreticalMove> {
+        ChessMoveController::<T>.get_theoretical_moves(board)
+    }
+
+    fn get_max_available_moves(&self) -> u32 {
+        ChessMoveController::<T>.get_max_available_moves()
+    }
+}
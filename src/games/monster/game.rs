@@ -0,0 +1,113 @@
+use crate::{
+    board::{
+        fen::FenCurrentTurn,
+        game::{Game, ZobristController},
+        zobrist::ZobristHashTable,
+    },
+    games::chess::Chess,
+};
+
+use super::{MonsterMoveController, MonsterResolution, MonsterZobrist};
+
+pub struct Monster;
+
+impl Monster {
+    /// White (with only their king and four pawns) makes two turns per sub-move, while black makes one.
+    /// The current turn is added after the full move number in FENs.
+    pub fn create() -> Game<1> {
+        let mut game = Chess::create();
+        game.name = String::from("Monster Chess");
+        game.turns = vec![2, 1];
+        // En passant has to look back through both of white's moves.
+        game.saved_last_moves = 2;
+        game.controller = Box::new(MonsterMoveController);
+        game.resolution = Box::new(MonsterResolution);
+
+        let extra_hashes = ZobristController::<1>::get_extra_hashes(&MonsterZobrist);
+        game.zobrist_controller = Box::new(MonsterZobrist);
+        game.zobrist = ZobristHashTable::<1>::generate(64, 2, 6, extra_hashes, || fastrand::u64(0..u64::MAX));
+
+        game.fen_options.args.push(("current turn".to_string(), Box::new(FenCurrentTurn)));
+        game.fen_options.default_fen = String::from("rnbqkbnr/pppppppp/8/8/8/8/2PPPP2/4K3 w kq - 0 1 0");
+        game
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::game::{GameResults, NORMAL_MODE};
+
+    use super::Monster;
+
+    #[test]
+    fn white_moves_twice() {
+        let monster = Monster::create();
+        let mut board = monster.from_fen("rnbqkbnr/pppppppp/8/8/8/8/2PPPP2/4K3 w kq - 0 1 0");
+
+        let state = board.state.clone();
+        let hash = monster.zobrist.compute(&board);
+
+        let action = board.decode_action("e2e4", NORMAL_MODE).expect("e4 should be legal.");
+        let first_undo = board.make_move(&action);
+        assert_eq!(board.to_fen(), "rnbqkbnr/pppppppp/8/8/4P3/8/2PP1P2/4K3 w kq - 0 1 1");
+        assert_ne!(monster.zobrist.compute(&board), hash);
+
+        let action = board.decode_action("d2d4", NORMAL_MODE).expect("d4 should be legal.");
+        let second_undo = board.make_move(&action);
+        assert_eq!(board.to_fen(), "rnbqkbnr/pppppppp/8/8/3PP3/8/2P2P2/4K3 b kq d3 0 1 0");
+
+        // Either of white's double steps can be captured en passant.
+        let action = board.decode_action("e7e5", NORMAL_MODE).expect("e5 should be legal.");
+        let third_undo = board.make_move(&action);
+        assert_eq!(board.to_fen(), "rnbqkbnr/pppp1ppp/8/4p3/3PP3/8/2P2P2/4K3 w kq e6 0 2 0");
+
+        board.undo_move(third_undo);
+        board.undo_move(second_undo);
+        board.undo_move(first_undo);
+        assert_eq!(board.state, state);
+        assert_eq!(monster.zobrist.compute(&board), hash);
+    }
+
+    #[test]
+    fn check_after_both_moves() {
+        let monster = Monster::create();
+        let mut board = monster.from_fen("4k3/8/8/8/8/8/4r3/4K3 w - - 0 1 0");
+
+        // White's king can be in check after their first move, as long as it's out of check after their second.
+        assert_eq!(board.generate_legal_moves(NORMAL_MODE).len(), 5);
+
+        let action = board.decode_action("e1d2", NORMAL_MODE).expect("Kd2 should be legal.");
+        board.make_move(&action);
+        assert_eq!(board.generate_legal_moves(NORMAL_MODE).len(), 5);
+    }
+
+    #[test]
+    fn first_move_needs_safe_follow_up() {
+        let monster = Monster::create();
+        let mut board = monster.from_fen("k2rrr2/8/8/8/8/8/P7/4K3 w - - 0 1 0");
+
+        // White's king is in check on e1, with the d, e and f files covered.
+        // Only the king steps to d1, d2, f1 and f2 leave a second move that gets out of check.
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert_eq!(moves.len(), 4);
+        for action in ["a2a3", "e1e2"] {
+            let action = board.decode_action(action, NORMAL_MODE).expect("The move should be generated.");
+            assert!(!moves.contains(&action));
+        }
+    }
+
+    #[test]
+    fn capturing_the_king_wins() {
+        let monster = Monster::create();
+        let mut board = monster.from_fen("4k3/8/8/8/8/8/8/R3K3 w - - 0 1 0");
+
+        let action = board.decode_action("a1a8", NORMAL_MODE).expect("Ra8+ should be legal.");
+        board.make_move(&action);
+        let action = board.decode_action("a8e8", NORMAL_MODE).expect("Rxe8 should be legal.");
+        board.make_move(&action);
+
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert!(moves.is_empty());
+        assert!(matches!(monster.resolution.resolve(&mut board, &moves), GameResults::Win(0)));
+    }
+}
@@ -0,0 +1,10 @@
+mod game;
+mod controller;
+mod resolution;
+mod zobrist;
+mod suite;
+
+pub use game::*;
+pub use controller::*;
+pub use resolution::*;
+pub use zobrist::*;
@@ -0,0 +1,17 @@
+use crate::{
+    board::{game::{Resolution, GameResults}, Board, actions::Move},
+    games::chess::{get_kingless_team, ChessResolution},
+};
+
+#[derive(Debug)]
+pub struct MonsterResolution;
+
+impl<const T: usize> Resolution<T> for MonsterResolution {
+    fn resolve(&self, board: &mut Board<T>, legal_moves: &Vec<Move>) -> GameResults {
+        if let Some(team) = get_kingless_team(board) {
+            return GameResults::Win(board.get_next_team(team));
+        }
+
+        ChessResolution::<T>.resolve(board, legal_moves)
+    }
+}
@@ -0,0 +1,15 @@
+use crate::board::{tests::run_tests};
+use super::Monster;
+
+const TEST_STR: &str = r#"rnbqkbnr/pppppppp/8/8/8/8/2PPPP2/4K3 w kq - 0 1 0 ;D1 10 ;D2 99 ;D3 1976 ;D4 19904 ;D5 204810
+rnbqkbnr/ppp1pppp/8/8/3p4/8/2PPPP2/4K3 w kq - 0 3 0 ;D1 9 ;D2 82 ;D3 2414 ;D4 22758 ;D5 212107
+rnbqkbnr/pp1ppppp/8/2pP4/8/8/2P1PP2/4K3 w kq c6 0 3 0 ;D1 11 ;D2 112 ;D3 2334 ;D4 24433
+rnbqkbnr/pppppppp/8/8/4P3/8/2PP1P2/4K3 w kq - 0 1 1 ;D1 10 ;D2 199 ;D3 2046 ;D4 21446
+r3k2r/ppp2ppp/8/8/8/8/2PPPP2/2B1KB2 w kq - 0 1 0 ;D1 13 ;D2 211 ;D3 4611 ;D4 82996
+4k3/8/8/8/8/8/8/R3K3 w - - 0 1 0 ;D1 15 ;D2 270 ;D3 1166 ;D4 22212
+r3k2r/8/8/8/8/8/8/4K3 w kq - 0 1 0 ;D1 5 ;D2 34 ;D3 884 ;D4 6058"#;
+
+#[test]
+fn monster_perft_suite() {
+    run_tests("Monster Chess", Monster::create(), TEST_STR);
+}
@@ -0,0 +1,17 @@
+use crate::board::{game::ZobristController, zobrist::ZobristHashTable, Board};
+
+/// The same position can come up before or after white's first move, so white's second turn has its own hash.
+#[derive(Debug)]
+pub struct MonsterZobrist;
+
+impl<const T: usize> ZobristController<T> for MonsterZobrist {
+    fn apply(&self, hash: &mut u64, zobrist: &ZobristHashTable<T>, board: &Board<T>) {
+        if board.state.current_turn > 0 {
+            *hash ^= zobrist.table[zobrist.base_len];
+        }
+    }
+
+    fn get_extra_hashes(&self) -> usize {
+        1
+    }
+}
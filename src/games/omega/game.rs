@@ -0,0 +1,158 @@
+use crate::{
+    board::{
+        fen::{FenFullMoves, FenOptions, FenState, FenSubMoves, FenTeamArgument},
+        game::{DefaultZobristController, Game},
+        pieces::Piece,
+        zobrist::ZobristHashTable,
+    },
+    games::chess::{
+        pieces::{BishopPiece, KingPiece, KnightPiece, PawnPiece, QueenPiece, RookPiece},
+        ChessCastlingRights, ChessEnPassant, ChessMoveController, ChessResolution,
+    },
+};
+
+use super::{pieces::{get_omega_pieces, get_omega_regions}, OmegaPostProcess};
+
+const PAWN: &dyn Piece<2> = &PawnPiece { king_promotion: false, first_move_steps: 3, promotion_pieces: None };
+const KNIGHT: &dyn Piece<2> = &KnightPiece;
+const BISHOP: &dyn Piece<2> = &BishopPiece;
+const ROOK: &dyn Piece<2> = &RookPiece;
+const QUEEN: &dyn Piece<2> = &QueenPiece;
+const KING: &dyn Piece<2> = &KingPiece;
+
+pub struct Omega;
+
+impl Omega {
+    /// Omega chess is played on a 10x10 board, with an extra "wizard square" diagonally outside each of its corners.
+    /// The wizard squares need a 12x12 grid, with walls (`*` in FENs) filling the squares that aren't part of the board.
+    /// That grid is 144 squares, more than a `u128` can hold, so it's played on `Game<2>`.
+    ///
+    /// Each team adds two champions and two wizards to chess's pieces, pawns can step up to three squares on their first move
+    /// (and be captured en passant on any square they stepped over), and pawns promote on the opponent's back rank.
+    pub fn create() -> Game<2> {
+        let pieces = get_omega_pieces();
+
+        Game {
+            teams: 2,
+            turns: vec![1, 1],
+            rows: 12,
+            cols: 12,
+            squares: 144,
+            saved_last_moves: 1,
+            regions: get_omega_regions(),
+            zobrist_controller: Box::new(DefaultZobristController),
+            zobrist: ZobristHashTable::<2>::generate(144, 2, 8, 145, || fastrand::u64(0..u64::MAX)),
+            name: String::from("Omega"),
+            pieces: vec![PAWN, KNIGHT, BISHOP, ROOK, QUEEN, KING, &pieces.champion, &pieces.wizard],
+            controller: Box::new(ChessMoveController),
+            resolution: Box::new(ChessResolution),
+            fen_options: FenOptions {
+                state: FenState { first_moves: false, gaps: '*', reserves: false },
+                args: vec![
+                    (
+                        "team to move".to_string(),
+                        Box::new(FenTeamArgument::Teams(vec!['w', 'b'])),
+                    ),
+                    ("castling rights".to_string(), Box::new(ChessCastlingRights::XFen)),
+                    ("en passant".to_string(), Box::new(ChessEnPassant)),
+                    ("half moves".to_string(), Box::new(FenSubMoves)),
+                    ("full moves".to_string(), Box::new(FenFullMoves)),
+                ],
+                post_process: Box::new(OmegaPostProcess),
+                default_fen: String::from("w**********w/*crnbqkbnrc*/*pppppppppp*/*10*/*10*/*10*/*10*/*10*/*10*/*PPPPPPPPPP*/*CRNBQKBNRC*/W**********W w KQkq - 0 1")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::game::NORMAL_MODE;
+
+    use super::Omega;
+
+    fn encode_moves(fen: &str) -> Vec<String> {
+        let omega = Omega::create();
+        let mut board = omega.from_fen(fen);
+        board
+            .generate_legal_moves(NORMAL_MODE)
+            .iter()
+            .map(|action| board.encode_action(action))
+            .collect()
+    }
+
+    #[test]
+    fn fen() {
+        let omega = Omega::create();
+        for fen in [
+            "w**********w/*crnbqkbnrc*/*pppppppppp*/*10*/*10*/*10*/*10*/*10*/*10*/*PPPPPPPPPP*/*CRNBQKBNRC*/W**********W w KQkq - 0 1",
+            "w**********W/*1r3k2r1*/*2p7*/*10*/*3C6*/*10*/*10*/*10*/*10*/*10*/*1R3K2R1*/W**********w b Kq - 3 12",
+        ] {
+            assert_eq!(omega.from_fen(fen).to_fen(), fen);
+        }
+    }
+
+    #[test]
+    fn en_passant() {
+        let omega = Omega::create();
+        let mut board = omega.from_fen("w**********w/*4k5*/*10*/*10*/*10*/*10*/*5p4*/*3p6*/*10*/*4P5*/*5K4*/W**********W w - - 0 1");
+
+        // Pawns can step three squares on their first move, and can be captured en passant on either square they stepped over.
+        let action = board.decode_action("f3f6", NORMAL_MODE).expect("A triple pawn step should be legal.");
+        board.make_move(&action);
+        assert_eq!(board.to_fen(), "w**********w/*4k5*/*10*/*10*/*10*/*10*/*4Pp4*/*3p6*/*10*/*10*/*5K4*/W**********W b - f4 0 1");
+
+        let moves = encode_moves(&board.to_fen());
+        assert!(moves.contains(&"e5f4".to_string()));
+        assert!(moves.contains(&"g6f5".to_string()));
+
+        let action = board.decode_action("e5f4", NORMAL_MODE).expect("Capturing en passant should be legal.");
+        board.make_move(&action);
+        assert_eq!(board.to_fen(), "w**********w/*4k5*/*10*/*10*/*10*/*10*/*5p4*/*10*/*4p5*/*10*/*5K4*/W**********W w - - 0 2");
+    }
+
+    #[test]
+    fn castling() {
+        let omega = Omega::create();
+        let mut board = omega.from_fen("w**********w/*1r3k2r1*/*10*/*10*/*10*/*10*/*10*/*10*/*10*/*10*/*1R3K2R1*/W**********W w KQkq - 0 1");
+
+        // The king castles two squares, even though the rooks start further away than in chess.
+        let action = board.decode_action("g2i2", NORMAL_MODE).expect("Kingside castling should be legal.");
+        board.make_move(&action);
+        assert_eq!(board.to_fen(), "w**********w/*1r3k2r1*/*10*/*10*/*10*/*10*/*10*/*10*/*10*/*10*/*1R4RK2*/W**********W b kq - 1 1");
+
+        let action = board.decode_action("g11e11", NORMAL_MODE).expect("Queenside castling should be legal.");
+        board.make_move(&action);
+        assert_eq!(board.to_fen(), "w**********w/*3kr3r1*/*10*/*10*/*10*/*10*/*10*/*10*/*10*/*10*/*1R4RK2*/W**********W w - - 2 2");
+    }
+
+    #[test]
+    fn walls() {
+        // Walls can't be moved onto, and stop sliding pieces just like the edges of the board would.
+        let moves = encode_moves("w**********w/*R9*/*10*/*10*/*10*/*10*/*10*/*10*/*9k*/*10*/*10*/W**********K w - - 0 1");
+        assert_eq!(moves.iter().filter(|action| action.starts_with("b11")).count(), 18);
+
+        // The wizard squares are still part of the board, though.
+        let moves = encode_moves("w**********w/*9k*/*10*/*10*/*10*/*10*/*10*/*10*/*10*/*10*/*B9*/1**********K w - - 0 1");
+        assert!(moves.contains(&"b2a1".to_string()));
+    }
+
+    #[test]
+    fn observe_keeps_walls() {
+        let omega = Omega::create();
+        let mut board = omega.from_fen("1**********k/*10*/*10*/*10*/*10*/*10*/*10*/*10*/*10*/*10*/*10*/R**********K w - - 0 1");
+
+        // Walls aren't pieces, so they aren't hidden, and the rook still can't slide through them on the observed board.
+        let mut observed = board.observe(0);
+        assert_eq!(observed.state.all_pieces & observed.state.gaps, observed.state.gaps);
+        assert_eq!(observed.generate_legal_moves(NORMAL_MODE), board.generate_legal_moves(NORMAL_MODE));
+    }
+
+    #[test]
+    fn wizard() {
+        let mut moves = encode_moves("w**********w/*9k*/*10*/*10*/*10*/*10*/*10*/*10*/*10*/*10*/*10*/W**********K w - - 0 1");
+        moves.retain(|action| action.starts_with("a1"));
+        moves.sort();
+        assert_eq!(moves, vec!["a1b2", "a1b4", "a1d2"]);
+    }
+}
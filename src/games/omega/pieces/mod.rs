@@ -0,0 +1,42 @@
+use std::sync::OnceLock;
+
+use crate::{board::{pieces::BetzaPiece, regions::{get_rectangle, Region}, PieceType, Rows, Cols}, games::chess::pieces::{BACK_RANK_REGION, CASTLING_REGION}};
+
+/// Omega chess adds its pieces after chess's pieces, so the chess piece types (eg. `KING_PIECE_TYPE`) stay the same.
+pub const CHAMPION_PIECE_TYPE: PieceType = 6;
+pub const WIZARD_PIECE_TYPE: PieceType = 7;
+
+const ROWS: Rows = 12;
+const COLS: Cols = 12;
+
+pub struct OmegaPieces {
+    /// The champion steps one square orthogonally, or jumps two squares orthogonally or diagonally.
+    pub champion: BetzaPiece,
+    /// The wizard steps one square diagonally, or jumps like a camel (one square and three squares.)
+    pub wizard: BetzaPiece,
+}
+
+/// Gets the pieces Omega chess adds to chess.
+pub fn get_omega_pieces() -> &'static OmegaPieces {
+    static PIECES: OnceLock<OmegaPieces> = OnceLock::new();
+    PIECES.get_or_init(|| OmegaPieces {
+        champion: BetzaPiece::parse('c', "WAD").expect("The champion should have valid Betza notation."),
+        wizard: BetzaPiece::parse('w', "FC").expect("The wizard should have valid Betza notation."),
+    })
+}
+
+/// Gets the regions of the Omega chess board.
+/// The 10x10 board sits inside a 12x12 grid of walls (with the wizard squares in its corners), so the back ranks aren't the edges of the grid,
+/// and the king castles two squares (to the `e` and `i` files) instead of to the usual files.
+pub fn get_omega_regions<const T: usize>() -> Vec<Region<T>> {
+    vec![
+        Region::new(BACK_RANK_REGION, vec![
+            get_rectangle(ROWS - 2..ROWS - 1, 1..COLS - 1, COLS),
+            get_rectangle(1..2, 1..COLS - 1, COLS),
+        ]),
+        Region::new(CASTLING_REGION, vec![
+            get_rectangle(ROWS - 2..ROWS - 1, 4..5, COLS) | get_rectangle(ROWS - 2..ROWS - 1, 8..9, COLS),
+            get_rectangle(1..2, 4..5, COLS) | get_rectangle(1..2, 8..9, COLS),
+        ]),
+    ]
+}
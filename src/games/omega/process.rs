@@ -0,0 +1,12 @@
+use crate::{board::{fen::PostProcess, Board}, games::chess::ChessPostProcess};
+
+#[derive(Debug)]
+pub struct OmegaPostProcess;
+impl<const T: usize> PostProcess<T> for OmegaPostProcess {
+    fn apply(&self, board: &mut Board<T>) {
+        PostProcess::<T>::apply(&ChessPostProcess, board);
+
+        // The walls around the board block sliding pieces, just like pieces would.
+        board.state.all_pieces |= board.state.gaps;
+    }
+}
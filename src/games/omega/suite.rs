@@ -0,0 +1,13 @@
+use crate::board::{tests::run_tests};
+use super::Omega;
+
+const TEST_STR: &str = r#"w**********w/*crnbqkbnrc*/*pppppppppp*/*10*/*10*/*10*/*10*/*10*/*10*/*PPPPPPPPPP*/*CRNBQKBNRC*/W**********W w KQkq - 0 1 ;D1 40 ;D2 1600 ;D3 67202
+w**********w/*1r3k2r1*/*10*/*10*/*10*/*10*/*10*/*10*/*10*/*10*/*1R3K2R1*/W**********W w KQkq - 0 1 ;D1 38 ;D2 1284 ;D3 46519
+w**********w/*4k5*/*10*/*10*/*10*/*3pP5*/*3p6*/*10*/*10*/*10*/*5K4*/W**********W b - f4 0 1 ;D1 14 ;D2 166 ;D3 2298 ;D4 31635
+w**********w/*1r3k4*/*P8p*/*10*/*10*/*10*/*10*/*10*/*10*/*10*/*5K4*/W**********W w - - 0 1 ;D1 23 ;D2 504 ;D3 9917 ;D4 240664
+w**********W/*4k5*/*10*/*3C6*/*10*/*10*/*10*/*10*/*2w7*/*10*/*5K4*/W**********w w - - 0 1 ;D1 22 ;D2 316 ;D3 6627 ;D4 115502"#;
+
+#[test]
+fn omega_perft_suite() {
+    run_tests("Omega", Omega::create(), TEST_STR);
+}
@@ -0,0 +1,101 @@
+use crate::{
+    bitboard::BitBoard,
+    board::{
+        actions::{Move, TheoreticalMove, TurnUpdate},
+        game::{Game, MoveController, ATTACKS_MODE},
+        Board, BoardState,
+    },
+    games::chess::{pieces::KING_PIECE_TYPE, ChessMoveController},
+};
+
+/// Racing Kings is played with the rules of chess, except no team may give check, and the first king to reach the last rank wins.
+/// If white's king reaches the last rank first, black gets one more move to equalize by reaching it as well, drawing the game.
+#[derive(Debug)]
+pub struct RacingKingsMoveController;
+
+impl RacingKingsMoveController {
+    /// Finds which teams have their king on the last rank.
+    pub fn get_finished_teams<const T: usize>(board: &Board<T>) -> Vec<u16> {
+        let kings = board.state.pieces[KING_PIECE_TYPE as usize] & board.state.edges[0].top;
+        (0..board.game.teams)
+            .filter(|team| (kings & board.state.teams[*team as usize]).is_set())
+            .collect()
+    }
+
+    /// Whether or not `team`'s king can be captured by its opponent.
+    fn is_in_check<const T: usize>(board: &Board<T>, team: u16) -> bool {
+        let king: BitBoard<T> = board.state.pieces[KING_PIECE_TYPE as usize] & board.state.teams[team as usize];
+        board.can_move(board.get_next_team(team), king, ATTACKS_MODE)
+    }
+}
+
+impl<const T: usize> MoveController<T> for RacingKingsMoveController {
+    #[allow(unused_variables)]
+    fn transform_moves(&self, board: &mut Board<T>, mode: u16, actions: Vec<Move>) -> Vec<Move> {
+        let finished_teams = RacingKingsMoveController::get_finished_teams(board);
+        // Black can only keep playing after white's king reaches the last rank if black's king can reach it on this move.
+        let can_equalize = finished_teams == vec![0] && board.state.moving_team == 1;
+        if !finished_teams.is_empty() && !can_equalize {
+            return vec![];
+        }
+
+        let mut legal_moves = Vec::with_capacity(actions.len());
+        for action in actions {
+            if self.is_legal(board, &action) {
+                legal_moves.push(action);
+            }
+        }
+
+        if can_equalize {
+            let top = board.state.edges[0].top;
+            let reaches_last_rank = legal_moves.iter().any(|action| match action {
+                Move::Action(action) => action.piece_type == KING_PIECE_TYPE && (BitBoard::<T>::from_lsb(action.to) & top).is_set(),
+                Move::Pass => false,
+            });
+
+            if !reaches_last_rank {
+                return vec![];
+            }
+        }
+
+        legal_moves
+    }
+
+    fn is_legal(&self, board: &mut Board<T>, action: &Move) -> bool {
+        match action {
+            Move::Action(action) => {
+                let team = action.team;
+                let opponent = board.get_next_team(team);
+
+                let undo = board.make_move(&Move::Action(*action));
+                // Neither king can be in check after a move, so checks can't be given either.
+                let is_legal = !RacingKingsMoveController::is_in_check(board, team)
+                    && !RacingKingsMoveController::is_in_check(board, opponent);
+                board.undo_move(undo);
+
+                is_legal
+            }
+            Move::Pass => false,
+        }
+    }
+
+    fn use_pseudolegal(&self) -> bool {
+        true
+    }
+
+    fn encode_action(&self, board: &Board<T>, action: &Move) -> Vec<String> {
+        ChessMoveController::<T>.encode_action(board, action)
+    }
+
+    fn update(&self, action: &Move, state: &BoardState<T>, game: &Game<T>) -> TurnUpdate {
+        ChessMoveController::<T>.update(action, state, game)
+    }
+
+    fn get_theoretical_moves(&self, board: &Board<T>) -> Vec<TheoreticalMove> {
+        ChessMoveController::<T>.get_theoretical_moves(board)
+    }
+
+    fn get_max_available_moves(&self) -> u32 {
+        ChessMoveController::<T>.get_max_available_moves()
+    }
+}
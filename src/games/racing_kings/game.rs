@@ -0,0 +1,61 @@
+use crate::{board::game::Game, games::chess::Chess};
+
+use super::{RacingKingsMoveController, RacingKingsResolution};
+
+pub struct RacingKings;
+
+impl RacingKings {
+    pub fn create() -> Game<1> {
+        let mut game = Chess::create();
+        game.name = String::from("Racing Kings");
+        game.controller = Box::new(RacingKingsMoveController);
+        game.resolution = Box::new(RacingKingsResolution);
+        game.fen_options.default_fen = String::from("8/8/8/8/8/8/krbnNBRK/qrbnNBRQ w - - 0 1");
+        game
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::game::{GameResults, NORMAL_MODE};
+
+    use super::RacingKings;
+
+    #[test]
+    fn checks_are_illegal() {
+        let racing_kings = RacingKings::create();
+        let mut board = racing_kings.from_fen("8/1K6/8/3k4/8/8/8/2R5 w - - 0 1");
+
+        // Rd1+ and Rc5+ would give check.
+        assert!(board.decode_action("c1d1", NORMAL_MODE).is_some());
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert!(moves.iter().all(|action| !["c1d1", "c1c5"].contains(&board.encode_action(action).as_str())));
+    }
+
+    #[test]
+    fn black_equalizes() {
+        let racing_kings = RacingKings::create();
+        let mut board = racing_kings.from_fen("K7/2k5/8/8/8/8/8/8 b - - 0 1");
+
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert!(matches!(racing_kings.resolution.resolve(&mut board, &moves), GameResults::Ongoing));
+
+        let action = board.decode_action("c7c8", NORMAL_MODE).expect("Kc8 should be legal.");
+        board.make_move(&action);
+
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert!(moves.is_empty());
+        assert!(matches!(racing_kings.resolution.resolve(&mut board, &moves), GameResults::Draw));
+    }
+
+    #[test]
+    fn white_wins_race() {
+        let racing_kings = RacingKings::create();
+        let mut board = racing_kings.from_fen("K4R2/2k5/8/8/8/8/8/8 b - - 0 1");
+
+        // Black's king can't reach the last rank, since the rook covers it.
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert!(moves.is_empty());
+        assert!(matches!(racing_kings.resolution.resolve(&mut board, &moves), GameResults::Win(0)));
+    }
+}
@@ -0,0 +1,29 @@
+use crate::{
+    board::{game::{Resolution, GameResults}, Board, actions::Move},
+    games::chess::ChessResolution,
+};
+
+use super::RacingKingsMoveController;
+
+#[derive(Debug)]
+pub struct RacingKingsResolution;
+
+impl<const T: usize> Resolution<T> for RacingKingsResolution {
+    fn resolve(&self, board: &mut Board<T>, legal_moves: &Vec<Move>) -> GameResults {
+        match RacingKingsMoveController::get_finished_teams(board)[..] {
+            // Both kings reached the last rank, so black equalized.
+            [_, _] => GameResults::Draw,
+            // White's king reached the last rank, but black may still be able to equalize.
+            [0] if !legal_moves.is_empty() => GameResults::Ongoing,
+            [team] => GameResults::Win(team),
+            _ => {
+                // Kings can't be in check, so running out of moves is always a stalemate.
+                if legal_moves.is_empty() {
+                    GameResults::Draw
+                } else {
+                    ChessResolution::<T>.resolve(board, legal_moves)
+                }
+            }
+        }
+    }
+}
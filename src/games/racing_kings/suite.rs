@@ -0,0 +1,16 @@
+use crate::board::{tests::run_tests};
+use super::RacingKings;
+
+const TEST_STR: &str = r#"8/8/8/8/8/8/krbnNBRK/qrbnNBRQ w - - 0 1 ;D1 21 ;D2 421 ;D3 11264 ;D4 296242
+8/8/8/8/8/6K1/krbnNBR1/qrbnNBRQ b - - 1 1 ;D1 20 ;D2 441 ;D3 11289 ;D4 303758
+4brn1/2K2k2/8/8/8/8/8/8 w - - 0 1 ;D1 6 ;D2 33 ;D3 178 ;D4 3151 ;D5 12981
+8/1K6/8/3k4/8/8/8/2R5 w - - 0 1 ;D1 19 ;D2 85 ;D3 1573 ;D4 8621 ;D5 156468
+8/8/2K5/8/5k2/8/1r6/8 w - - 0 1 ;D1 5 ;D2 97 ;D3 595 ;D4 9390 ;D5 58207
+K7/8/1k6/8/8/8/8/8 b - - 0 1 ;D1 0 ;D2 0
+K7/2k5/8/8/8/8/8/7R b - - 0 1 ;D1 6 ;D2 0
+K4R2/2k5/8/8/8/8/8/8 b - - 0 1 ;D1 0 ;D2 0"#;
+
+#[test]
+fn racing_kings_perft_suite() {
+    run_tests("Racing Kings", RacingKings::create(), TEST_STR);
+}
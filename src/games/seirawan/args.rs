@@ -0,0 +1,86 @@
+use crate::{
+    bitboard::BitBoard,
+    board::{fen::{FenArgument, FenDecodeError}, Board},
+    games::chess::{pieces::{get_back_rank, KING_PIECE_TYPE, ROOK_PIECE_TYPE}, ChessCastlingRights},
+};
+
+/// Castling rights followed by the files of each team's other back rank pieces that can still gate, such as `KQBCDFGkqbcdfg`.
+/// Castling rights imply gating rights on the king's and castling rooks' squares, so their files aren't repeated.
+#[derive(Debug)]
+pub struct SeirawanGatingRights;
+
+impl<const T: usize> FenArgument<T> for SeirawanGatingRights {
+    fn decode(&self, board: &mut Board<T>, arg: &str) -> Result<(), FenDecodeError> {
+        // This only sets the first move of rooks with castling rights, and leaves every other piece alone.
+        ChessCastlingRights::XFen.decode(board, arg)?;
+
+        let mut back_ranks = BitBoard::new();
+        for team in 0..board.game.teams {
+            back_ranks |= get_back_rank(board, team) & board.state.teams[team as usize];
+        }
+        board.state.first_move &= !(back_ranks & !board.state.pieces[ROOK_PIECE_TYPE as usize]);
+
+        if arg == "-" {
+            return Ok(());
+        }
+
+        let cols = board.state.cols;
+        for char in arg.chars() {
+            let team = if char.is_ascii_uppercase() { 0 } else { 1 };
+            let pieces = get_back_rank(board, team) & board.state.teams[team as usize];
+
+            let gating_square = match char.to_ascii_lowercase() {
+                'k' | 'q' => pieces & board.state.pieces[KING_PIECE_TYPE as usize],
+                file @ 'a'..='z' if ((file as u16) - ('a' as u16)) < cols => {
+                    pieces & board.state.files[((file as u16) - ('a' as u16)) as usize]
+                }
+                _ => {
+                    return Err(FenDecodeError::InvalidArgument(format!(
+                        "'{char}' is not a valid gating rights character."
+                    )));
+                }
+            };
+
+            // Rooks with castling rights have already been handled when decoding the castling rights.
+            board.state.first_move |= gating_square & !board.state.pieces[ROOK_PIECE_TYPE as usize];
+        }
+
+        Ok(())
+    }
+
+    fn encode(&self, board: &Board<T>) -> String {
+        let castling_rights = ChessCastlingRights::XFen.encode(board);
+
+        let mut rights = String::new();
+        for team in 0..board.game.teams {
+            let mut team_rights = castling_rights
+                .chars()
+                .filter(|char| char.is_ascii_uppercase() == (team == 0) && *char != '-')
+                .collect::<String>();
+
+            let pieces = get_back_rank(board, team) & board.state.teams[team as usize] & board.state.first_move;
+            let king = pieces & board.state.pieces[KING_PIECE_TYPE as usize];
+
+            // Rooks are only listed in the castling rights while the king can still castle, and `K` or `Q` imply the king itself.
+            let mut gating_squares = pieces;
+            if king.is_set() {
+                gating_squares &= !board.state.pieces[ROOK_PIECE_TYPE as usize];
+                if team_rights.contains(|char: char| char.eq_ignore_ascii_case(&'k') || char.eq_ignore_ascii_case(&'q')) {
+                    gating_squares &= !king;
+                }
+            }
+
+            for square in gating_squares.iter_set_bits(board.state.squares) {
+                let file = (b'a' + (square % board.state.cols) as u8) as char;
+                team_rights.push(if team == 0 { file.to_ascii_uppercase() } else { file });
+            }
+            rights.push_str(&team_rights);
+        }
+
+        if rights.is_empty() {
+            String::from("-")
+        } else {
+            rights
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use crate::{
+    bitboard::{BitBoard, Direction},
+    board::{
+        actions::{Action, ActionInfo, HistoryMove, HistoryUpdate, IndexedPreviousBoard, Move, PreviousReserve, TheoreticalMove, TurnUpdate},
+        game::{Game, get_theoretical_moves_bound, MoveController},
+        pieces::PieceSymbol,
+        Board, BoardState, PieceType,
+    },
+    games::chess::{
+        pieces::{get_back_rank, get_castling_squares, CASTLING_MOVE, KING_PIECE_TYPE, PAWN_PIECE_TYPE},
+        ChessMoveController,
+    },
+};
+
+/// Gating moves store the gated piece's type (plus one) in `Action::info`.
+/// When castling gates the piece onto the rook's square instead of the king's, this is added on top.
+pub const ROOK_SQUARE_GATING: ActionInfo = 8;
+
+/// Gets the `info` of a move that gates `piece_type` onto the square it leaves (or the castling rook's square, if `rook_square` is set.)
+pub fn gating_move(piece_type: PieceType, rook_square: bool) -> ActionInfo {
+    let info = piece_type + 1;
+    if rook_square {
+        info + ROOK_SQUARE_GATING
+    } else {
+        info
+    }
+}
+
+/// Gets the piece type `action` gates, and the square it's gated onto.
+/// Pawns never start on the back rank, so their `info` is always a promotion instead.
+pub fn get_gating(action: &Action) -> Option<(PieceType, u16)> {
+    let from = action.from?;
+    if action.piece_type == PAWN_PIECE_TYPE {
+        return None;
+    }
+
+    match action.info {
+        0 => None,
+        info if info > ROOK_SQUARE_GATING => Some((info - ROOK_SQUARE_GATING - 1, action.to)),
+        info => Some((info - 1, from)),
+    }
+}
+
+fn is_castling(action: &Action) -> bool {
+    action.piece_type == KING_PIECE_TYPE && action.move_type == CASTLING_MOVE
+}
+
+/// Seirawan chess is played with the rules of chess, but the hawk and elephant start in each team's hand.
+/// Right after a piece on the back rank makes its first move, a piece from the hand can be gated onto the square it left.
+/// Which back rank pieces haven't moved yet is tracked through `BoardState::first_move`.
+#[derive(Debug)]
+pub struct SeirawanMoveController;
+
+impl<const T: usize> MoveController<T> for SeirawanMoveController {
+    fn transform_moves(&self, board: &mut Board<T>, mode: u16, actions: Vec<Move>) -> Vec<Move> {
+        ChessMoveController::<T>.transform_moves(board, mode, actions)
+    }
+
+    fn is_legal(&self, board: &mut Board<T>, action: &Move) -> bool {
+        if let Move::Action(Action { from: None, .. }) = action {
+            return false;
+        }
+
+        ChessMoveController::<T>.is_legal(board, action)
+    }
+
+    fn use_pseudolegal(&self) -> bool {
+        true
+    }
+
+    fn add_moves(&self, board: &Board<T>, actions: &mut Vec<Move>) {
+        // Pieces in hand can only ever be gated, not dropped.
+        actions.retain(|action| !matches!(action, Move::Action(Action { from: None, .. })));
+
+        let team = board.state.moving_team;
+        let hand = &board.state.reserves[team as usize];
+        if hand.iter().all(|count| *count == 0) {
+            return;
+        }
+
+        let gating_squares = get_back_rank(board, team) & board.state.first_move & board.state.teams[team as usize];
+        for ind in 0..actions.len() {
+            let action = match actions[ind] {
+                Move::Action(action) => action,
+                Move::Pass => continue,
+            };
+            let from = match action.from {
+                Some(from) if (gating_squares & BitBoard::from_lsb(from)).is_set() => from,
+                _ => continue,
+            };
+
+            // Castling can gate onto either the king's or the rook's square, as long as neither of them ends up there.
+            let mut rook_squares = vec![];
+            if is_castling(&action) {
+                let dir = if from < action.to { Direction::RIGHT } else { Direction::LEFT };
+                let (king_dest, rook_dest) = get_castling_squares(board, team, dir);
+                if from != king_dest && from != rook_dest {
+                    rook_squares.push(false);
+                }
+                if action.to != king_dest && action.to != rook_dest {
+                    rook_squares.push(true);
+                }
+            } else {
+                rook_squares.push(false);
+            }
+
+            for (piece_type, count) in hand.iter().enumerate() {
+                if *count == 0 {
+                    continue;
+                }
+
+                for rook_square in &rook_squares {
+                    actions.push(Move::Action(Action {
+                        info: gating_move(piece_type as PieceType, *rook_square),
+                        ..action
+                    }));
+                }
+            }
+        }
+    }
+
+    fn make_move(&self, board: &mut Board<T>, action: &Action) -> Option<HistoryMove<T>> {
+        let (gated_piece_type, square) = match get_gating(action) {
+            Some(gating) => gating,
+            None => return board.game.pieces[action.piece_type as usize].make_move(board, action),
+        };
+
+        let team = action.team as usize;
+        let gated_piece_type = gated_piece_type as usize;
+        let square = BitBoard::from_lsb(square);
+
+        // These are saved before the move is made, so undoing the move restores them no matter which order the updates are applied in.
+        let updates = vec![
+            HistoryUpdate::Team(IndexedPreviousBoard(team, board.state.teams[team])),
+            HistoryUpdate::Piece(IndexedPreviousBoard(gated_piece_type, board.state.pieces[gated_piece_type])),
+            HistoryUpdate::Reserve(PreviousReserve(team, gated_piece_type, board.state.reserves[team][gated_piece_type])),
+        ];
+
+        let base_action = Action { info: 0, ..*action };
+        let mut history_move = board.game.pieces[action.piece_type as usize].make_move(board, &base_action)?;
+        history_move.action = Move::Action(*action);
+        if let Some(last_move) = board.history.back_mut() {
+            *last_move = Move::Action(*action);
+        }
+        history_move.add_updates(updates);
+
+        board.state.teams[team] |= square;
+        board.state.pieces[gated_piece_type] |= square;
+        board.state.all_pieces |= square;
+        board.state.reserves[team][gated_piece_type] -= 1;
+
+        Some(history_move)
+    }
+
+    /// Gating moves add the gated piece after the move (eg. `b1c3h`).
+    /// When castling gates onto the rook's square, the move is written from the rook's square to the king's square instead (eg. `h1e1e`.)
+    fn encode_action(&self, board: &Board<T>, action: &Move) -> Vec<String> {
+        let (action, gated_piece_type, square) = match action {
+            Move::Action(action) => match get_gating(action) {
+                Some((gated_piece_type, square)) => (action, gated_piece_type, square),
+                None => return ChessMoveController::<T>.encode_action(board, &Move::Action(*action)),
+            },
+            Move::Pass => return ChessMoveController::<T>.encode_action(board, action),
+        };
+
+        let symbol = match board.game.pieces[gated_piece_type as usize].get_piece_symbol() {
+            PieceSymbol::Char(symbol) => symbol.to_string(),
+            _ => String::new(),
+        };
+
+        let from = action.from.expect("Gating moves should have a from square.");
+        if square != from {
+            return vec![format!("{}{}{}", board.encode_position(action.to), board.encode_position(from), symbol)];
+        }
+
+        ChessMoveController::<T>
+            .encode_action(board, &Move::Action(Action { info: 0, ..*action }))
+            .into_iter()
+            .map(|encoded| format!("{encoded}{symbol}"))
+            .collect()
+    }
+
+    fn update(&self, action: &Move, state: &BoardState<T>, game: &Game<T>) -> TurnUpdate {
+        ChessMoveController::<T>.update(action, state, game)
+    }
+
+    fn get_theoretical_moves(&self, board: &Board<T>) -> Vec<TheoreticalMove> {
+        get_theoretical_moves_bound(board, (2 * ROOK_SQUARE_GATING) + 1, false)
+    }
+
+    fn get_max_available_moves(&self) -> u32 {
+        ChessMoveController::<T>.get_max_available_moves() * 3
+    }
+}
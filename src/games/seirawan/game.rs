@@ -0,0 +1,100 @@
+use crate::{
+    board::{game::Game, zobrist::ZobristHashTable},
+    games::chess::Chess,
+};
+
+use super::{pieces::get_seirawan_pieces, SeirawanGatingRights, SeirawanMoveController};
+
+pub struct Seirawan;
+
+impl Seirawan {
+    /// Seirawan chess (S-chess) is played with the rules of chess, but each team starts with a hawk (`H`) and an elephant (`E`) in hand.
+    /// They can't be dropped, but can be gated onto a back rank square right after the piece on it first moves (see `SeirawanMoveController`.)
+    pub fn create() -> Game<1> {
+        let mut game = Chess::create();
+        game.name = String::from("Seirawan");
+
+        let pieces = get_seirawan_pieces();
+        game.pieces.push(&pieces.hawk);
+        game.pieces.push(&pieces.elephant);
+        game.controller = Box::new(SeirawanMoveController);
+        game.zobrist = ZobristHashTable::<1>::generate(64, 2, 8, 65, || fastrand::u64(0..u64::MAX));
+
+        game.fen_options.state.reserves = true;
+        game.fen_options.args[1] = ("gating rights".to_string(), Box::new(SeirawanGatingRights));
+        game.fen_options.default_fen = String::from("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR[HEhe] w KQBCDFGkqbcdfg - 0 1");
+        game
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::game::NORMAL_MODE;
+
+    use super::Seirawan;
+
+    fn encode_moves(fen: &str) -> Vec<String> {
+        let seirawan = Seirawan::create();
+        let mut board = seirawan.from_fen(fen);
+        board
+            .generate_legal_moves(NORMAL_MODE)
+            .iter()
+            .map(|action| board.encode_action(action))
+            .collect()
+    }
+
+    #[test]
+    fn fen() {
+        let seirawan = Seirawan::create();
+        for fen in [
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR[HEhe] w KQBCDFGkqbcdfg - 0 1",
+            "rnbqkb1r/pppppppp/5n2/8/8/5N2/PPPPPPPP/RNBQKBER[Hhe] w KQBCDkqbcdf - 2 2",
+            "4k3/8/8/8/8/8/8/R4K1R[H] w AH - 0 1",
+            "4k3/8/8/8/8/8/8/4K3[] w - - 0 1",
+        ] {
+            assert_eq!(seirawan.from_fen(fen).to_fen(), fen);
+        }
+    }
+
+    #[test]
+    fn gating() {
+        let seirawan = Seirawan::create();
+        let mut board = seirawan.default();
+        let state = board.state.clone();
+
+        // Pieces in hand can't be dropped, so the only extra moves are the knight moves gating a hawk or an elephant.
+        let moves = encode_moves("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR[HEhe] w KQBCDFGkqbcdfg - 0 1");
+        assert_eq!(moves.len(), 20 + (4 * 2));
+
+        let action = board.decode_action("g1f3e", NORMAL_MODE).expect("Nf3 gating an elephant should be legal.");
+        assert_eq!(board.encode_action(&action), "g1f3e");
+        let undo = board.make_move(&action);
+        assert_eq!(board.to_fen(), "rnbqkbnr/pppppppp/8/8/8/5N2/PPPPPPPP/RNBQKBER[Hhe] b KQBCDFkqbcdfg - 1 1");
+
+        board.undo_move(undo);
+        assert_eq!(board.state, state);
+
+        // Pieces that have already moved can't gate.
+        let moves = encode_moves("rnbqkbnr/pppppppp/8/8/8/5N2/PPPPPPPP/RNBQKB1R[HEhe] w KQBCDFkqbcdfg - 0 1");
+        assert!(moves.contains(&"f3g1".to_string()));
+        assert!(!moves.contains(&"f3g1h".to_string()));
+    }
+
+    #[test]
+    fn castling_gating() {
+        let seirawan = Seirawan::create();
+        let fen = "r3k2r/8/8/8/8/8/8/R3K2R[H] w KQkq - 0 1";
+
+        // Castling can gate onto either the king's square or the rook's square.
+        let mut board = seirawan.from_fen(fen);
+        let action = board.decode_action("e1g1h", NORMAL_MODE).expect("Castling gating onto e1 should be legal.");
+        board.make_move(&action);
+        assert_eq!(board.to_fen(), "r3k2r/8/8/8/8/8/8/R3HRK1[] b Akq - 1 1");
+
+        let mut board = seirawan.from_fen(fen);
+        let action = board.decode_action("h1e1h", NORMAL_MODE).expect("Castling gating onto h1 should be legal.");
+        assert_eq!(board.encode_action(&action), "h1e1h");
+        board.make_move(&action);
+        assert_eq!(board.to_fen(), "r3k2r/8/8/8/8/8/8/R4RKH[] b Akq - 1 1");
+    }
+}
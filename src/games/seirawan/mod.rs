@@ -0,0 +1,9 @@
+mod game;
+mod args;
+mod controller;
+mod suite;
+pub mod pieces;
+
+pub use game::*;
+pub use args::*;
+pub use controller::*;
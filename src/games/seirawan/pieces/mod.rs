@@ -0,0 +1,23 @@
+use std::sync::OnceLock;
+
+use crate::board::{pieces::BetzaPiece, PieceType};
+
+/// Seirawan chess adds its pieces after chess's pieces, so the chess piece types (eg. `KING_PIECE_TYPE`) stay the same.
+pub const HAWK_PIECE_TYPE: PieceType = 6;
+pub const ELEPHANT_PIECE_TYPE: PieceType = 7;
+
+pub struct SeirawanPieces {
+    /// The hawk moves as a bishop or a knight.
+    pub hawk: BetzaPiece,
+    /// The elephant moves as a rook or a knight.
+    pub elephant: BetzaPiece,
+}
+
+/// Gets the pieces Seirawan chess adds to chess.
+pub fn get_seirawan_pieces() -> &'static SeirawanPieces {
+    static PIECES: OnceLock<SeirawanPieces> = OnceLock::new();
+    PIECES.get_or_init(|| SeirawanPieces {
+        hawk: BetzaPiece::parse('h', "BN").expect("The hawk should have valid Betza notation."),
+        elephant: BetzaPiece::parse('e', "RN").expect("The elephant should have valid Betza notation."),
+    })
+}
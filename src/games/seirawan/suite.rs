@@ -0,0 +1,13 @@
+use crate::board::{tests::run_tests};
+use super::Seirawan;
+
+const TEST_STR: &str = r#"rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR[HEhe] w KQBCDFGkqbcdfg - 0 1 ;D1 28 ;D2 784 ;D3 24830 ;D4 782599
+r3k2r/pppq1ppp/2nbbn2/3pp3/3PP3/2NBBN2/PPPQ1PPP/R3K2R[HEhe] w KQkq - 0 1 ;D1 65 ;D2 4222 ;D3 229947
+r3k3/1P6/8/3pP3/8/8/8/4K2R[Eh] w Kq d6 0 1 ;D1 45 ;D2 1092 ;D3 37193
+r3k2r/8/8/8/8/8/8/R4K1R[HE] w AHkq - 0 1 ;D1 62 ;D2 1249 ;D3 48552
+rnbqk2r/pppp1ppp/5n2/2b1p3/4P3/5N2/PPPP1PPP/RNBQKB1R[HEhe] b KQBCDFkqbcd - 0 1 ;D1 51 ;D2 2351 ;D3 108255"#;
+
+#[test]
+fn seirawan_perft_suite() {
+    run_tests("Seirawan", Seirawan::create(), TEST_STR);
+}
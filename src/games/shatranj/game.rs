@@ -0,0 +1,101 @@
+use crate::{
+    board::{fen::FenUnused, game::Game},
+    games::chess::{pieces::{KING_PIECE_TYPE, PAWN_PIECE_TYPE}, Chess},
+};
+
+use super::{pieces::{get_shatranj_pieces, ALFIL_PIECE_TYPE, FERZ_PIECE_TYPE, SHATRANJ_PAWN}, ShatranjResolution};
+
+pub struct Shatranj;
+
+impl Shatranj {
+    /// Shatranj is the ancestor of chess, with an alfil (`B`) instead of a bishop and a ferz (`Q`) instead of a queen.
+    /// Pawns only step one square at a time and promote to a ferz, and there's no castling.
+    /// Besides checkmate, a team wins by stalemating the other team or by capturing all of its pieces besides its king.
+    pub fn create() -> Game<1> {
+        let mut game = Chess::create();
+        game.name = String::from("Shatranj");
+
+        let pieces = get_shatranj_pieces();
+        game.pieces[PAWN_PIECE_TYPE as usize] = SHATRANJ_PAWN;
+        game.pieces[ALFIL_PIECE_TYPE as usize] = &pieces.alfil;
+        game.pieces[FERZ_PIECE_TYPE as usize] = &pieces.ferz;
+        game.pieces[KING_PIECE_TYPE as usize] = &pieces.king;
+        game.resolution = Box::new(ShatranjResolution);
+
+        // There's no castling, but its FEN field is kept as a placeholder.
+        game.fen_options.args[1] = ("castling rights".to_string(), Box::new(FenUnused));
+
+        game.fen_options.default_fen = String::from("rnbkqbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBKQBNR w - - 0 1");
+        game
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::game::{GameResults, NORMAL_MODE};
+
+    use super::Shatranj;
+
+    fn encode_moves(fen: &str) -> Vec<String> {
+        let shatranj = Shatranj::create();
+        let mut board = shatranj.from_fen(fen);
+        board
+            .generate_legal_moves(NORMAL_MODE)
+            .iter()
+            .map(|action| board.encode_action(action))
+            .collect()
+    }
+
+    #[test]
+    fn fen() {
+        let shatranj = Shatranj::create();
+        let board = shatranj.default();
+        assert_eq!(board.to_fen(), "rnbkqbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBKQBNR w - - 0 1");
+    }
+
+    #[test]
+    fn pieces() {
+        // Pawns can't double step, and alfils jump over the pawns in front of them.
+        let moves = encode_moves("rnbkqbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBKQBNR w - - 0 1");
+        assert_eq!(moves.len(), 16);
+        assert!(!moves.contains(&"e2e4".to_string()));
+        assert!(moves.contains(&"c1a3".to_string()));
+        assert!(moves.contains(&"c1e3".to_string()));
+
+        // Pawns can only promote to a ferz.
+        let moves = encode_moves("8/1P2k3/8/8/8/8/8/4K3 w - - 0 1");
+        assert!(moves.contains(&"b7b8q".to_string()));
+        for promotion in ["b7b8n", "b7b8b", "b7b8r"] {
+            assert!(!moves.contains(&promotion.to_string()));
+        }
+    }
+
+    #[test]
+    fn stalemate_wins() {
+        let shatranj = Shatranj::create();
+        let mut board = shatranj.from_fen("k7/2Q5/1K6/8/8/8/8/8 b - - 0 1");
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert!(moves.is_empty());
+        assert!(matches!(shatranj.resolution.resolve(&mut board, &moves), GameResults::Win(0)));
+    }
+
+    #[test]
+    fn bare_king() {
+        let shatranj = Shatranj::create();
+
+        // Black's king is bare, and can't capture white's rook, so white wins.
+        let mut board = shatranj.from_fen("8/3k4/8/8/8/8/R7/4K3 b - - 0 1");
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert!(matches!(shatranj.resolution.resolve(&mut board, &moves), GameResults::Win(0)));
+
+        // Black's king can bare white's king in return, so the game goes on.
+        let mut board = shatranj.from_fen("8/3k4/3R4/8/8/8/8/4K3 b - - 0 1");
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert!(matches!(shatranj.resolution.resolve(&mut board, &moves), GameResults::Ongoing));
+
+        let action = board.decode_action("d7d6", NORMAL_MODE).expect("Kxd6 should be legal.");
+        board.make_move(&action);
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert!(matches!(shatranj.resolution.resolve(&mut board, &moves), GameResults::Draw));
+    }
+}
@@ -0,0 +1,7 @@
+mod game;
+mod resolution;
+mod suite;
+pub mod pieces;
+
+pub use game::*;
+pub use resolution::*;
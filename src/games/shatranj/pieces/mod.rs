@@ -0,0 +1,36 @@
+use std::sync::OnceLock;
+
+use crate::{
+    board::{pieces::{BetzaPiece, Piece}, PieceType},
+    games::chess::pieces::{PawnPiece, BISHOP_PIECE_TYPE, QUEEN_PIECE_TYPE},
+};
+
+/// Shatranj's pieces take the place of chess's, so the alfil and ferz use the piece types of the bishop and queen they became.
+pub const ALFIL_PIECE_TYPE: PieceType = BISHOP_PIECE_TYPE;
+pub const FERZ_PIECE_TYPE: PieceType = QUEEN_PIECE_TYPE;
+
+/// Pawns can't double step, and can only promote to a ferz.
+pub const SHATRANJ_PAWN: &dyn Piece<1> = &PawnPiece {
+    king_promotion: false,
+    first_move_steps: 1,
+    promotion_pieces: Some(&[FERZ_PIECE_TYPE]),
+};
+
+pub struct ShatranjPieces {
+    /// The king steps one square in any direction, and can't castle.
+    pub king: BetzaPiece,
+    /// The alfil jumps two squares diagonally, over any piece in between.
+    pub alfil: BetzaPiece,
+    /// The ferz steps one square diagonally.
+    pub ferz: BetzaPiece,
+}
+
+/// Gets the pieces Shatranj uses instead of chess's bishop, queen and king.
+pub fn get_shatranj_pieces() -> &'static ShatranjPieces {
+    static PIECES: OnceLock<ShatranjPieces> = OnceLock::new();
+    PIECES.get_or_init(|| ShatranjPieces {
+        king: BetzaPiece::parse('k', "K").expect("The king should have valid Betza notation."),
+        alfil: BetzaPiece::parse('b', "A").expect("The alfil should have valid Betza notation."),
+        ferz: BetzaPiece::parse('q', "F").expect("The ferz should have valid Betza notation."),
+    })
+}
@@ -0,0 +1,55 @@
+use crate::{
+    bitboard::BitBoard,
+    board::{game::{Resolution, GameResults}, Board, actions::Move},
+    games::chess::pieces::KING_PIECE_TYPE,
+};
+
+/// Gets `team`'s pieces other than its king.
+pub fn get_non_royal_pieces<const T: usize>(board: &Board<T>, team: u16) -> BitBoard<T> {
+    board.state.teams[team as usize] & !board.state.pieces[KING_PIECE_TYPE as usize]
+}
+
+/// In Shatranj, a team loses once it's checkmated, stalemated or left with a bare king.
+#[derive(Debug)]
+pub struct ShatranjResolution;
+
+impl<const T: usize> Resolution<T> for ShatranjResolution {
+    fn resolve(&self, board: &mut Board<T>, legal_moves: &Vec<Move>) -> GameResults {
+        let team = board.state.moving_team;
+        let opponent = board.get_next_team(team);
+
+        let pieces = get_non_royal_pieces(board, team);
+        let opponent_pieces = get_non_royal_pieces(board, opponent);
+
+        if pieces.is_empty() && opponent_pieces.is_empty() {
+            return GameResults::Draw;
+        }
+
+        if opponent_pieces.is_empty() {
+            return GameResults::Win(team);
+        }
+
+        if pieces.is_empty() {
+            // A bared king gets one move to bare the other king in return, which draws the game instead.
+            let can_bare_back = opponent_pieces.count_ones() == 1 && legal_moves.iter().any(|action| match action {
+                Move::Action(action) => (BitBoard::from_lsb(action.to) & opponent_pieces).is_set(),
+                Move::Pass => false,
+            });
+
+            return if can_bare_back {
+                GameResults::Ongoing
+            } else {
+                GameResults::Win(opponent)
+            };
+        }
+
+        if legal_moves.is_empty() {
+            // Stalemate is a loss for the stalemated team, just like checkmate.
+            GameResults::Win(opponent)
+        } else if board.state.sub_moves == 100 {
+            GameResults::Draw
+        } else {
+            GameResults::Ongoing
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use crate::board::{tests::run_tests};
+use super::Shatranj;
+
+const TEST_STR: &str = r#"rnbkqbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBKQBNR w - - 0 1 ;D1 16 ;D2 256 ;D3 4176 ;D4 68122
+4k3/1P6/8/8/3q4/8/6p1/R3K2R w - - 0 1 ;D1 24 ;D2 235 ;D3 5878 ;D4 58240
+rn1kq1nr/ppp2ppp/3bp3/3p4/3P4/4PB2/PPP2PPP/RN1KQ1NR w - - 0 1 ;D1 17 ;D2 342 ;D3 6254 ;D4 131684
+8/2k5/8/3Q4/8/5B2/8/4K2R b - - 0 1 ;D1 7 ;D2 140 ;D3 779 ;D4 17139"#;
+
+#[test]
+fn shatranj_perft_suite() {
+    run_tests("Shatranj", Shatranj::create(), TEST_STR);
+}
@@ -0,0 +1,54 @@
+use crate::board::{
+    fen::{FenArgument, FenDecodeError, FenReserves},
+    pieces::PieceSymbol,
+    Board,
+};
+
+use super::pieces::{BISHOP, GOLD, KNIGHT, LANCE, PAWN, ROOK, SILVER};
+
+/// The order pieces in hand are written in SFEN.
+const HAND_ORDER: [u16; 7] = [ROOK, BISHOP, GOLD, SILVER, KNIGHT, LANCE, PAWN];
+
+/// The pieces in each player's hand in SFEN, such as `2Pb` (or `-` if neither player has any.)
+#[derive(Debug)]
+pub struct ShogiHand;
+
+impl<const T: usize> FenArgument<T> for ShogiHand {
+    fn encode(&self, board: &Board<T>) -> String {
+        let mut hand = String::new();
+        for team in 0..board.state.reserves.len() {
+            for piece_type in HAND_ORDER {
+                let count = board.state.reserves[team][piece_type as usize];
+                if count == 0 {
+                    continue;
+                }
+
+                if count > 1 {
+                    hand.push_str(&count.to_string());
+                }
+
+                if let PieceSymbol::Char(char) = board.game.pieces[piece_type as usize].get_piece_symbol() {
+                    hand.push(if team == 0 {
+                        char.to_ascii_uppercase()
+                    } else {
+                        char.to_ascii_lowercase()
+                    });
+                }
+            }
+        }
+
+        if hand.is_empty() {
+            "-".to_string()
+        } else {
+            hand
+        }
+    }
+
+    fn decode(&self, board: &mut Board<T>, arg: &str) -> Result<(), FenDecodeError> {
+        if arg == "-" {
+            return FenReserves::decode_reserves(board, arg);
+        }
+
+        FenReserves::decode_reserves(board, &format!("[{arg}]"))
+    }
+}
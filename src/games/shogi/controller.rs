@@ -0,0 +1,109 @@
+use crate::{
+    board::{
+        actions::{Move, TheoreticalMove},
+        game::{get_theoretical_moves_bound, MoveController, ATTACKS_MODE, NORMAL_MODE},
+        pieces::PieceSymbol,
+        Board,
+    },
+};
+
+use super::pieces::{KING, PAWN};
+
+/// Encodes a square in USI notation, where files are numbered from 9 to 1 (left to right), and ranks are lettered from `a` to `i` (top to bottom.)
+pub fn encode_usi_position<const T: usize>(board: &Board<T>, pos: u16) -> String {
+    let row = pos / board.state.cols;
+    let col = pos % board.state.cols;
+    format!("{}{}", board.state.cols - col, (b'a' + row as u8) as char)
+}
+
+#[derive(Debug)]
+pub struct ShogiMoveController;
+
+impl ShogiMoveController {
+    fn is_in_check<const T: usize>(board: &Board<T>, team: u16) -> bool {
+        let king_board = board.state.pieces[KING as usize] & board.state.teams[team as usize];
+        board.can_move(board.get_next_team(team), king_board, ATTACKS_MODE)
+    }
+}
+
+impl<const T: usize> MoveController<T> for ShogiMoveController {
+    #[allow(unused_variables)]
+    fn transform_moves(&self, board: &mut Board<T>, mode: u16, actions: Vec<Move>) -> Vec<Move> {
+        let mut legal_moves = Vec::with_capacity(actions.len());
+        for action in actions {
+            if self.is_legal(board, &action) {
+                legal_moves.push(action);
+            }
+        }
+        legal_moves
+    }
+
+    fn is_legal(&self, board: &mut Board<T>, action: &Move) -> bool {
+        match action {
+            Move::Action(action) => {
+                let current_team = board.state.moving_team;
+
+                let undo = board.make_move(&Move::Action(*action));
+                if ShogiMoveController::is_in_check(board, current_team) {
+                    board.undo_move(undo);
+                    return false;
+                }
+
+                /*
+                    Uchifuzume: dropping a pawn to give an immediate checkmate is illegal.
+                    Pawns only attack the square in front of them, so we only need to check this when the drop gives check.
+                */
+                let mut legal = true;
+                if action.from.is_none() && action.piece_type == PAWN {
+                    let opponent = board.state.moving_team;
+                    if ShogiMoveController::is_in_check(board, opponent) {
+                        let replies = board.generate_moves(NORMAL_MODE);
+                        legal = replies.iter().any(|reply| self.is_legal(board, reply));
+                    }
+                }
+
+                board.undo_move(undo);
+                legal
+            }
+            Move::Pass => false,
+        }
+    }
+
+    fn use_pseudolegal(&self) -> bool {
+        true
+    }
+
+    fn encode_action(&self, board: &Board<T>, action: &Move) -> Vec<String> {
+        vec![match action {
+            Move::Action(action) => match action.from {
+                Some(from) => format!(
+                    "{}{}{}",
+                    encode_usi_position(board, from),
+                    encode_usi_position(board, action.to),
+                    board.game.pieces[action.piece_type as usize].format_info(board, action.info)
+                ),
+                None => {
+                    let symbol = match board.game.pieces[action.piece_type as usize].get_piece_symbol() {
+                        PieceSymbol::Char(char) | PieceSymbol::Prefixed(_, char) => char,
+                        PieceSymbol::TeamSymbol(chars) => chars[0],
+                    };
+                    format!(
+                        "{}*{}",
+                        symbol.to_ascii_uppercase(),
+                        encode_usi_position(board, action.to)
+                    )
+                }
+            },
+            Move::Pass => "0000".to_string(),
+        }]
+    }
+
+    fn get_theoretical_moves(&self, board: &Board<T>) -> Vec<TheoreticalMove> {
+        get_theoretical_moves_bound(board, 2, false)
+    }
+
+    fn get_max_available_moves(&self) -> u32 {
+        // The most legal moves known to be possible in a single Shogi position.
+        593
+    }
+}
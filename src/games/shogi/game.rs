@@ -0,0 +1,118 @@
+use crate::board::{
+    fen::{FenOptions, FenState, FenSubMoves, FenTeamArgument},
+    game::{DefaultZobristController, Game},
+    pieces::Piece,
+    zobrist::ZobristHashTable,
+};
+
+use super::{pieces::get_shogi_pieces, ShogiHand, ShogiMoveController, ShogiPostProcess, ShogiResolution};
+
+pub struct Shogi;
+
+impl Shogi {
+    pub fn create() -> Game<1> {
+        let pieces = get_shogi_pieces()
+            .iter()
+            .map(|piece| piece as &dyn Piece<1>)
+            .collect::<Vec<_>>();
+
+        Game {
+            teams: 2,
+            turns: vec![1, 1],
+            rows: 9,
+            cols: 9,
+            squares: 81,
+            saved_last_moves: 0,
+            regions: vec![],
+            zobrist_controller: Box::new(DefaultZobristController),
+            zobrist: ZobristHashTable::<1>::generate(81, 2, 14, 0, || fastrand::u64(0..u64::MAX)),
+            name: String::from("Shogi"),
+            pieces,
+            controller: Box::new(ShogiMoveController),
+            resolution: Box::new(ShogiResolution),
+            fen_options: FenOptions {
+                state: FenState { first_moves: false, gaps: '-', reserves: false },
+                args: vec![
+                    (
+                        "team to move".to_string(),
+                        Box::new(FenTeamArgument::Teams(vec!['b', 'w'])),
+                    ),
+                    ("pieces in hand".to_string(), Box::new(ShogiHand)),
+                    ("move number".to_string(), Box::new(FenSubMoves)),
+                ],
+                post_process: Box::new(ShogiPostProcess),
+                default_fen: String::from("lnsgkgsnl/1r5b1/ppppppppp/9/9/9/PPPPPPPPP/1B5R1/LNSGKGSNL b - 1")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::game::{GameResults, NORMAL_MODE};
+
+    use super::Shogi;
+
+    fn encode_moves(fen: &str) -> Vec<String> {
+        let shogi = Shogi::create();
+        let mut board = shogi.from_fen(fen);
+        board
+            .generate_legal_moves(NORMAL_MODE)
+            .iter()
+            .map(|action| board.encode_action(action))
+            .collect()
+    }
+
+    #[test]
+    fn sfen() {
+        let shogi = Shogi::create();
+        for fen in [
+            "lnsgkgsnl/1r5b1/ppppppppp/9/9/9/PPPPPPPPP/1B5R1/LNSGKGSNL b - 1",
+            "l6nl/5+P1gk/2np1S3/p1p4Pp/3P2Sp1/1PPb2P1P/P5GS1/R8/LN4bKL w RGgsn5p 1",
+        ] {
+            assert_eq!(shogi.from_fen(fen).to_fen(), fen);
+        }
+    }
+
+    #[test]
+    fn usi_moves() {
+        let moves = encode_moves("lnsgkgsnl/1r5b1/ppppppppp/9/9/9/PPPPPPPPP/1B5R1/LNSGKGSNL b - 1");
+        assert!(moves.contains(&"7g7f".to_string()));
+        assert!(moves.contains(&"2h1h".to_string()));
+
+        // The pawn on 2d can promote as it enters the zone, but doesn't have to.
+        let moves = encode_moves("4k4/9/9/7P1/9/9/9/9/4K4 b - 1");
+        assert!(moves.contains(&"2d2c".to_string()));
+        assert!(moves.contains(&"2d2c+".to_string()));
+
+        // A pawn entering the last rank has to promote.
+        let moves = encode_moves("4k4/7P1/9/9/9/9/9/9/4K4 b - 1");
+        assert!(moves.contains(&"2b2a+".to_string()));
+        assert!(!moves.contains(&"2b2a".to_string()));
+    }
+
+    #[test]
+    fn drops() {
+        // Pawns can't be dropped onto a file that already has one of your unpromoted pawns (nifu), or onto the last rank.
+        let moves = encode_moves("4k4/9/9/9/9/9/9/7P1/4K4 b P 1");
+        assert!(moves.contains(&"P*3e".to_string()));
+        assert!(!moves.iter().any(|action| action.starts_with("P*2")));
+        assert!(!moves.iter().any(|action| action.starts_with("P*") && action.ends_with('a')));
+
+        // Dropping a pawn to give checkmate (uchifuzume) is illegal, but any other drop is fine.
+        let moves = encode_moves("3lkl3/9/4G4/9/9/9/9/9/4K4 b GP 1");
+        assert!(!moves.contains(&"P*5b".to_string()));
+        assert!(moves.contains(&"P*5d".to_string()));
+        assert!(moves.contains(&"G*5b".to_string()));
+    }
+
+    #[test]
+    fn no_moves_loses() {
+        let shogi = Shogi::create();
+        // Gote isn't in check, but has no legal moves, which is still a loss.
+        let mut board = shogi.from_fen("8k/9/6N1S/9/9/9/9/9/4K4 w - 1");
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert!(moves.is_empty());
+        assert!(matches!(shogi.resolution.resolve(&mut board, &moves), GameResults::Win(0)));
+    }
+}
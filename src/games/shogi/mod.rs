@@ -0,0 +1,13 @@
+mod game;
+mod args;
+mod process;
+mod controller;
+mod resolution;
+mod suite;
+pub mod pieces;
+
+pub use game::*;
+pub use args::*;
+pub use process::*;
+pub use controller::*;
+pub use resolution::*;
@@ -0,0 +1,380 @@
+use std::sync::OnceLock;
+
+use crate::{
+    bitboard::BitBoard,
+    board::{
+        actions::{
+            Action, ActionInfo, HistoryMove, HistoryState, HistoryUpdate, IndexedPreviousBoard, Move,
+            PreviousBoard, PreviousReserve, TurnInfo,
+        },
+        pieces::{BetzaPiece, Piece, PieceSymbol},
+        AttackDirections, Board, PieceType,
+    },
+};
+
+pub const PAWN: PieceType = 0;
+pub const LANCE: PieceType = 1;
+pub const KNIGHT: PieceType = 2;
+pub const SILVER: PieceType = 3;
+pub const GOLD: PieceType = 4;
+pub const BISHOP: PieceType = 5;
+pub const ROOK: PieceType = 6;
+pub const KING: PieceType = 7;
+pub const TOKIN: PieceType = 8;
+pub const PROMOTED_LANCE: PieceType = 9;
+pub const PROMOTED_KNIGHT: PieceType = 10;
+pub const PROMOTED_SILVER: PieceType = 11;
+pub const HORSE: PieceType = 12;
+pub const DRAGON: PieceType = 13;
+
+const NORMAL_SHOGI_MOVE: u16 = 0;
+pub const PROMOTION_MOVE: u16 = 1;
+
+/// The piece type each piece promotes to, if it can promote at all.
+pub const PROMOTIONS: [Option<PieceType>; 14] = [
+    Some(TOKIN), Some(PROMOTED_LANCE), Some(PROMOTED_KNIGHT), Some(PROMOTED_SILVER), None,
+    Some(HORSE), Some(DRAGON), None, None, None, None, None, None, None,
+];
+
+/// The piece type each piece goes back to when it's captured and put into the capturer's hand.
+pub const DEMOTIONS: [PieceType; 14] = [
+    PAWN, LANCE, KNIGHT, SILVER, GOLD, BISHOP, ROOK, KING,
+    PAWN, LANCE, KNIGHT, SILVER, BISHOP, ROOK,
+];
+
+/// How many of the furthest rows a piece can never be on, since it would have no moves left from there.
+/// Pieces must promote when moving onto these rows, and can't be dropped onto them.
+const DEAD_ROWS: [u16; 14] = [1, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+
+const PROMOTION_ROWS: u16 = 3;
+
+#[derive(Debug)]
+pub struct ShogiPiece {
+    pub piece_type: PieceType,
+    pub symbol: char,
+    pub promoted: bool,
+    pub movement: BetzaPiece,
+}
+
+impl ShogiPiece {
+    fn new(piece_type: PieceType, symbol: char, promoted: bool, notation: &str) -> ShogiPiece {
+        ShogiPiece {
+            piece_type,
+            symbol,
+            promoted,
+            movement: BetzaPiece::parse(symbol, notation)
+                .expect("Shogi pieces should have valid Betza notation."),
+        }
+    }
+}
+
+/// Gets every Shogi piece, indexed by piece type.
+pub fn get_shogi_pieces() -> &'static Vec<ShogiPiece> {
+    static PIECES: OnceLock<Vec<ShogiPiece>> = OnceLock::new();
+    PIECES.get_or_init(|| {
+        vec![
+            ShogiPiece::new(PAWN, 'p', false, "fW"),
+            ShogiPiece::new(LANCE, 'l', false, "fR"),
+            ShogiPiece::new(KNIGHT, 'n', false, "ffN"),
+            ShogiPiece::new(SILVER, 's', false, "FfW"),
+            ShogiPiece::new(GOLD, 'g', false, "WfF"),
+            ShogiPiece::new(BISHOP, 'b', false, "B"),
+            ShogiPiece::new(ROOK, 'r', false, "R"),
+            ShogiPiece::new(KING, 'k', false, "K"),
+            ShogiPiece::new(TOKIN, 'p', true, "WfF"),
+            ShogiPiece::new(PROMOTED_LANCE, 'l', true, "WfF"),
+            ShogiPiece::new(PROMOTED_KNIGHT, 'n', true, "WfF"),
+            ShogiPiece::new(PROMOTED_SILVER, 's', true, "WfF"),
+            ShogiPiece::new(HORSE, 'b', true, "BW"),
+            ShogiPiece::new(DRAGON, 'r', true, "RF"),
+        ]
+    })
+}
+
+/// Gets the furthest `rows` rows from the given team's side of the board.
+pub fn get_far_rows<const T: usize>(board: &Board<T>, team: u16, rows: u16) -> BitBoard<T> {
+    if rows == 0 {
+        return BitBoard::new();
+    }
+
+    let edges = &board.state.edges[(rows - 1) as usize];
+    match team {
+        0 => edges.top,
+        _ => edges.bottom,
+    }
+}
+
+impl<const T: usize> Piece<T> for ShogiPiece {
+    fn get_piece_symbol(&self) -> PieceSymbol {
+        if self.promoted {
+            PieceSymbol::Prefixed('+', self.symbol)
+        } else {
+            PieceSymbol::Char(self.symbol)
+        }
+    }
+
+    #[allow(unused_variables)]
+    fn format_info(&self, board: &Board<T>, info: ActionInfo) -> String {
+        if info == PROMOTION_MOVE {
+            "+".to_string()
+        } else {
+            "".to_string()
+        }
+    }
+
+    #[allow(unused_variables)]
+    fn parse_info(&self, board: &Board<T>, info: String) -> u32 {
+        if info == "+" {
+            PROMOTION_MOVE as u32
+        } else {
+            NORMAL_SHOGI_MOVE as u32
+        }
+    }
+
+    fn can_lookup(&self) -> bool {
+        true
+    }
+
+    fn generate_lookup_moves(&self, board: &Board<T>, from: BitBoard<T>) -> AttackDirections<T> {
+        Piece::<T>::generate_lookup_moves(&self.movement, board, from)
+    }
+
+    fn get_moves(
+        &self,
+        board: &Board<T>,
+        from: BitBoard<T>,
+        piece_type: PieceType,
+        team: u16,
+        mode: u16,
+    ) -> BitBoard<T> {
+        Piece::<T>::get_moves(&self.movement, board, from, piece_type, team, mode)
+    }
+
+    fn make_capture_move(
+        &self,
+        board: &mut Board<T>,
+        action: &Action,
+        piece_type: PieceType,
+        from: BitBoard<T>,
+        to: BitBoard<T>,
+        turn_info: TurnInfo,
+    ) -> Option<HistoryMove<T>> {
+        let color = action.team as usize;
+        let piece_type = piece_type as usize;
+        let captured_color: usize = if (to & board.state.teams[0]).is_set() {
+            0
+        } else {
+            1
+        };
+        let mut captured_piece_type: usize = 0;
+        for i in 0..(board.game.pieces.len()) {
+            if (board.state.pieces[i] & to).is_set() {
+                captured_piece_type = i;
+                break;
+            }
+        }
+        let hand_piece_type = DEMOTIONS[captured_piece_type] as usize;
+        let new_piece_type = match action.info {
+            PROMOTION_MOVE => PROMOTIONS[piece_type].expect("This Shogi piece cannot promote.") as usize,
+            _ => piece_type,
+        };
+
+        let history_move = HistoryMove {
+            action: Move::Action(*action),
+            first_history_move: board.retrieve_first_history_move(Move::Action(*action)),
+            turn_info,
+            state: HistoryState::Any {
+                all_pieces: PreviousBoard(board.state.all_pieces),
+                first_move: PreviousBoard(board.state.first_move),
+                updates: vec![
+                    HistoryUpdate::Team(IndexedPreviousBoard(color, board.state.teams[color])),
+                    HistoryUpdate::Team(IndexedPreviousBoard(
+                        captured_color,
+                        board.state.teams[captured_color],
+                    )),
+                    HistoryUpdate::Piece(IndexedPreviousBoard(
+                        piece_type,
+                        board.state.pieces[piece_type],
+                    )),
+                    HistoryUpdate::Piece(IndexedPreviousBoard(
+                        captured_piece_type,
+                        board.state.pieces[captured_piece_type],
+                    )),
+                    HistoryUpdate::Piece(IndexedPreviousBoard(
+                        new_piece_type,
+                        board.state.pieces[new_piece_type],
+                    )),
+                    HistoryUpdate::Reserve(PreviousReserve(
+                        color,
+                        hand_piece_type,
+                        board.state.reserves[color][hand_piece_type],
+                    )),
+                ],
+            },
+        };
+
+        board.state.teams[captured_color] ^= to;
+        board.state.teams[color] ^= from;
+        board.state.teams[color] |= to;
+
+        board.state.pieces[captured_piece_type] ^= to;
+        board.state.pieces[piece_type] ^= from;
+        board.state.pieces[new_piece_type] |= to;
+
+        board.state.all_pieces ^= from;
+
+        board.state.reserves[color][hand_piece_type] += 1;
+
+        Some(history_move)
+    }
+
+    fn make_normal_move(
+        &self,
+        board: &mut Board<T>,
+        action: &Action,
+        piece_type: PieceType,
+        from: BitBoard<T>,
+        to: BitBoard<T>,
+        turn_info: TurnInfo,
+    ) -> Option<HistoryMove<T>> {
+        if action.info != PROMOTION_MOVE {
+            let color = action.team as usize;
+            let piece_type = piece_type as usize;
+
+            let history_move = HistoryMove {
+                action: Move::Action(*action),
+                first_history_move: board.retrieve_first_history_move(Move::Action(*action)),
+                turn_info,
+                state: HistoryState::Single {
+                    team: IndexedPreviousBoard(color, board.state.teams[color]),
+                    piece: IndexedPreviousBoard(piece_type, board.state.pieces[piece_type]),
+                    all_pieces: PreviousBoard(board.state.all_pieces),
+                    first_move: PreviousBoard(board.state.first_move),
+                },
+            };
+
+            board.state.teams[color] = (board.state.teams[color] ^ from) | to;
+            board.state.pieces[piece_type] = (board.state.pieces[piece_type] ^ from) | to;
+            board.state.all_pieces = (board.state.all_pieces ^ from) | to;
+
+            return Some(history_move);
+        }
+
+        let color = action.team as usize;
+        let piece_type = piece_type as usize;
+        let promotion_type = PROMOTIONS[piece_type].expect("This Shogi piece cannot promote.") as usize;
+
+        let history_move = HistoryMove {
+            action: Move::Action(*action),
+            first_history_move: board.retrieve_first_history_move(Move::Action(*action)),
+            turn_info,
+            state: HistoryState::Any {
+                all_pieces: PreviousBoard(board.state.all_pieces),
+                first_move: PreviousBoard(board.state.first_move),
+                updates: vec![
+                    HistoryUpdate::Team(IndexedPreviousBoard(color, board.state.teams[color])),
+                    HistoryUpdate::Piece(IndexedPreviousBoard(
+                        piece_type,
+                        board.state.pieces[piece_type],
+                    )),
+                    HistoryUpdate::Piece(IndexedPreviousBoard(
+                        promotion_type,
+                        board.state.pieces[promotion_type],
+                    )),
+                ],
+            },
+        };
+
+        board.state.teams[color] = (board.state.teams[color] ^ from) | to;
+        board.state.pieces[piece_type] ^= from;
+        board.state.pieces[promotion_type] |= to;
+        board.state.all_pieces = (board.state.all_pieces ^ from) | to;
+
+        Some(history_move)
+    }
+
+    fn add_actions(
+        &self,
+        actions: &mut Vec<Move>,
+        board: &Board<T>,
+        piece_type: PieceType,
+        from: u16,
+        team: u16,
+        mode: u16,
+    ) {
+        let from_board = BitBoard::from_lsb(from);
+        let bit_actions = self.get_moves(board, from_board, piece_type, team, mode)
+            & !board.state.teams[team as usize]
+            & !board.state.gaps;
+
+        if bit_actions.is_empty() {
+            return;
+        }
+
+        let can_promote = PROMOTIONS[piece_type as usize].is_some();
+        let promotion_zone = get_far_rows(board, team, PROMOTION_ROWS);
+        let dead_zone = get_far_rows(board, team, DEAD_ROWS[piece_type as usize]);
+
+        for bit in bit_actions.iter_set_bits(board.state.squares) {
+            let to_board = BitBoard::from_lsb(bit);
+
+            // Pieces can promote when moving into, out of, or within the promotion zone.
+            if can_promote && ((from_board | to_board) & promotion_zone).is_set() {
+                actions.push(Move::Action(Action {
+                    from: Some(from),
+                    to: bit,
+                    team,
+                    info: PROMOTION_MOVE,
+                    move_type: NORMAL_SHOGI_MOVE,
+                    piece_type,
+                }));
+            }
+
+            if (to_board & dead_zone).is_empty() {
+                actions.push(Move::Action(Action {
+                    from: Some(from),
+                    to: bit,
+                    team,
+                    info: NORMAL_SHOGI_MOVE,
+                    move_type: NORMAL_SHOGI_MOVE,
+                    piece_type,
+                }));
+            }
+        }
+    }
+
+    #[allow(unused_variables)]
+    fn add_drop_actions(
+        &self,
+        actions: &mut Vec<Move>,
+        board: &Board<T>,
+        piece_type: PieceType,
+        team: u16,
+        mode: u16,
+    ) {
+        let mut drops = !board.state.all_pieces
+            & !board.state.gaps
+            & !get_far_rows(board, team, DEAD_ROWS[piece_type as usize]);
+
+        // Nifu: a team can't have two unpromoted pawns on the same file.
+        if piece_type == PAWN {
+            let pawns = board.state.pieces[PAWN as usize] & board.state.teams[team as usize];
+            for file in &board.state.files {
+                if (*file & pawns).is_set() {
+                    drops &= !*file;
+                }
+            }
+        }
+
+        for bit in drops.iter_set_bits(board.state.squares) {
+            actions.push(Move::Action(Action {
+                from: None,
+                to: bit,
+                team,
+                info: NORMAL_SHOGI_MOVE,
+                move_type: NORMAL_SHOGI_MOVE,
+                piece_type,
+            }));
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use crate::{board::{fen::PostProcess, Board}, bitboard::BitBoard};
+
+#[derive(Debug)]
+pub struct ShogiPostProcess;
+impl<const T: usize> PostProcess<T> for ShogiPostProcess {
+    fn apply(&self, board: &mut Board<T>) {
+        // Shogi doesn't have any moves that depend on whether a piece has moved yet.
+        board.state.first_move = BitBoard::new();
+    }
+}
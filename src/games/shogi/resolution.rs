@@ -0,0 +1,15 @@
+use crate::board::{game::{Resolution, GameResults}, Board, actions::Move};
+
+#[derive(Debug)]
+pub struct ShogiResolution;
+
+impl<const T: usize> Resolution<T> for ShogiResolution {
+    fn resolve(&self, board: &mut Board<T>, legal_moves: &Vec<Move>) -> GameResults {
+        // Unlike chess, running out of moves is always a loss in Shogi, whether or not you're in check.
+        if legal_moves.is_empty() {
+            GameResults::Win(board.get_next_team(board.state.moving_team))
+        } else {
+            GameResults::Ongoing
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use crate::board::{tests::run_tests};
+use super::Shogi;
+
+const TEST_STR: &str = r#"lnsgkgsnl/1r5b1/ppppppppp/9/9/9/PPPPPPPPP/1B5R1/LNSGKGSNL b - 1 ;D1 30 ;D2 900 ;D3 25470 ;D4 719731
+l6nl/5+P1gk/2np1S3/p1p4Pp/3P2Sp1/1PPb2P1P/P5GS1/R8/LN4bKL w RGgsn5p 1 ;D1 207 ;D2 28684 ;D3 4809015
+R8/2K1S1SSk/4B4/9/9/9/9/9/1L1L1L3 b RBGSNLP3g3n17p 1 ;D1 593"#;
+
+#[test]
+fn shogi_perft_suite() {
+    run_tests("Shogi", Shogi::create(), TEST_STR);
+}
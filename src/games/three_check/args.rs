@@ -0,0 +1,42 @@
+use crate::board::{fen::{FenArgument, FenDecodeError}, Board};
+
+/// The amount of checks each team has given, such as `+1+0`.
+#[derive(Debug)]
+pub struct ThreeCheckCounts;
+
+impl<const T: usize> FenArgument<T> for ThreeCheckCounts {
+    fn encode(&self, board: &Board<T>) -> String {
+        board.state.counters
+            .iter()
+            .map(|checks| format!("+{checks}"))
+            .collect::<String>()
+    }
+
+    fn decode(&self, board: &mut Board<T>, arg: &str) -> Result<(), FenDecodeError> {
+        let checks = arg
+            .strip_prefix('+')
+            .ok_or_else(|| {
+                FenDecodeError::InvalidArgument(format!(
+                    "'{arg}' is not a valid amount of checks, as it doesn't start with '+'."
+                ))
+            })?
+            .split('+')
+            .map(|checks| checks.parse::<u16>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| {
+                FenDecodeError::InvalidArgument(format!(
+                    "'{arg}' is not a valid amount of checks, as they aren't all positive integers."
+                ))
+            })?;
+
+        if checks.len() != board.state.counters.len() {
+            return Err(FenDecodeError::InvalidArgument(format!(
+                "'{arg}' should have the amount of checks for each of the {} teams.",
+                board.state.counters.len()
+            )));
+        }
+
+        board.state.counters = checks;
+        Ok(())
+    }
+}
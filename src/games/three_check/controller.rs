@@ -0,0 +1,79 @@
+use crate::{
+    bitboard::BitBoard,
+    board::{
+        actions::{Action, HistoryMove, HistoryUpdate, Move, PreviousCounter, TheoreticalMove, TurnUpdate},
+        game::{Game, MoveController, ATTACKS_MODE},
+        Board, BoardState,
+    },
+    games::chess::{pieces::KING_PIECE_TYPE, ChessMoveController},
+};
+
+/// The amount of checks a team has to give to win.
+pub const CHECKS_TO_WIN: u16 = 3;
+
+/// Three-check is played with the rules of chess, but each team counts the checks they give in `BoardState::counters`.
+#[derive(Debug)]
+pub struct ThreeCheckMoveController;
+
+impl ThreeCheckMoveController {
+    pub fn is_game_over<const T: usize>(board: &Board<T>) -> bool {
+        board.state.counters.iter().any(|checks| *checks >= CHECKS_TO_WIN)
+    }
+}
+
+impl<const T: usize> MoveController<T> for ThreeCheckMoveController {
+    fn transform_moves(&self, board: &mut Board<T>, mode: u16, actions: Vec<Move>) -> Vec<Move> {
+        // Once a team has given its third check, the game is over.
+        if ThreeCheckMoveController::is_game_over(board) {
+            return vec![];
+        }
+
+        ChessMoveController::<T>.transform_moves(board, mode, actions)
+    }
+
+    fn is_legal(&self, board: &mut Board<T>, action: &Move) -> bool {
+        ChessMoveController::<T>.is_legal(board, action)
+    }
+
+    fn use_pseudolegal(&self) -> bool {
+        true
+    }
+
+    fn get_counters(&self) -> usize {
+        // One check counter for each team.
+        2
+    }
+
+    fn make_move(&self, board: &mut Board<T>, action: &Action) -> Option<HistoryMove<T>> {
+        let team = action.team as usize;
+        let mut history_move = board.game.pieces[action.piece_type as usize].make_move(board, action)?;
+
+        let opponent = board.get_next_team(action.team) as usize;
+        let opponent_king: BitBoard<T> = board.state.pieces[KING_PIECE_TYPE as usize] & board.state.teams[opponent];
+        if board.can_move(action.team, opponent_king, ATTACKS_MODE) {
+            history_move.add_updates(vec![HistoryUpdate::Counter(PreviousCounter(
+                team,
+                board.state.counters[team],
+            ))]);
+            board.state.counters[team] += 1;
+        }
+
+        Some(history_move)
+    }
+
+    fn encode_action(&self, board: &Board<T>, action: &Move) -> Vec<String> {
+        ChessMoveController::<T>.encode_action(board, action)
+    }
+
+    fn update(&self, action: &Move, state: &BoardState<T>, game: &Game<T>) -> TurnUpdate {
+        ChessMoveController::<T>.update(action, state, game)
+    }
+
+    fn get_theoretical_moves(&self, board: &Board<T>) -> Vec<TheoreticalMove> {
+        ChessMoveController::<T>.get_theoretical_moves(board)
+    }
+
+    fn get_max_available_moves(&self) -> u32 {
+        ChessMoveController::<T>.get_max_available_moves()
+    }
+}
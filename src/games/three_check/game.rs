@@ -0,0 +1,69 @@
+use crate::{
+    board::{game::{Game, ZobristController}, zobrist::ZobristHashTable},
+    games::chess::Chess,
+};
+
+use super::{ThreeCheckCounts, ThreeCheckMoveController, ThreeCheckResolution, ThreeCheckZobrist};
+
+pub struct ThreeCheck;
+
+impl ThreeCheck {
+    pub fn create() -> Game<1> {
+        let mut game = Chess::create();
+        game.name = String::from("Three-check");
+        game.controller = Box::new(ThreeCheckMoveController);
+        game.resolution = Box::new(ThreeCheckResolution);
+
+        let extra_hashes = ZobristController::<1>::get_extra_hashes(&ThreeCheckZobrist);
+        game.zobrist_controller = Box::new(ThreeCheckZobrist);
+        game.zobrist = ZobristHashTable::<1>::generate(64, 2, 6, extra_hashes, || fastrand::u64(0..u64::MAX));
+
+        game.fen_options.args.push(("checks".to_string(), Box::new(ThreeCheckCounts)));
+        game.fen_options.default_fen = String::from("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1 +0+0");
+        game
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::game::{GameResults, NORMAL_MODE};
+
+    use super::ThreeCheck;
+
+    #[test]
+    fn check_counters() {
+        let three_check = ThreeCheck::create();
+        let fen = "r1bqkbnr/pppp1ppp/2n5/4p3/2B1P3/5Q2/PPPP1PPP/RNB1K1NR w KQkq - 2 3 +1+0";
+        let mut board = three_check.from_fen(fen);
+        assert_eq!(board.to_fen(), fen);
+
+        let state = board.state.clone();
+        let hash = three_check.zobrist.compute(&board);
+
+        let action = board.decode_action("c4f7", NORMAL_MODE).expect("Bxf7+ should be legal.");
+        let undo = board.make_move(&action);
+        assert_eq!(board.state.counters, vec![2, 0]);
+        assert_eq!(board.to_fen(), "r1bqkbnr/pppp1Bpp/2n5/4p3/4P3/5Q2/PPPP1PPP/RNB1K1NR b KQkq - 0 3 +2+0");
+
+        board.undo_move(undo);
+        assert_eq!(board.state, state);
+        assert_eq!(three_check.zobrist.compute(&board), hash);
+
+        // The same position with a different amount of checks isn't the same position.
+        let other_board = three_check.from_fen("r1bqkbnr/pppp1ppp/2n5/4p3/2B1P3/5Q2/PPPP1PPP/RNB1K1NR w KQkq - 2 3 +2+0");
+        assert_ne!(three_check.zobrist.compute(&other_board), hash);
+    }
+
+    #[test]
+    fn third_check_wins() {
+        let three_check = ThreeCheck::create();
+        let mut board = three_check.from_fen("r1bqkbnr/pppp1ppp/2n5/4p3/2B1P3/5Q2/PPPP1PPP/RNB1K1NR w KQkq - 2 3 +2+0");
+
+        let action = board.decode_action("c4f7", NORMAL_MODE).expect("Bxf7+ should be legal.");
+        board.make_move(&action);
+
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert!(moves.is_empty());
+        assert!(matches!(three_check.resolution.resolve(&mut board, &moves), GameResults::Win(0)));
+    }
+}
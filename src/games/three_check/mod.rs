@@ -0,0 +1,12 @@
+mod game;
+mod args;
+mod controller;
+mod resolution;
+mod zobrist;
+mod suite;
+
+pub use game::*;
+pub use args::*;
+pub use controller::*;
+pub use resolution::*;
+pub use zobrist::*;
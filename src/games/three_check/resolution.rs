@@ -0,0 +1,19 @@
+use crate::{
+    board::{game::{Resolution, GameResults}, Board, actions::Move},
+    games::chess::ChessResolution,
+};
+
+use super::CHECKS_TO_WIN;
+
+#[derive(Debug)]
+pub struct ThreeCheckResolution;
+
+impl<const T: usize> Resolution<T> for ThreeCheckResolution {
+    fn resolve(&self, board: &mut Board<T>, legal_moves: &Vec<Move>) -> GameResults {
+        if let Some(team) = board.state.counters.iter().position(|checks| *checks >= CHECKS_TO_WIN) {
+            return GameResults::Win(team as u16);
+        }
+
+        ChessResolution::<T>.resolve(board, legal_moves)
+    }
+}
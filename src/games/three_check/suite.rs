@@ -0,0 +1,15 @@
+use crate::board::{tests::run_tests};
+use super::ThreeCheck;
+
+const TEST_STR: &str = r#"rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1 +0+0 ;D1 20 ;D2 400 ;D3 8902 ;D4 197281 ;D5 4865609
+r1bqkbnr/pppp1ppp/2n5/1B2p3/4P3/5N2/PPPP1PPP/RNBQK2R b KQkq - 3 3 +1+0 ;D1 30 ;D2 959 ;D3 28579 ;D4 908001
+r1bqkbnr/pppp1ppp/2n5/4p3/2B1P3/5Q2/PPPP1PPP/RNB1K1NR w KQkq - 2 3 +2+0 ;D1 42 ;D2 1231 ;D3 49106 ;D4 1447873
+rnbqkbnr/ppp2ppp/8/3pp3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 0 3 +0+2 ;D1 30 ;D2 1103 ;D3 33555 ;D4 1233814
+4k3/8/8/8/8/8/8/4K2R w K - 0 1 +2+2 ;D1 15 ;D2 63 ;D3 1140 ;D4 6208 ;D5 117941
+r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1 +1+2 ;D1 26 ;D2 568 ;D3 13587 ;D4 310173 ;D5 7385925
+8/8/3k4/8/8/3K4/2Q5/8 b - - 0 1 +2+2 ;D1 5 ;D2 120 ;D3 535 ;D4 14476 ;D5 58505"#;
+
+#[test]
+fn three_check_perft_suite() {
+    run_tests("Three-check", ThreeCheck::create(), TEST_STR);
+}
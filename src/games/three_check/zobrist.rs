@@ -0,0 +1,22 @@
+use crate::board::{game::ZobristController, zobrist::ZobristHashTable, Board};
+
+use super::CHECKS_TO_WIN;
+
+/// Each team has a hash for every amount of checks they could have given (other than zero.)
+#[derive(Debug)]
+pub struct ThreeCheckZobrist;
+
+impl<const T: usize> ZobristController<T> for ThreeCheckZobrist {
+    fn apply(&self, hash: &mut u64, zobrist: &ZobristHashTable<T>, board: &Board<T>) {
+        for (team, checks) in board.state.counters.iter().enumerate() {
+            if *checks > 0 {
+                let checks = (*checks).min(CHECKS_TO_WIN) as usize;
+                *hash ^= zobrist.table[zobrist.base_len + (team * CHECKS_TO_WIN as usize) + (checks - 1)];
+            }
+        }
+    }
+
+    fn get_extra_hashes(&self) -> usize {
+        2 * CHECKS_TO_WIN as usize
+    }
+}
@@ -0,0 +1,289 @@
+use crate::{
+    bitboard::BitBoard,
+    board::{
+        actions::{Action, HistoryMove, HistoryUpdate, Move, PreviousCounter, TheoreticalMove, TurnUpdate, CounterUpdate},
+        game::{Game, get_theoretical_moves_bound, MoveController, ATTACKS_MODE, NORMAL_MODE},
+        Board, BoardState, PieceType,
+    },
+};
+
+use super::pieces::{CHARIOT, GENERAL, HOME, SOLDIER};
+
+/// How many times a position can be reached while a team keeps checking (or chasing) before that team loses the game.
+pub const PERPETUAL_REPETITIONS: usize = 3;
+
+/// How many sub moves can be made without a capture before the game is drawn.
+pub const MOVE_LIMIT: u16 = 120;
+
+/// The WXF letter of each piece type.
+const WXF_PIECES: [char; 7] = ['K', 'A', 'E', 'H', 'R', 'C', 'P'];
+
+/// Encodes a square in ICCS notation, where files are lettered from `a` to `i` (left to right), and ranks are numbered from 0 to 9 (bottom to top.)
+pub fn encode_iccs_position<const T: usize>(board: &Board<T>, pos: u16) -> String {
+    let row = pos / board.state.cols;
+    let col = pos % board.state.cols;
+    format!("{}{}", (b'a' + col as u8) as char, board.state.rows - 1 - row)
+}
+
+/// Gets the WXF file number of a column, which each team counts from 1 starting at their right.
+fn get_wxf_file<const T: usize>(board: &Board<T>, col: u16, team: u16) -> u16 {
+    if team == 0 {
+        board.state.cols - col
+    } else {
+        col + 1
+    }
+}
+
+/// Encodes a move in WXF notation (eg. `C2.5`, `H8+7` or `+R-1`.)
+///
+/// When two of a team's identical pieces share a file, the file is replaced with `+` (the front piece) or `-` (the back piece) before the piece's letter.
+/// WXF uses numbers for three or more soldiers on a file, but these are also written with `+` and `-` (for the front and back soldier) here.
+pub fn encode_wxf_action<const T: usize>(board: &Board<T>, action: &Action, from: u16) -> String {
+    let cols = board.state.cols;
+    let team = action.team;
+    let (from_row, from_col) = (from / cols, from % cols);
+    let (to_row, to_col) = (action.to / cols, action.to % cols);
+
+    // Team 0 moves towards the top of the board, which is row 0.
+    let forwards = if team == 0 {
+        from_row as i32 - to_row as i32
+    } else {
+        to_row as i32 - from_row as i32
+    };
+
+    let letter = WXF_PIECES[action.piece_type as usize];
+    let operator = match forwards {
+        1.. => '+',
+        0 => '.',
+        _ => '-',
+    };
+    let is_diagonal = from_col != to_col && forwards != 0;
+    let destination = if forwards == 0 || is_diagonal {
+        get_wxf_file(board, to_col, team)
+    } else {
+        forwards.unsigned_abs() as u16
+    };
+
+    let file_pieces = board.state.get_piece_team_board(action.piece_type as usize, team as usize)
+        .iter_set_bits(board.state.squares)
+        .filter(|pos| pos % cols == from_col)
+        .collect::<Vec<_>>();
+
+    if file_pieces.len() > 1 {
+        let front = if team == 0 { file_pieces[0] } else { file_pieces[file_pieces.len() - 1] };
+        let position = if from == front { '+' } else { '-' };
+        format!("{position}{letter}{operator}{destination}")
+    } else {
+        format!("{letter}{}{operator}{destination}", get_wxf_file(board, from_col, team))
+    }
+}
+
+/// Xiangqi is won by checkmating or stalemating the opponent's general.
+///
+/// Checking or chasing the same team's pieces forever isn't allowed, which is tracked in `BoardState::counters`:
+/// the first two counters are how many checks each team has given in a row, and the last two are how many times each team has chased in a row.
+/// A team only loses once it repeats a position during one of these cycles, which is found through `BoardState::positions`.
+/// Hashing every position is slow, so positions are only recorded while a team is checking or chasing in a row.
+/// This is a simplification of the full Asian rules, which also judge cycles mixing checks and chases.
+#[derive(Debug)]
+pub struct XiangqiMoveController;
+
+impl XiangqiMoveController {
+    pub fn is_in_check<const T: usize>(board: &Board<T>, team: u16) -> bool {
+        let general = board.state.get_piece_team_board(GENERAL as usize, team as usize);
+        general.is_set() && board.can_move(board.get_next_team(team), general, ATTACKS_MODE)
+    }
+
+    /// The two generals can never face each other on the same file without any pieces between them (the "flying general" rule.)
+    pub fn are_generals_facing<const T: usize>(board: &Board<T>) -> bool {
+        let generals = board.state.pieces[GENERAL as usize];
+        if generals.count_ones() != 2 {
+            return false;
+        }
+
+        let cols = board.state.cols;
+        let (top, bottom) = (generals.bitscan_forward(), generals.bitscan_reverse());
+        if top % cols != bottom % cols {
+            return false;
+        }
+
+        ((top + cols)..bottom)
+            .step_by(cols as usize)
+            .all(|pos| (BitBoard::<T>::from_lsb(pos) & board.state.all_pieces).is_empty())
+    }
+
+    /// Finds the squares a piece attacks from the given square.
+    pub fn get_attacks<const T: usize>(board: &Board<T>, piece_type: PieceType, square: u16, team: u16) -> BitBoard<T> {
+        let piece = BitBoard::<T>::from_lsb(square);
+        board.game.pieces[piece_type as usize].get_moves(board, piece, piece_type, team, ATTACKS_MODE)
+    }
+
+    /// A piece is chased when the piece that just moved newly attacks it (`previous_attacks` being what it attacked before moving),
+    /// and it's either undefended or a chariot attacked by a weaker piece.
+    /// Generals and soldiers that haven't crossed the river can't be chased.
+    pub fn is_chasing<const T: usize>(board: &Board<T>, action: &Action, previous_attacks: BitBoard<T>) -> bool {
+        let team = action.team;
+        let opponent = board.get_next_team(team);
+        let piece_type = action.piece_type as PieceType;
+
+        let uncrossed_soldiers = board.state.pieces[SOLDIER as usize] & board.game.regions[HOME].get(opponent);
+        let targets = XiangqiMoveController::get_attacks(board, piece_type, action.to, team)
+            & !previous_attacks
+            & board.state.teams[opponent as usize]
+            & !board.state.pieces[GENERAL as usize]
+            & !uncrossed_soldiers;
+
+        let chariots = board.state.pieces[CHARIOT as usize];
+        targets.iter_set_bits(board.state.squares).any(|target| {
+            let target = BitBoard::from_lsb(target);
+            (piece_type != CHARIOT && (target & chariots).is_set()) || !board.can_move(opponent, target, ATTACKS_MODE)
+        })
+    }
+
+    /// A team loses once the position it just reached has been reached `PERPETUAL_REPETITIONS` times since it started checking (or chasing) in a row.
+    pub fn get_perpetual_team<const T: usize>(board: &Board<T>) -> Option<u16> {
+        let position = board.state.positions.last()?;
+        let teams = board.game.teams as usize;
+        let team = board.get_previous_team(board.state.moving_team);
+
+        let is_perpetual = [team as usize, teams + team as usize].iter().any(|counter| {
+            let streak = board.state.counters[*counter] as usize;
+            if streak == 0 {
+                return false;
+            }
+
+            // The cycle covers the team's checks (or chases) in a row, along with the other teams' moves between them.
+            let cycle = (streak - 1) * teams + 1;
+            let repetitions = board.state.positions.iter().rev().take(cycle).filter(|el| *el == position).count();
+            repetitions >= PERPETUAL_REPETITIONS
+        });
+
+        is_perpetual.then_some(team)
+    }
+
+    /// Whether the moving team has any legal moves, regardless of whether the game has already ended by perpetual check or chase.
+    pub fn has_legal_moves<const T: usize>(&self, board: &mut Board<T>) -> bool {
+        let actions = board.generate_moves(NORMAL_MODE);
+        actions.iter().any(|action| self.is_legal(board, action))
+    }
+
+    fn set_counter<const T: usize>(board: &mut Board<T>, history_move: &mut HistoryMove<T>, counter: usize, value: u16) {
+        if board.state.counters[counter] != value {
+            history_move.add_updates(vec![HistoryUpdate::Counter(PreviousCounter(
+                counter,
+                board.state.counters[counter],
+            ))]);
+            board.state.counters[counter] = value;
+        }
+    }
+}
+
+impl<const T: usize> MoveController<T> for XiangqiMoveController {
+    #[allow(unused_variables)]
+    fn transform_moves(&self, board: &mut Board<T>, mode: u16, actions: Vec<Move>) -> Vec<Move> {
+        if XiangqiMoveController::get_perpetual_team(board).is_some() {
+            return vec![];
+        }
+
+        let mut legal_moves = Vec::with_capacity(actions.len());
+        for action in actions {
+            if self.is_legal(board, &action) {
+                legal_moves.push(action);
+            }
+        }
+        legal_moves
+    }
+
+    fn is_legal(&self, board: &mut Board<T>, action: &Move) -> bool {
+        match action {
+            Move::Action(_) => {
+                let current_team = board.state.moving_team;
+
+                let undo = board.make_move(action);
+                let legal = !XiangqiMoveController::is_in_check(board, current_team)
+                    && !XiangqiMoveController::are_generals_facing(board);
+                board.undo_move(undo);
+
+                legal
+            }
+            Move::Pass => false,
+        }
+    }
+
+    fn use_pseudolegal(&self) -> bool {
+        true
+    }
+
+    fn get_counters(&self) -> usize {
+        // A check counter and a chase counter for each team.
+        4
+    }
+
+    fn make_move(&self, board: &mut Board<T>, action: &Action) -> Option<HistoryMove<T>> {
+        let from = action.from.expect("Xiangqi moves should always have a from square.");
+        let previous_attacks = XiangqiMoveController::get_attacks(board, action.piece_type, from, action.team);
+        let mut history_move = board.game.pieces[action.piece_type as usize].make_move(board, action)?;
+
+        let teams = board.game.teams as usize;
+        let team = action.team as usize;
+        let opponent = board.get_next_team(action.team);
+
+        let checks = if XiangqiMoveController::is_in_check(board, opponent) {
+            board.state.counters[team] + 1
+        } else {
+            0
+        };
+        let chases = if XiangqiMoveController::is_chasing(board, action, previous_attacks) {
+            board.state.counters[teams + team] + 1
+        } else {
+            0
+        };
+
+        XiangqiMoveController::set_counter(board, &mut history_move, team, checks);
+        XiangqiMoveController::set_counter(board, &mut history_move, teams + team, chases);
+
+        // Every move since a cycle started is recorded, so the cycle's positions are always the latest ones.
+        if board.state.counters.iter().any(|count| *count > 0) {
+            let position = board.game.zobrist.compute(board);
+            board.state.positions.push(position);
+            history_move.add_updates(vec![HistoryUpdate::Position]);
+        }
+
+        Some(history_move)
+    }
+
+    fn encode_action(&self, board: &Board<T>, action: &Move) -> Vec<String> {
+        match action {
+            Move::Action(action) => {
+                let from = action.from.expect("Xiangqi moves should always have a from square.");
+                let (from_pos, to_pos) = (encode_iccs_position(board, from), encode_iccs_position(board, action.to));
+                vec![
+                    format!("{from_pos}{to_pos}"),
+                    format!("{}-{}", from_pos.to_ascii_uppercase(), to_pos.to_ascii_uppercase()),
+                    encode_wxf_action(board, action, from),
+                ]
+            }
+            Move::Pass => vec!["0000".to_string()],
+        }
+    }
+
+    fn update(&self, action: &Move, state: &BoardState<T>, game: &Game<T>) -> TurnUpdate {
+        TurnUpdate {
+            turns: CounterUpdate::Next,
+            sub_moves: match action {
+                Move::Action(action) if (BitBoard::<T>::from_lsb(action.to) & state.all_pieces).is_set() => CounterUpdate::To(0),
+                _ => CounterUpdate::Next,
+            },
+            full_moves: CounterUpdate::Next,
+        }
+    }
+
+    fn get_theoretical_moves(&self, board: &Board<T>) -> Vec<TheoreticalMove> {
+        get_theoretical_moves_bound(board, 1, false)
+    }
+
+    fn get_max_available_moves(&self) -> u32 {
+        // The most moves each piece could have on its own, added together.
+        119
+    }
+}
@@ -0,0 +1,237 @@
+use crate::board::{
+    fen::{FenFullMoves, FenOptions, FenState, FenSubMoves, FenTeamArgument, FenUnused},
+    game::{DefaultZobristController, Game},
+    zobrist::ZobristHashTable,
+};
+
+use super::{
+    pieces::{get_xiangqi_pieces, get_xiangqi_regions},
+    XiangqiMoveController, XiangqiPostProcess, XiangqiResolution,
+};
+
+pub struct Xiangqi;
+
+impl Xiangqi {
+    pub fn create() -> Game<1> {
+        Game {
+            teams: 2,
+            turns: vec![1, 1],
+            rows: 10,
+            cols: 9,
+            squares: 90,
+            saved_last_moves: 0,
+            regions: get_xiangqi_regions(),
+            zobrist_controller: Box::new(DefaultZobristController),
+            zobrist: ZobristHashTable::<1>::generate(90, 2, 7, 0, || fastrand::u64(0..u64::MAX)),
+            name: String::from("Xiangqi"),
+            pieces: get_xiangqi_pieces(),
+            controller: Box::new(XiangqiMoveController),
+            resolution: Box::new(XiangqiResolution),
+            fen_options: FenOptions {
+                state: FenState { first_moves: false, gaps: '-', reserves: false },
+                args: vec![
+                    (
+                        "team to move".to_string(),
+                        Box::new(FenTeamArgument::Teams(vec!['w', 'b'])),
+                    ),
+                    // Xiangqi FENs keep chess's castling and en passant fields, even though they're never used.
+                    ("castling rights".to_string(), Box::new(FenUnused)),
+                    ("en passant".to_string(), Box::new(FenUnused)),
+                    ("half moves".to_string(), Box::new(FenSubMoves)),
+                    ("full moves".to_string(), Box::new(FenFullMoves)),
+                ],
+                post_process: Box::new(XiangqiPostProcess),
+                default_fen: String::from("rnbakabnr/9/1c5c1/p1p1p1p1p/9/9/P1P1P1P1P/1C5C1/9/RNBAKABNR w - - 0 1")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::{game::{GameResults, NORMAL_MODE}, Board};
+
+    use super::Xiangqi;
+
+    fn encode_moves(fen: &str) -> Vec<String> {
+        let xiangqi = Xiangqi::create();
+        let mut board = xiangqi.from_fen(fen);
+        board
+            .generate_legal_moves(NORMAL_MODE)
+            .iter()
+            .map(|action| board.encode_action(action))
+            .collect()
+    }
+
+    fn encode_from(fen: &str, from: &str) -> Vec<String> {
+        let mut moves = encode_moves(fen)
+            .into_iter()
+            .filter(|action| action.starts_with(from))
+            .collect::<Vec<_>>();
+        moves.sort();
+        moves
+    }
+
+    #[test]
+    fn fen() {
+        let xiangqi = Xiangqi::create();
+        for fen in [
+            "rnbakabnr/9/1c5c1/p1p1p1p1p/9/9/P1P1P1P1P/1C5C1/9/RNBAKABNR w - - 0 1",
+            "r1ba1a3/4kn3/2n1b4/pNp1p1p1p/4c4/6P2/P1P2R2P/1CcC5/9/2BAKAB2 b - - 3 12",
+        ] {
+            assert_eq!(xiangqi.from_fen(fen).to_fen(), fen);
+        }
+    }
+
+    #[test]
+    fn piece_movement() {
+        let fen = "3k5/9/9/P8/9/2B6/3P5/9/7P1/3AK2N1 w - - 0 1";
+
+        // The general and advisors can't leave the palace.
+        assert_eq!(encode_from(fen, "e0"), vec!["e0e1", "e0f0"]);
+        assert_eq!(encode_from(fen, "d0"), vec!["d0e1"]);
+        // Elephants can't cross the river, and are blocked by a piece on their eye.
+        assert_eq!(encode_from(fen, "c4"), vec!["c4a2"]);
+        // Horses are blocked by a piece on their leg.
+        assert_eq!(encode_from(fen, "h0"), vec!["h0f1"]);
+        // Soldiers can only move sideways once they've crossed the river.
+        assert_eq!(encode_from(fen, "d3"), vec!["d3d4"]);
+        assert_eq!(encode_from(fen, "a6"), vec!["a6a7", "a6b6"]);
+    }
+
+    #[test]
+    fn cannons_capture_by_hopping() {
+        let moves = encode_moves("3k5/9/4r4/9/4p4/9/9/9/4C4/5K3 w - - 0 1");
+        assert!(moves.contains(&"e1e4".to_string()));
+        assert!(moves.contains(&"e1e7".to_string()));
+        assert!(!moves.contains(&"e1e5".to_string()));
+        assert!(!moves.contains(&"e1e6".to_string()));
+    }
+
+    #[test]
+    fn flying_general() {
+        // The horse can't move, since the generals would then face each other.
+        let mut moves = encode_moves("4k4/9/9/9/4n4/9/9/9/9/4K4 b - - 0 1");
+        moves.sort();
+        assert_eq!(moves, vec!["e9d9", "e9e8", "e9f9"]);
+    }
+
+    #[test]
+    fn stalemate_loses() {
+        let xiangqi = Xiangqi::create();
+        let mut board = xiangqi.from_fen("3k5/8R/9/9/9/9/9/9/9/4K4 b - - 0 1");
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert!(moves.is_empty());
+        assert!(matches!(xiangqi.resolution.resolve(&mut board, &moves), GameResults::Win(0)));
+    }
+
+    #[test]
+    fn notation() {
+        let xiangqi = Xiangqi::create();
+        let mut board = xiangqi.default();
+        for (wxf, iccs, dashed_iccs) in [
+            ("C2.5", "h2e2", "H2-E2"),
+            ("H2+3", "h0g2", "H0-G2"),
+            ("R1+1", "i0i1", "I0-I1"),
+            ("A4+5", "f0e1", "F0-E1"),
+            ("E3+5", "g0e2", "G0-E2"),
+        ] {
+            let action = board.decode_action(wxf, NORMAL_MODE).expect("The move should be decoded from WXF.");
+            assert_eq!(board.encode_action(&action), iccs);
+            assert_eq!(board.decode_action(dashed_iccs, NORMAL_MODE), Some(action));
+        }
+
+        // Black counts its files from its own right.
+        let mut board = xiangqi.from_fen("rnbakabnr/9/1c5c1/p1p1p1p1p/9/9/P1P1P1P1P/1C5C1/9/RNBAKABNR b - - 0 1");
+        let action = board.decode_action("C8.5", NORMAL_MODE).expect("C8.5 should be legal.");
+        assert_eq!(board.encode_action(&action), "h7e7");
+
+        // Identical pieces on the same file are told apart by which is in front.
+        let mut board = xiangqi.from_fen("4k4/9/9/9/9/9/R8/9/R8/3K5 w - - 0 1");
+        let action = board.decode_action("+R+1", NORMAL_MODE).expect("+R+1 should be legal.");
+        assert_eq!(board.encode_action(&action), "a3a4");
+        let action = board.decode_action("-R.8", NORMAL_MODE).expect("-R.8 should be legal.");
+        assert_eq!(board.encode_action(&action), "a1b1");
+    }
+
+    fn play<const T: usize>(board: &mut Board<T>, actions: &[&str]) {
+        for action in actions {
+            assert!(!board.generate_legal_moves(NORMAL_MODE).is_empty(), "The game shouldn't be over before {action}.");
+            let action = board.decode_action(action, NORMAL_MODE).expect("The move should be legal.");
+            board.make_move(&action);
+        }
+    }
+
+    #[test]
+    fn perpetual_check_loses() {
+        let xiangqi = Xiangqi::create();
+        let mut board = xiangqi.from_fen("4k4/9/9/9/9/9/9/9/9/R2K5 w - - 0 1");
+        let state = board.state.clone();
+
+        let action = board.decode_action("a0a9", NORMAL_MODE).expect("a0a9 should be legal.");
+        let undo = board.make_move(&action);
+        assert_eq!(board.state.counters, vec![1, 0, 0, 0]);
+        assert_eq!(board.state.positions.len(), 1);
+        board.undo_move(undo);
+        assert_eq!(board.state, state);
+
+        // Checking in a row is fine until a position repeats for the third time within those checks.
+        play(&mut board, &["a0a9", "e9e8", "a9a8", "e8e9", "a8a9", "e9e8", "a9a8", "e8e9"]);
+        assert_eq!(board.state.counters, vec![4, 0, 0, 0]);
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert!(matches!(xiangqi.resolution.resolve(&mut board, &moves), GameResults::Ongoing));
+
+        play(&mut board, &["a8a9"]);
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert!(moves.is_empty());
+        assert!(matches!(xiangqi.resolution.resolve(&mut board, &moves), GameResults::Win(1)));
+    }
+
+    #[test]
+    fn checking_into_mate_wins() {
+        let xiangqi = Xiangqi::create();
+        let mut board = xiangqi.from_fen("4k4/9/9/9/9/9/9/9/8R/R4K3 w - - 0 1");
+
+        // Seven checks in a row, ending in checkmate, win for the team giving them.
+        play(&mut board, &[
+            "a0a9", "e9e8", "i1i8", "e8e7", "i8i7", "e7e8", "a9a8", "e8e9",
+            "a8a9", "e9e8", "i7i8", "e8e7", "a9a7"
+        ]);
+        assert_eq!(board.state.counters, vec![7, 0, 0, 0]);
+
+        let moves = board.generate_legal_moves(NORMAL_MODE);
+        assert!(moves.is_empty());
+        assert!(matches!(xiangqi.resolution.resolve(&mut board, &moves), GameResults::Win(0)));
+    }
+
+    #[test]
+    fn chasing() {
+        let xiangqi = Xiangqi::create();
+
+        // Attacking an undefended horse chases it.
+        let mut board = xiangqi.from_fen("4k4/9/9/9/n8/9/9/9/9/1R1K5 w - - 0 1");
+        let action = board.decode_action("b0a0", NORMAL_MODE).expect("b0a0 should be legal.");
+        board.make_move(&action);
+        assert_eq!(board.state.counters, vec![0, 0, 1, 0]);
+
+        // But attacking a defended one doesn't.
+        let mut board = xiangqi.from_fen("4k4/9/9/r8/n8/9/9/9/9/1R1K5 w - - 0 1");
+        let action = board.decode_action("b0a0", NORMAL_MODE).expect("b0a0 should be legal.");
+        board.make_move(&action);
+        assert_eq!(board.state.counters, vec![0, 0, 0, 0]);
+        // Without any team checking or chasing, there's no need to record the position.
+        assert!(board.state.positions.is_empty());
+
+        // Unless it's a chariot being attacked by a weaker piece.
+        let mut board = xiangqi.from_fen("4k4/9/1n7/9/r8/9/P8/9/9/1C1K5 w - - 0 1");
+        let action = board.decode_action("b0a0", NORMAL_MODE).expect("b0a0 should be legal.");
+        board.make_move(&action);
+        assert_eq!(board.state.counters, vec![0, 0, 1, 0]);
+
+        // Pieces that were already attacked before the move aren't chased again.
+        let mut board = xiangqi.from_fen("4k4/9/9/9/n8/9/9/9/9/R2K5 w - - 0 1");
+        let action = board.decode_action("a0a1", NORMAL_MODE).expect("a0a1 should be legal.");
+        board.make_move(&action);
+        assert_eq!(board.state.counters, vec![0, 0, 0, 0]);
+    }
+}
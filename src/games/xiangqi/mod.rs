@@ -0,0 +1,11 @@
+mod game;
+mod controller;
+mod resolution;
+mod process;
+mod suite;
+pub mod pieces;
+
+pub use game::*;
+pub use controller::*;
+pub use resolution::*;
+pub use process::*;
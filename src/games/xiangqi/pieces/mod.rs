@@ -0,0 +1,118 @@
+use std::sync::OnceLock;
+
+use crate::{
+    bitboard::BitBoard,
+    board::{
+        pieces::{BetzaPiece, ConfinedPiece, Piece, PieceSymbol},
+        regions::{get_rectangle, Region},
+        AttackDirections, Board, Cols, PieceType, Rows,
+    },
+};
+
+pub const GENERAL: PieceType = 0;
+pub const ADVISOR: PieceType = 1;
+pub const ELEPHANT: PieceType = 2;
+pub const HORSE: PieceType = 3;
+pub const CHARIOT: PieceType = 4;
+pub const CANNON: PieceType = 5;
+pub const SOLDIER: PieceType = 6;
+
+/// The index of each team's palace in `Game::regions`, which the general and advisors can't leave.
+pub const PALACE: usize = 0;
+/// The index of each team's side of the river in `Game::regions`, which elephants can't leave.
+pub const HOME: usize = 1;
+
+const ROWS: Rows = 10;
+const COLS: Cols = 9;
+
+/// Gets the regions of the Xiangqi board, indexed by `PALACE` and `HOME`.
+pub fn get_xiangqi_regions<const T: usize>() -> Vec<Region<T>> {
+    vec![
+        Region::new("palace", vec![
+            get_rectangle(7..ROWS, 3..6, COLS),
+            get_rectangle(0..3, 3..6, COLS),
+        ]),
+        Region::new("home", vec![
+            get_rectangle(5..ROWS, 0..COLS, COLS),
+            get_rectangle(0..5, 0..COLS, COLS),
+        ]),
+    ]
+}
+
+/// Soldiers step forwards, and can also step sideways once they've crossed the river.
+#[derive(Debug)]
+pub struct SoldierPiece {
+    pub movement: BetzaPiece,
+}
+
+impl<const T: usize> Piece<T> for SoldierPiece {
+    fn get_piece_symbol(&self) -> PieceSymbol {
+        Piece::<T>::get_piece_symbol(&self.movement)
+    }
+
+    fn can_lookup(&self) -> bool {
+        true
+    }
+
+    fn generate_lookup_moves(&self, board: &Board<T>, from: BitBoard<T>) -> AttackDirections<T> {
+        Piece::<T>::generate_lookup_moves(&self.movement, board, from)
+    }
+
+    fn get_moves(
+        &self,
+        board: &Board<T>,
+        from: BitBoard<T>,
+        piece_type: PieceType,
+        team: u16,
+        mode: u16,
+    ) -> BitBoard<T> {
+        let moves = Piece::<T>::get_moves(&self.movement, board, from, piece_type, team, mode);
+        if (from & board.game.regions[HOME].get(team)).is_set() {
+            // Before crossing the river, the soldier can't move along its row.
+            let row = from.bitscan_forward() / board.state.cols;
+            moves & !board.state.ranks[row as usize]
+        } else {
+            moves
+        }
+    }
+}
+
+pub struct XiangqiPieces {
+    pub general: ConfinedPiece,
+    pub advisor: ConfinedPiece,
+    pub elephant: ConfinedPiece,
+    pub horse: BetzaPiece,
+    pub chariot: BetzaPiece,
+    pub cannon: BetzaPiece,
+    pub soldier: SoldierPiece,
+}
+
+fn parse(symbol: char, notation: &str) -> BetzaPiece {
+    BetzaPiece::parse(symbol, notation).expect("Xiangqi pieces should have valid Betza notation.")
+}
+
+/// Gets every Xiangqi piece, indexed by piece type.
+pub fn get_xiangqi_pieces() -> Vec<&'static dyn Piece<1>> {
+    static PIECES: OnceLock<XiangqiPieces> = OnceLock::new();
+    let pieces = PIECES.get_or_init(|| XiangqiPieces {
+        general: ConfinedPiece::new(parse('k', "W"), PALACE),
+        advisor: ConfinedPiece::new(parse('a', "F"), PALACE),
+        // The elephant is blocked by a piece on the square between it and its destination (its "eye".)
+        elephant: ConfinedPiece::new(parse('b', "nA"), HOME),
+        // The horse is blocked by a piece next to it, in the direction it's moving furthest (its "leg".)
+        horse: parse('n', "nN"),
+        chariot: parse('r', "R"),
+        cannon: parse('c', "mRcpR"),
+        soldier: SoldierPiece { movement: parse('p', "fsW") },
+    });
+
+    vec![
+        &pieces.general,
+        &pieces.advisor,
+        &pieces.elephant,
+        &pieces.horse,
+        &pieces.chariot,
+        &pieces.cannon,
+        &pieces.soldier,
+    ]
+}
@@ -0,0 +1,10 @@
+use crate::{board::{fen::PostProcess, Board}, bitboard::BitBoard};
+
+#[derive(Debug)]
+pub struct XiangqiPostProcess;
+impl<const T: usize> PostProcess<T> for XiangqiPostProcess {
+    fn apply(&self, board: &mut Board<T>) {
+        // Xiangqi doesn't have any moves that depend on whether a piece has moved yet.
+        board.state.first_move = BitBoard::new();
+    }
+}
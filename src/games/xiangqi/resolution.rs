@@ -0,0 +1,28 @@
+use crate::board::{game::{Resolution, GameResults}, Board, actions::Move};
+
+use super::{XiangqiMoveController, MOVE_LIMIT};
+
+#[derive(Debug)]
+pub struct XiangqiResolution;
+
+impl<const T: usize> Resolution<T> for XiangqiResolution {
+    fn resolve(&self, board: &mut Board<T>, legal_moves: &Vec<Move>) -> GameResults {
+        // Like Shogi, running out of moves is always a loss, whether or not you're in check.
+        // This is checked first, so a perpetual check that ends in checkmate still wins.
+        let perpetual_team = XiangqiMoveController::get_perpetual_team(board);
+        if legal_moves.is_empty() && (perpetual_team.is_none() || !XiangqiMoveController.has_legal_moves(board)) {
+            return GameResults::Win(board.get_next_team(board.state.moving_team));
+        }
+
+        // Otherwise, perpetually checking or chasing loses the game.
+        if let Some(team) = perpetual_team {
+            return GameResults::Win(board.get_next_team(team));
+        }
+
+        if board.state.sub_moves >= MOVE_LIMIT {
+            return GameResults::Draw;
+        }
+
+        GameResults::Ongoing
+    }
+}
@@ -0,0 +1,15 @@
+use crate::board::{tests::run_tests};
+use super::Xiangqi;
+
+const TEST_STR: &str = r#"rnbakabnr/9/1c5c1/p1p1p1p1p/9/9/P1P1P1P1P/1C5C1/9/RNBAKABNR w - - 0 1 ;D1 44 ;D2 1920 ;D3 79666
+r1ba1a3/4kn3/2n1b4/pNp1p1p1p/4c4/6P2/P1P2R2P/1CcC5/9/2BAKAB2 w - - 0 1 ;D1 38 ;D2 1128 ;D3 43929
+1cbak4/9/n2a5/2p1p3p/5cp2/2n2N3/6PCP/3AB4/2C6/3A1K1N1 w - - 0 1 ;D1 7 ;D2 281 ;D3 8620 ;D4 326201
+5a3/3k5/3aR4/9/5r3/5n3/9/3A1A3/5K3/2BC2B2 w - - 0 1 ;D1 25 ;D2 424 ;D3 9850 ;D4 202884
+CRN1k1b2/3ca4/4ba3/9/2nr5/9/9/4B4/4A4/4KA3 w - - 0 1 ;D1 28 ;D2 516 ;D3 14808
+R1N1k1b2/9/3aba3/9/2nr5/2B6/9/4B4/4A4/4KA3 w - - 0 1 ;D1 21 ;D2 364 ;D3 7626
+3k5/4a4/9/p1P6/9/9/2p1p4/9/4A4/3K5 b - - 0 1 ;D1 4 ;D2 32 ;D3 324"#;
+
+#[test]
+fn xiangqi_perft_suite() {
+    run_tests("Xiangqi", Xiangqi::create(), TEST_STR);
+}